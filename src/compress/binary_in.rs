@@ -0,0 +1,88 @@
+//! 按位读取的输入流。
+
+use std::io::{self, Read};
+
+/// 按位读取数据，高位在前。
+pub struct BinaryIn<R: Read> {
+    input: R,
+    buffer: u8, // 尚未读取的位。
+    n: usize,   // buffer 中剩余的位数。
+}
+
+impl<R> BinaryIn<R>
+where
+    R: Read,
+{
+    /// 创建一个从 input 读取的输入流。
+    pub fn new(input: R) -> Self {
+        Self {
+            input,
+            buffer: 0,
+            n: 0,
+        }
+    }
+
+    /// 读取一位，数据已读完时返回 `None`。
+    pub fn try_read_bit(&mut self) -> io::Result<Option<bool>> {
+        if self.n == 0 {
+            let mut b = [0];
+            loop {
+                match self.input.read(&mut b) {
+                    Ok(0) => return Ok(None),
+                    Ok(_) => break,
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+            self.buffer = b[0];
+            self.n = 8;
+        }
+        self.n -= 1;
+        Ok(Some((self.buffer >> self.n) & 1 == 1))
+    }
+
+    /// 读取一位，数据已读完时返回 [`io::ErrorKind::UnexpectedEof`] 错误。
+    pub fn read_bit(&mut self) -> io::Result<bool> {
+        self.try_read_bit()?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "比特流意外结束"))
+    }
+
+    /// 读取 width 位，高位在前。
+    ///
+    /// # Panics
+    ///
+    /// width 大于 64 时 panic。
+    pub fn read_bits(&mut self, width: usize) -> io::Result<u64> {
+        assert!(width <= 64, "位数 {width} 不能大于 64");
+        let mut value = 0;
+        for _ in 0..width {
+            value = (value << 1) | self.read_bit()? as u64;
+        }
+        Ok(value)
+    }
+
+    /// 读取一个字节。
+    pub fn read_byte(&mut self) -> io::Result<u8> {
+        self.read_bits(8).map(|b| b as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let data = [0b1010_1111, 0b1111_0000, 0x10, 0x20];
+        let mut input = BinaryIn::new(&data[..]);
+        assert!(input.read_bit().unwrap());
+        assert_eq!(0b010, input.read_bits(3).unwrap());
+        assert_eq!(0xff, input.read_byte().unwrap());
+        assert_eq!(0x0102, input.read_bits(16).unwrap());
+        assert_eq!(0, input.read_bits(4).unwrap());
+        assert_eq!(None, input.try_read_bit().unwrap());
+
+        let err = input.read_bit().unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+    }
+}
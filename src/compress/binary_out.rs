@@ -0,0 +1,85 @@
+//! 按位写入的输出流。
+
+use std::io::{self, Write};
+
+/// 按位写入数据，高位在前。
+pub struct BinaryOut<W: Write> {
+    out: W,
+    buffer: u8, // 尚未写出的位。
+    n: usize,   // buffer 中的位数。
+}
+
+impl<W> BinaryOut<W>
+where
+    W: Write,
+{
+    /// 创建一个写入 out 的输出流。
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            buffer: 0,
+            n: 0,
+        }
+    }
+
+    /// 写入一位。
+    pub fn write_bit(&mut self, bit: bool) -> io::Result<()> {
+        self.buffer = (self.buffer << 1) | bit as u8;
+        self.n += 1;
+        if self.n == 8 {
+            self.out.write_all(&[self.buffer])?;
+            self.buffer = 0;
+            self.n = 0;
+        }
+        Ok(())
+    }
+
+    /// 写入 value 的低 width 位，高位在前。
+    ///
+    /// # Panics
+    ///
+    /// width 大于 64 时 panic。
+    pub fn write_bits(&mut self, value: u64, width: usize) -> io::Result<()> {
+        assert!(width <= 64, "位数 {width} 不能大于 64");
+        for i in (0..width).rev() {
+            self.write_bit((value >> i) & 1 == 1)?;
+        }
+        Ok(())
+    }
+
+    /// 写入一个字节。
+    pub fn write_byte(&mut self, b: u8) -> io::Result<()> {
+        if self.n == 0 {
+            return self.out.write_all(&[b]);
+        }
+        self.write_bits(b as u64, 8)
+    }
+
+    /// 用 0 补齐最后一个字节，写出所有数据并返回底层的输出。
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.n > 0 {
+            let b = self.buffer << (8 - self.n);
+            self.out.write_all(&[b])?;
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut out = BinaryOut::new(vec![]);
+        out.write_bit(true).unwrap();
+        out.write_bits(0b010, 3).unwrap();
+        out.write_byte(0xff).unwrap();
+        out.write_bits(0x0102, 16).unwrap();
+        assert_eq!(
+            vec![0b1010_1111, 0b1111_0000, 0x10, 0x20],
+            out.finish().unwrap()
+        );
+    }
+}
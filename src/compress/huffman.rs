@@ -0,0 +1,268 @@
+//! 霍夫曼压缩。
+//!
+//! 压缩格式依次为：
+//!
+//! 1. 原始数据的字节数，64 位无符号整数；
+//! 2. 字节数不为 0 时，前序遍历写入的单词查找树：内部节点写入 0，
+//!    叶子节点写入 1 及其对应的字节；
+//! 3. 每个字节对应的编码；
+//! 4. 补齐最后一个字节的 0。
+
+use std::io::{self, Read, Write};
+
+use super::{binary_in::BinaryIn, binary_out::BinaryOut};
+use crate::sort::min_pq::MinPQ;
+
+const R: usize = 256; // 字母表的大小，按字节划分。
+const CHUNK: usize = 1 << 16; // 展开时每次写出的字节数。
+
+/// 霍夫曼压缩和展开。
+pub struct Huffman;
+
+impl Huffman {
+    /// 压缩 input 中的所有数据，写入 output。
+    pub fn compress<R, W>(mut input: R, output: W) -> io::Result<()>
+    where
+        R: Read,
+        W: Write,
+    {
+        let mut data = vec![];
+        input.read_to_end(&mut data)?;
+
+        let mut out = BinaryOut::new(output);
+        out.write_bits(data.len() as u64, 64)?;
+        if !data.is_empty() {
+            let mut freq = [0; R];
+            for &b in &data {
+                freq[b as usize] += 1;
+            }
+            let root = build_trie(&freq);
+            write_trie(&root, &mut out)?;
+
+            let mut st = vec![vec![]; R];
+            build_code(&mut st, &root, &mut vec![]);
+            for &b in &data {
+                for &bit in &st[b as usize] {
+                    out.write_bit(bit)?;
+                }
+            }
+        }
+        out.finish()?;
+        Ok(())
+    }
+
+    /// 展开 input 中的压缩数据，写入 output。
+    ///
+    /// 数据格式不正确时返回 [`io::ErrorKind::InvalidData`] 或
+    /// [`io::ErrorKind::UnexpectedEof`] 错误。
+    pub fn expand<R, W>(input: R, mut output: W) -> io::Result<()>
+    where
+        R: Read,
+        W: Write,
+    {
+        let mut input = BinaryIn::new(input);
+        let n = input.read_bits(64)?;
+        if n > 0 {
+            let root = read_trie(&mut input, 0)?;
+            // 压缩时的编码至少有一位，根为叶子时展开不会读取任何输入。
+            if root.is_leaf() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "单词查找树的根不能是叶子节点",
+                ));
+            }
+            let mut buf = Vec::with_capacity(n.min(CHUNK as u64) as usize);
+            for _ in 0..n {
+                let mut x = &root;
+                while let (Some(left), Some(right)) = (&x.left, &x.right) {
+                    x = if input.read_bit()? { right } else { left };
+                }
+                buf.push(x.ch);
+                if buf.len() == CHUNK {
+                    output.write_all(&buf)?;
+                    buf.clear();
+                }
+            }
+            output.write_all(&buf)?;
+        }
+        output.flush()
+    }
+}
+
+/// 单词查找树的节点，按频率比较大小。
+struct Node {
+    ch: u8,                   // 叶子节点对应的字节。
+    freq: usize,              // 频率。
+    left: Option<Box<Node>>,  // 左子树，对应编码 0。
+    right: Option<Box<Node>>, // 右子树，对应编码 1。
+}
+
+impl Node {
+    fn leaf(ch: u8, freq: usize) -> Self {
+        Self {
+            ch,
+            freq,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.left.is_none() && self.right.is_none()
+    }
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.freq == other.freq
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.freq.partial_cmp(&other.freq)
+    }
+}
+
+/// 由频率构造霍夫曼单词查找树。
+fn build_trie(freq: &[usize; R]) -> Node {
+    let mut pq = MinPQ::new(R);
+    for (c, &f) in freq.iter().enumerate() {
+        if f > 0 {
+            pq.insert(Node::leaf(c as u8, f));
+        }
+    }
+
+    // 只有一种字节时，补充一个频率为 0 的字节，使编码至少有一位。
+    if pq.len() == 1 {
+        let c = if freq[0] == 0 { 0 } else { 1 };
+        pq.insert(Node::leaf(c, 0));
+    }
+
+    // 合并两棵最小的树。
+    loop {
+        let x = pq.delete_min().expect("队列不能为空");
+        let Some(y) = pq.delete_min() else {
+            return x;
+        };
+        pq.insert(Node {
+            ch: 0,
+            freq: x.freq + y.freq,
+            left: Some(Box::new(x)),
+            right: Some(Box::new(y)),
+        });
+    }
+}
+
+/// 构造编译表。
+fn build_code(st: &mut [Vec<bool>], x: &Node, code: &mut Vec<bool>) {
+    match (&x.left, &x.right) {
+        (Some(left), Some(right)) => {
+            code.push(false);
+            build_code(st, left, code);
+            code.pop();
+            code.push(true);
+            build_code(st, right, code);
+            code.pop();
+        }
+        _ => st[x.ch as usize] = code.clone(),
+    }
+}
+
+/// 前序遍历写入单词查找树。
+fn write_trie<W: Write>(x: &Node, out: &mut BinaryOut<W>) -> io::Result<()> {
+    if x.is_leaf() {
+        out.write_bit(true)?;
+        return out.write_byte(x.ch);
+    }
+    out.write_bit(false)?;
+    if let (Some(left), Some(right)) = (&x.left, &x.right) {
+        write_trie(left, out)?;
+        write_trie(right, out)?;
+    }
+    Ok(())
+}
+
+/// 读取单词查找树，d 为当前节点的深度。
+fn read_trie<R: Read>(input: &mut BinaryIn<R>, d: usize) -> io::Result<Node> {
+    // 256 个叶子节点的树深度不会超过 255。
+    if d >= R {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "单词查找树的深度超出范围",
+        ));
+    }
+    if input.read_bit()? {
+        return Ok(Node::leaf(input.read_byte()?, 0));
+    }
+    let left = read_trie(input, d + 1)?;
+    let right = read_trie(input, d + 1)?;
+    Ok(Node {
+        ch: 0,
+        freq: 0,
+        left: Some(Box::new(left)),
+        right: Some(Box::new(right)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compress_test() {
+        let data = b"ABRACADABRA!";
+        let compressed = compress(data);
+        // 8 字节的长度，5 个内部节点和 6 个叶子共 59 位的树，以及 28 位编码。
+        assert_eq!(8 + (59_usize + 28).div_ceil(8), compressed.len());
+        assert_eq!(&data[..], &expand(&compressed).unwrap()[..]);
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let text = "it was the best of times it was the worst of times".repeat(20);
+        let all: Vec<u8> = (0..=255).collect();
+        for data in [&b""[..], b"a", b"aaaa", text.as_bytes(), &all] {
+            assert_eq!(data, &expand(&compress(data)).unwrap()[..]);
+        }
+        assert!(compress(text.as_bytes()).len() < text.len() / 2);
+    }
+
+    #[test]
+    fn invalid_data_test() {
+        let compressed = compress(b"ABRACADABRA!");
+        let err = expand(&compressed[..compressed.len() - 1]).unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+
+        // 长度为 1，树全部由内部节点组成。
+        let mut data = vec![0; 8];
+        data[7] = 1;
+        data.extend([0; 64]);
+        let err = expand(&data).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+        // 长度为 u64::MAX，树只有一个叶子节点 'a'。
+        let mut data = vec![0xff; 8];
+        data.extend([0b1011_0000, 0b1000_0000]);
+        let err = expand(&data).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+    }
+
+    #[test]
+    fn chunk_test() {
+        let data: Vec<u8> = (0..CHUNK * 2 + 3).map(|i| (i % 7) as u8).collect();
+        assert_eq!(data, expand(&compress(&data)).unwrap());
+    }
+
+    fn compress(data: &[u8]) -> Vec<u8> {
+        let mut out = vec![];
+        Huffman::compress(data, &mut out).unwrap();
+        out
+    }
+
+    fn expand(data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut out = vec![];
+        Huffman::expand(data, &mut out)?;
+        Ok(out)
+    }
+}
@@ -0,0 +1,284 @@
+//! LZW 压缩。
+//!
+//! 压缩格式依次为：
+//!
+//! 1. 编码的最大位数，8 位无符号整数；
+//! 2. 变长的编码序列，以 [`EOF`] 结尾；
+//! 3. 补齐最后一个字节的 0。
+//!
+//! 编码 0 到 255 对应单个字节，[`EOF`] 表示数据结束，[`RESET`] 表示编译表已满、
+//! 双方都将编译表恢复为初始状态。其余编码按加入编译表的顺序分配。
+//! 写出每个编码时，所用的位数恰好能表示当前编译表中最大的编码，但不少于 9 位。
+
+use std::io::{self, BufReader, Read, Write};
+
+use super::{binary_in::BinaryIn, binary_out::BinaryOut};
+use crate::search::tst::TST;
+
+const R: usize = 256; // 字母表的大小，按字节划分。
+
+/// 数据结束的编码。
+pub const EOF: usize = R;
+
+/// 重置编译表的编码。
+pub const RESET: usize = R + 1;
+
+const FIRST: usize = R + 2; // 第一个可分配的编码。
+const MIN_WIDTH: usize = 9; // 编码的最小位数。
+const MAX_WIDTH: usize = 16; // 编码的最大位数允许的最大值。
+
+/// LZW 压缩和展开。
+#[allow(clippy::upper_case_acronyms)]
+pub struct LZW {
+    max_width: usize, // 编码的最大位数。
+}
+
+impl LZW {
+    /// 创建编码最多为 max_width 位的压缩器。
+    ///
+    /// # Panics
+    ///
+    /// max_width 不在 9 到 16 之间时 panic。
+    pub fn new(max_width: usize) -> Self {
+        assert!(
+            (MIN_WIDTH..=MAX_WIDTH).contains(&max_width),
+            "编码的位数 {max_width} 必须在 {MIN_WIDTH} 到 {MAX_WIDTH} 之间"
+        );
+        Self { max_width }
+    }
+
+    /// 压缩 input 中的所有数据，写入 output。
+    ///
+    /// 逐字节读取 input，每确定一个最长前缀就立即写出它的编码。
+    pub fn compress<R, W>(&self, input: R, output: W) -> io::Result<()>
+    where
+        R: Read,
+        W: Write,
+    {
+        let mut out = BinaryOut::new(output);
+        out.write_bits(self.max_width as u64, 8)?;
+
+        let l = 1 << self.max_width; // 编码总数。
+        let mut st = initial_st();
+        let mut code = FIRST;
+        let mut prefix = String::new(); // 当前匹配的最长前缀。
+        for b in BufReader::new(input).bytes() {
+            // 每个字节映射为一个 Latin-1 字符，以便使用基于字符的 TST。
+            let c = b? as char;
+            prefix.push(c);
+            if st.contains(&prefix) {
+                continue;
+            }
+
+            // 前缀无法再延长，写出其编码。
+            prefix.pop();
+            out.write_bits(code_of(&st, &prefix), width(code))?;
+
+            // 将前缀和下一个字符加入编译表，编译表已满时重置。
+            if code == l {
+                out.write_bits(RESET as u64, width(code))?;
+                st = initial_st();
+                code = FIRST;
+            } else {
+                prefix.push(c);
+                st.put(&prefix, code);
+                code += 1;
+            }
+            prefix.clear();
+            prefix.push(c);
+        }
+        if !prefix.is_empty() {
+            out.write_bits(code_of(&st, &prefix), width(code))?;
+        }
+        out.write_bits(EOF as u64, width(code))?;
+        out.finish()?;
+        Ok(())
+    }
+
+    /// 展开 input 中的压缩数据，写入 output。
+    ///
+    /// 编码的最大位数从数据中读取。数据格式不正确时返回
+    /// [`io::ErrorKind::InvalidData`] 或 [`io::ErrorKind::UnexpectedEof`] 错误。
+    pub fn expand<R, W>(input: R, mut output: W) -> io::Result<()>
+    where
+        R: Read,
+        W: Write,
+    {
+        let mut input = BinaryIn::new(input);
+        let max_width = input.read_bits(8)? as usize;
+        if !(MIN_WIDTH..=MAX_WIDTH).contains(&max_width) {
+            return Err(invalid_data(format!("编码的位数 {max_width} 超出范围")));
+        }
+
+        let l = 1 << max_width;
+        let mut st = initial_table();
+        let mut prev: Option<Vec<u8>> = None;
+        loop {
+            // 压缩时已在写出当前编码之前将上一个前缀加入了编译表。
+            let next = if prev.is_some() && st.len() < l {
+                st.len() + 1
+            } else {
+                st.len()
+            };
+            let c = input.read_bits(width(next))? as usize;
+            if c == EOF {
+                break;
+            }
+            if c == RESET {
+                st = initial_table();
+                prev = None;
+                continue;
+            }
+
+            let val = match (st.get(c), &prev) {
+                (Some(val), _) => val.clone(),
+                // 编码恰好是正要加入编译表的那一个。
+                (None, Some(p)) if c == st.len() => {
+                    let mut val = p.clone();
+                    val.push(p[0]);
+                    val
+                }
+                _ => return Err(invalid_data(format!("编码 {c} 不在编译表中"))),
+            };
+            if let Some(mut p) = prev.take() {
+                if st.len() < l {
+                    p.push(val[0]);
+                    st.push(p);
+                }
+            }
+            output.write_all(&val)?;
+            prev = Some(val);
+        }
+        output.flush()
+    }
+}
+
+impl Default for LZW {
+    /// 编码最多为 12 位。
+    fn default() -> Self {
+        Self::new(12)
+    }
+}
+
+/// 表示小于 code 的所有编码所需的位数。
+fn width(code: usize) -> usize {
+    let bits = (usize::BITS - (code - 1).leading_zeros()) as usize;
+    bits.max(MIN_WIDTH)
+}
+
+/// 编译表中前缀的编码。
+fn code_of(st: &TST<usize>, prefix: &str) -> u64 {
+    *st.get(prefix).expect("前缀总在编译表中") as u64
+}
+
+/// 压缩用的初始编译表。
+fn initial_st() -> TST<usize> {
+    let mut st = TST::new();
+    for b in 0..R {
+        st.put(&(b as u8 as char).to_string(), b);
+    }
+    st
+}
+
+/// 展开用的初始编译表，[`EOF`] 和 [`RESET`] 用空值占位。
+fn initial_table() -> Vec<Vec<u8>> {
+    let mut st: Vec<Vec<u8>> = (0..R).map(|b| vec![b as u8]).collect();
+    st.resize(FIRST, vec![]);
+    st
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compress_test() {
+        let data = b"ABRABRABRA";
+        let compressed = compress(&LZW::default(), data);
+        // 1 字节的位数，以及 A B R AB RA BR A EOF 共 8 个 9 位的编码。
+        assert_eq!(1 + (8_usize * 9).div_ceil(8), compressed.len());
+        assert_eq!(&data[..], &expand(&compressed).unwrap()[..]);
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let text = "it was the best of times it was the worst of times".repeat(50);
+        let all: Vec<u8> = (0..=255).rev().collect();
+        for data in [&b""[..], b"a", b"aaaaaaa", text.as_bytes(), &all] {
+            let compressed = compress(&LZW::default(), data);
+            assert_eq!(data, &expand(&compressed).unwrap()[..]);
+        }
+        assert!(compress(&LZW::default(), text.as_bytes()).len() < text.len() / 4);
+    }
+
+    #[test]
+    fn reset_test() {
+        // 9 位编码的编译表很快就会填满，需要多次重置。
+        let data: Vec<u8> = (0..20000_u32).map(|i| (i * i % 251) as u8).collect();
+        for max_width in [9, 10, 16] {
+            let compressed = compress(&LZW::new(max_width), &data);
+            assert_eq!(data, expand(&compressed).unwrap());
+        }
+    }
+
+    #[test]
+    fn invalid_data_test() {
+        let err = expand(&[8]).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+        // 第一个编码就引用了不存在的条目。
+        let mut out = BinaryOut::new(vec![]);
+        out.write_bits(12, 8).unwrap();
+        out.write_bits(FIRST as u64, 9).unwrap();
+        let err = expand(&out.finish().unwrap()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+        let compressed = compress(&LZW::default(), b"ABRACADABRA");
+        let err = expand(&compressed[..compressed.len() - 2]).unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+    }
+
+    #[test]
+    fn streaming_test() {
+        // 读取出错之前，已经确定的编码都已写出。
+        struct Failing<'a>(&'a [u8]);
+
+        impl Read for Failing<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if self.0.is_empty() {
+                    return Err(io::Error::other("读取失败"));
+                }
+                self.0.read(buf)
+            }
+        }
+
+        let data = "to be or not to be ".repeat(20);
+        let mut out = vec![];
+        assert!(LZW::default()
+            .compress(Failing(data.as_bytes()), &mut out)
+            .is_err());
+        assert!(out.len() > 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "必须在 9 到 16 之间")]
+    fn width_panic_test() {
+        LZW::new(8);
+    }
+
+    fn compress(lzw: &LZW, data: &[u8]) -> Vec<u8> {
+        let mut out = vec![];
+        lzw.compress(data, &mut out).unwrap();
+        out
+    }
+
+    fn expand(data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut out = vec![];
+        LZW::expand(data, &mut out)?;
+        Ok(out)
+    }
+}
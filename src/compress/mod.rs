@@ -0,0 +1,9 @@
+//! 数据压缩。
+//!
+//! 压缩结果均为按位写入的比特流，高位在前，末尾不足一个字节的部分用 0 填充。
+//! 各算法的具体格式见对应的模块。
+
+pub mod binary_in;
+pub mod binary_out;
+pub mod huffman;
+pub mod lzw;
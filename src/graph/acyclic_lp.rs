@@ -0,0 +1,94 @@
+//! 无环加权有向图中的最长路径。
+
+use super::{
+    acyclic_sp::NotDagError, directed_edge::DirectedEdge,
+    edge_weighted_digraph::EdgeWeightedDigraph, topological::Topological,
+};
+
+pub struct AcyclicLP {
+    edge_to: Vec<Option<DirectedEdge>>, // 最长路径树中指向顶点的边。
+    dist_to: Vec<f64>,                  // 起点到顶点的最长距离。
+}
+
+impl AcyclicLP {
+    /// 按拓扑顺序放松顶点，计算从 s 出发的最长路径树。
+    ///
+    /// 图中有环时返回 [`NotDagError`]。
+    pub fn new(g: &EdgeWeightedDigraph, s: usize) -> Result<Self, NotDagError> {
+        let t = Topological::new(g);
+        if !t.is_dag() {
+            return Err(NotDagError);
+        }
+
+        let mut a = Self {
+            edge_to: vec![None; g.v()],
+            dist_to: vec![f64::NEG_INFINITY; g.v()],
+        };
+        a.dist_to[s] = 0.0;
+        for &v in t.order() {
+            a.relax(g, v);
+        }
+        Ok(a)
+    }
+
+    /// 与最短路径相反，距离变长时才更新。
+    fn relax(&mut self, g: &EdgeWeightedDigraph, v: usize) {
+        for e in g.adj(v) {
+            let w = e.to();
+            if self.dist_to[w] < self.dist_to[v] + e.weight() {
+                self.dist_to[w] = self.dist_to[v] + e.weight();
+                self.edge_to[w] = Some(e.clone());
+            }
+        }
+    }
+
+    /// 起点到 v 的最长距离，不可达时为负无穷大。
+    pub fn dist_to(&self, v: usize) -> f64 {
+        self.dist_to[v]
+    }
+
+    /// 是否存在起点到 v 的路径。
+    pub fn has_path_to(&self, v: usize) -> bool {
+        self.dist_to[v] > f64::NEG_INFINITY
+    }
+
+    /// 获取起点到 v 的最长路径，边按从起点到 v 的顺序排列。
+    pub fn path_to(&self, v: usize) -> Vec<&DirectedEdge> {
+        let mut path = vec![];
+        let mut e = self.edge_to[v].as_ref();
+        while let Some(x) = e {
+            path.push(x);
+            e = self.edge_to[x.from()].as_ref();
+        }
+        path.reverse();
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{tiny_ewd, tiny_ewdag};
+
+    #[test]
+    fn dist_to_test() {
+        let a = AcyclicLP::new(&tiny_ewdag(), 5).unwrap();
+        let expect = [2.44, 0.32, 2.77, 0.61, 2.06, 0.0, 1.13, 2.43];
+        for (v, dist) in expect.iter().enumerate() {
+            assert!((a.dist_to(v) - dist).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn path_to_test() {
+        let a = AcyclicLP::new(&tiny_ewdag(), 5).unwrap();
+        let path: Vec<usize> = a.path_to(2).iter().map(|e| e.to()).collect();
+        assert_eq!(&[1, 3, 6, 4, 7, 2], &path[..]);
+        assert!(!AcyclicLP::new(&tiny_ewdag(), 0).unwrap().has_path_to(5));
+    }
+
+    #[test]
+    fn not_dag_test() {
+        assert!(AcyclicLP::new(&tiny_ewd(), 0).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+//! 无环加权有向图中的最短路径。
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use super::{
+    directed_edge::DirectedEdge, edge_weighted_digraph::EdgeWeightedDigraph,
+    topological::Topological,
+};
+
+pub struct AcyclicSP {
+    edge_to: Vec<Option<DirectedEdge>>, // 最短路径树中指向顶点的边。
+    dist_to: Vec<f64>,                  // 起点到顶点的最短距离。
+}
+
+impl AcyclicSP {
+    /// 按拓扑顺序放松顶点，计算从 s 出发的最短路径树。
+    ///
+    /// 图中有环时返回 [`NotDagError`]。
+    pub fn new(g: &EdgeWeightedDigraph, s: usize) -> Result<Self, NotDagError> {
+        let t = Topological::new(g);
+        if !t.is_dag() {
+            return Err(NotDagError);
+        }
+
+        let mut a = Self {
+            edge_to: vec![None; g.v()],
+            dist_to: vec![f64::INFINITY; g.v()],
+        };
+        a.dist_to[s] = 0.0;
+        for &v in t.order() {
+            a.relax(g, v);
+        }
+        Ok(a)
+    }
+
+    fn relax(&mut self, g: &EdgeWeightedDigraph, v: usize) {
+        for e in g.adj(v) {
+            let w = e.to();
+            if self.dist_to[w] > self.dist_to[v] + e.weight() {
+                self.dist_to[w] = self.dist_to[v] + e.weight();
+                self.edge_to[w] = Some(e.clone());
+            }
+        }
+    }
+
+    /// 起点到 v 的最短距离，不可达时为无穷大。
+    pub fn dist_to(&self, v: usize) -> f64 {
+        self.dist_to[v]
+    }
+
+    /// 是否存在起点到 v 的路径。
+    pub fn has_path_to(&self, v: usize) -> bool {
+        self.dist_to[v] < f64::INFINITY
+    }
+
+    /// 最短路径树中指向 v 的边，v 为起点或不可达时为 [`None`]。
+    pub fn edge_to(&self, v: usize) -> Option<&DirectedEdge> {
+        self.edge_to[v].as_ref()
+    }
+
+    /// 获取起点到 v 的最短路径，边按从起点到 v 的顺序排列。
+    pub fn path_to(&self, v: usize) -> Vec<&DirectedEdge> {
+        let mut path = vec![];
+        let mut e = self.edge_to[v].as_ref();
+        while let Some(x) = e {
+            path.push(x);
+            e = self.edge_to[x.from()].as_ref();
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// 图不是有向无环图。
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotDagError;
+
+impl Display for NotDagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "图中存在有向环")
+    }
+}
+
+impl Error for NotDagError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{tiny_ewd, tiny_ewdag};
+
+    #[test]
+    fn dist_to_test() {
+        let a = AcyclicSP::new(&tiny_ewdag(), 5).unwrap();
+        let expect = [0.73, 0.32, 0.62, 0.61, 0.35, 0.0, 1.13, 0.28];
+        for (v, dist) in expect.iter().enumerate() {
+            assert!((a.dist_to(v) - dist).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn path_to_test() {
+        let a = AcyclicSP::new(&tiny_ewdag(), 5).unwrap();
+        let path: Vec<usize> = a.path_to(6).iter().map(|e| e.to()).collect();
+        assert_eq!(&[1, 3, 6], &path[..]);
+        assert_eq!(Some(3), a.edge_to(6).map(|e| e.from()));
+        assert!(a.edge_to(5).is_none());
+        assert!(!AcyclicSP::new(&tiny_ewdag(), 0).unwrap().has_path_to(5));
+    }
+
+    #[test]
+    fn not_dag_test() {
+        assert_eq!(Some(NotDagError), AcyclicSP::new(&tiny_ewd(), 0).err());
+    }
+}
@@ -0,0 +1,181 @@
+//! 基于队列的 Bellman-Ford 最短路径算法。
+
+use std::collections::VecDeque;
+
+use super::{
+    directed_edge::DirectedEdge, edge_weighted_digraph::EdgeWeightedDigraph,
+    edge_weighted_directed_cycle::EdgeWeightedDirectedCycle,
+};
+
+pub struct BellmanFordSP {
+    dist_to: Vec<f64>,                  // 起点到顶点的最短距离。
+    edge_to: Vec<Option<DirectedEdge>>, // 最短路径树中指向顶点的边。
+    on_q: Vec<bool>,                    // 顶点是否在队列中。
+    queue: VecDeque<usize>,             // 正在被放松的顶点。
+    cost: usize,                        // relax() 的调用次数。
+    cycle: Vec<DirectedEdge>,           // 最短路径树中的负权重环。
+}
+
+impl BellmanFordSP {
+    /// 计算从 s 出发的最短路径树，边的权重可以为负。
+    pub fn new(g: &EdgeWeightedDigraph, s: usize) -> Self {
+        let mut b = Self {
+            dist_to: vec![f64::INFINITY; g.v()],
+            edge_to: vec![None; g.v()],
+            on_q: vec![false; g.v()],
+            queue: VecDeque::new(),
+            cost: 0,
+            cycle: vec![],
+        };
+
+        b.dist_to[s] = 0.0;
+        b.queue.push_back(s);
+        b.on_q[s] = true;
+        while let Some(v) = b.queue.pop_front() {
+            if b.has_negative_cycle() {
+                break;
+            }
+            b.on_q[v] = false;
+            b.relax(g, v);
+        }
+
+        b
+    }
+
+    /// 放松从 v 指出的所有边。
+    fn relax(&mut self, g: &EdgeWeightedDigraph, v: usize) {
+        for e in g.adj(v) {
+            let w = e.to();
+            if self.dist_to[w] > self.dist_to[v] + e.weight() {
+                self.dist_to[w] = self.dist_to[v] + e.weight();
+                self.edge_to[w] = Some(e.clone());
+                if !self.on_q[w] {
+                    self.queue.push_back(w);
+                    self.on_q[w] = true;
+                }
+            }
+            // 每进行 V 次放松，检查一次最短路径树中是否有负权重环。
+            self.cost += 1;
+            if self.cost.is_multiple_of(g.v()) {
+                self.find_negative_cycle();
+                if self.has_negative_cycle() {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// 在最短路径树中查找负权重环。
+    fn find_negative_cycle(&mut self) {
+        let mut spt = EdgeWeightedDigraph::new(self.edge_to.len());
+        for e in self.edge_to.iter().flatten() {
+            spt.add_edge(e.clone());
+        }
+
+        let finder = EdgeWeightedDirectedCycle::new(&spt);
+        self.cycle = finder.cycle().to_vec();
+    }
+
+    /// 是否存在从起点可达的负权重环。
+    pub fn has_negative_cycle(&self) -> bool {
+        !self.cycle.is_empty()
+    }
+
+    /// 获取负权重环中的边，首尾相接。
+    pub fn negative_cycle(&self) -> Option<&[DirectedEdge]> {
+        if self.has_negative_cycle() {
+            Some(&self.cycle)
+        } else {
+            None
+        }
+    }
+
+    /// 起点到 v 的最短距离，不可达时为无穷大。
+    ///
+    /// 存在负权重环时最短距离没有意义，此时调用会 panic。
+    pub fn dist_to(&self, v: usize) -> f64 {
+        self.assert_no_negative_cycle();
+        self.dist_to[v]
+    }
+
+    /// 是否存在起点到 v 的路径。
+    pub fn has_path_to(&self, v: usize) -> bool {
+        self.dist_to[v] < f64::INFINITY
+    }
+
+    /// 最短路径树中指向 v 的边，v 为起点或不可达时为 [`None`]。
+    pub fn edge_to(&self, v: usize) -> Option<&DirectedEdge> {
+        self.edge_to[v].as_ref()
+    }
+
+    /// 获取起点到 v 的最短路径，边按从起点到 v 的顺序排列。
+    ///
+    /// 存在负权重环时最短路径没有意义，此时调用会 panic。
+    pub fn path_to(&self, v: usize) -> Vec<&DirectedEdge> {
+        self.assert_no_negative_cycle();
+        let mut path = vec![];
+        let mut e = self.edge_to[v].as_ref();
+        while let Some(x) = e {
+            path.push(x);
+            e = self.edge_to[x.from()].as_ref();
+        }
+        path.reverse();
+        path
+    }
+
+    fn assert_no_negative_cycle(&self) {
+        assert!(!self.has_negative_cycle(), "图中存在负权重环");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{tiny_ewd, tiny_ewdn};
+
+    #[test]
+    fn dist_to_test() {
+        let b = BellmanFordSP::new(&tiny_ewdn(), 0);
+        assert!(!b.has_negative_cycle());
+        assert!(b.negative_cycle().is_none());
+
+        let expect = [0.0, 0.93, 0.26, 0.99, 0.26, 0.61, 1.51, 0.60];
+        for (v, dist) in expect.iter().enumerate() {
+            assert!((b.dist_to(v) - dist).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn path_to_test() {
+        let b = BellmanFordSP::new(&tiny_ewdn(), 0);
+        let path: Vec<usize> = b.path_to(1).iter().map(|e| e.to()).collect();
+        assert_eq!(&[2, 7, 3, 6, 4, 5, 1], &path[..]);
+        assert_eq!(Some(5), b.edge_to(1).map(|e| e.from()));
+        assert!(b.edge_to(0).is_none());
+        assert!(b.has_path_to(1));
+    }
+
+    #[test]
+    fn negative_cycle_test() {
+        let mut g = tiny_ewd();
+        g.add_edge(DirectedEdge::new(5, 4, -0.66));
+        let b = BellmanFordSP::new(&g, 0);
+        assert!(b.has_negative_cycle());
+
+        let cycle = b.negative_cycle().unwrap();
+        let weight: f64 = cycle.iter().map(|e| e.weight()).sum();
+        assert!((weight - -0.31).abs() < 1e-9);
+        for (i, e) in cycle.iter().enumerate() {
+            assert_eq!(e.to(), cycle[(i + 1) % cycle.len()].from());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "负权重环")]
+    fn dist_to_with_negative_cycle_test() {
+        let mut g = EdgeWeightedDigraph::new(2);
+        g.add_edge(DirectedEdge::new(0, 1, 1.0));
+        g.add_edge(DirectedEdge::new(1, 0, -2.0));
+        BellmanFordSP::new(&g, 0).dist_to(1);
+    }
+}
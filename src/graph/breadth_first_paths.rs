@@ -2,7 +2,7 @@
 
 use std::collections::VecDeque;
 
-use super::Graph;
+use super::AdjacencyGraph;
 
 pub struct BreadthFirstPaths {
     marked: Vec<bool>,   // 是否已访问过。
@@ -12,26 +12,26 @@ pub struct BreadthFirstPaths {
 
 impl BreadthFirstPaths {
     /// 创建图的广度优先结果。
-    pub fn new(g: Graph, s: usize) -> Self {
+    pub fn new<G: AdjacencyGraph>(g: &G, s: usize) -> Self {
         let marked = vec![false; g.v()];
         let edge_to = vec![0; g.v()];
         let mut d = Self { marked, edge_to, s };
-        d.bfs(&g, s);
+        d.bfs(g, s);
         d
     }
 
     /// 广度优先算法。
-    fn bfs(&mut self, g: &Graph, s: usize) {
+    fn bfs<G: AdjacencyGraph>(&mut self, g: &G, s: usize) {
         let mut queue = VecDeque::new();
         queue.push_back(s);
 
         self.marked[s] = true;
         while let Some(value) = queue.pop_front() {
             for w in g.adj(value) {
-                if !self.marked[*w] {
-                    self.marked[*w] = true; // 找到最短路径。
-                    self.edge_to[*w] = value; // 最短路径的最后一条边。
-                    queue.push_back(*w); // 添加最短路径。
+                if !self.marked[w] {
+                    self.marked[w] = true; // 找到最短路径。
+                    self.edge_to[w] = value; // 最短路径的最后一条边。
+                    queue.push_back(w); // 添加最短路径。
                 }
             }
         }
@@ -67,14 +67,14 @@ mod tests {
     #[test]
     fn has_path_to_test() {
         let g = graph_data();
-        let d = BreadthFirstPaths::new(g, 1);
+        let d = BreadthFirstPaths::new(&g, 1);
         assert!(d.has_path_to(0));
     }
 
     #[test]
     fn path_to_test() {
         let g = graph_data();
-        let d = BreadthFirstPaths::new(g, 0);
+        let d = BreadthFirstPaths::new(&g, 0);
         let mut list = d.path_to(1);
         assert_eq!(Some(0), list.pop());
         assert_eq!(Some(1), list.pop());
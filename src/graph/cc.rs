@@ -1,6 +1,6 @@
 //! 连通分量。
 
-use super::Graph;
+use super::AdjacencyGraph;
 
 pub struct CC {
     marked: Vec<bool>,
@@ -9,7 +9,7 @@ pub struct CC {
 }
 
 impl CC {
-    pub fn new(g: Graph) -> Self {
+    pub fn new<G: AdjacencyGraph>(g: &G) -> Self {
         let marked = vec![false; g.v()];
         let id = vec![0; g.v()];
         let mut c = Self {
@@ -19,19 +19,28 @@ impl CC {
         };
         for s in 0..g.v() {
             if !c.marked[s] {
-                c.dfs(&g, s);
+                c.dfs(g, s);
                 c.count += 1;
             }
         }
         c
     }
 
-    fn dfs(&mut self, g: &Graph, s: usize) {
+    fn dfs<G: AdjacencyGraph>(&mut self, g: &G, s: usize) {
         self.marked[s] = true;
         self.id[s] = self.count;
-        for w in g.adj(s) {
-            if !self.marked[*w as usize] {
-                self.dfs(g, *w as usize);
+        let mut stack = vec![g.adj(s)];
+        while let Some(adj) = stack.last_mut() {
+            match adj.next() {
+                Some(w) if !self.marked[w] => {
+                    self.marked[w] = true;
+                    self.id[w] = self.count;
+                    stack.push(g.adj(w));
+                }
+                Some(_) => {}
+                None => {
+                    stack.pop();
+                }
             }
         }
     }
@@ -60,21 +69,21 @@ mod tests {
     #[test]
     fn connected_test() {
         let g = graph_data();
-        let d = CC::new(g);
+        let d = CC::new(&g);
         assert!(d.connected(0, 1));
     }
 
     #[test]
     fn count_test() {
         let g = graph_data();
-        let d = CC::new(g);
+        let d = CC::new(&g);
         assert_eq!(1, d.count());
     }
 
     #[test]
     fn id_test() {
         let g = graph_data();
-        let d = CC::new(g);
+        let d = CC::new(&g);
         assert_eq!(0, d.id(1));
     }
 }
@@ -0,0 +1,130 @@
+//! 优先级限制下的并行任务调度的关键路径方法。
+
+use super::{
+    acyclic_lp::AcyclicLP, acyclic_sp::NotDagError, directed_edge::DirectedEdge,
+    edge_weighted_digraph::EdgeWeightedDigraph,
+};
+
+/// 待调度的任务。
+#[derive(Debug, Clone)]
+pub struct Job {
+    duration: f64,          // 任务的耗时。
+    successors: Vec<usize>, // 必须在该任务完成后才能开始的任务。
+}
+
+impl Job {
+    /// 创建一个新的 [`Job`]。
+    pub fn new(duration: f64, successors: Vec<usize>) -> Self {
+        Self {
+            duration,
+            successors,
+        }
+    }
+}
+
+pub struct CriticalPathMethod {
+    n: usize,      // 任务数。
+    lp: AcyclicLP, // 起点到各顶点的最长路径。
+}
+
+impl CriticalPathMethod {
+    /// 计算所有任务的最早开始时间。
+    ///
+    /// 任务 i 对应顶点 i（开始）和 i + n（结束），2n 和 2n + 1 分别为总起点和总终点。
+    /// 优先级限制中有环时返回 [`NotDagError`]。后继任务不存在时 panic。
+    pub fn new(jobs: &[Job]) -> Result<Self, NotDagError> {
+        let n = jobs.len();
+        for (i, job) in jobs.iter().enumerate() {
+            if let Some(&j) = job.successors.iter().find(|&&j| j >= n) {
+                panic!("任务 {i} 的后继任务 {j} 不存在，共有 {n} 个任务");
+            }
+        }
+        let (s, t) = (2 * n, 2 * n + 1);
+        let mut g = EdgeWeightedDigraph::new(2 * n + 2);
+        for (i, job) in jobs.iter().enumerate() {
+            g.add_edge(DirectedEdge::new(s, i, 0.0));
+            g.add_edge(DirectedEdge::new(i + n, t, 0.0));
+            g.add_edge(DirectedEdge::new(i, i + n, job.duration));
+            for &j in &job.successors {
+                g.add_edge(DirectedEdge::new(i + n, j, 0.0));
+            }
+        }
+
+        let lp = AcyclicLP::new(&g, s)?;
+        Ok(Self { n, lp })
+    }
+
+    /// 任务 i 的最早开始时间。
+    pub fn start_time(&self, i: usize) -> f64 {
+        self.lp.dist_to(i)
+    }
+
+    /// 所有任务的最早开始时间。
+    pub fn start_times(&self) -> Vec<f64> {
+        (0..self.n).map(|i| self.start_time(i)).collect()
+    }
+
+    /// 全部任务完成的时间。
+    pub fn finish_time(&self) -> f64 {
+        self.lp.dist_to(2 * self.n + 1)
+    }
+
+    /// 关键路径上的任务，按开始时间排列。
+    pub fn critical_path(&self) -> Vec<usize> {
+        self.lp
+            .path_to(2 * self.n + 1)
+            .iter()
+            .map(|e| e.from())
+            .filter(|&v| v < self.n)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_times_test() {
+        let c = create();
+        let expect = [0.0, 41.0, 123.0, 91.0, 70.0, 0.0, 70.0, 41.0, 91.0, 41.0];
+        assert_eq!(&expect, &c.start_times()[..]);
+        assert_eq!(173.0, c.finish_time());
+    }
+
+    #[test]
+    fn critical_path_test() {
+        let c = create();
+        assert_eq!(&[0, 9, 6, 8, 2], &c.critical_path()[..]);
+    }
+
+    #[test]
+    fn cycle_test() {
+        let jobs = [Job::new(1.0, vec![1]), Job::new(1.0, vec![0])];
+        assert!(CriticalPathMethod::new(&jobs).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "任务 1 的后继任务 2 不存在")]
+    fn successor_out_of_range_test() {
+        let jobs = [Job::new(1.0, vec![1]), Job::new(1.0, vec![2])];
+        let _ = CriticalPathMethod::new(&jobs);
+    }
+
+    // 《算法》中的 jobsPC.txt。
+    fn create() -> CriticalPathMethod {
+        let jobs = [
+            Job::new(41.0, vec![1, 7, 9]),
+            Job::new(51.0, vec![2]),
+            Job::new(50.0, vec![]),
+            Job::new(36.0, vec![]),
+            Job::new(38.0, vec![]),
+            Job::new(45.0, vec![]),
+            Job::new(21.0, vec![3, 8]),
+            Job::new(32.0, vec![3, 8]),
+            Job::new(32.0, vec![2]),
+            Job::new(29.0, vec![4, 6]),
+        ];
+        CriticalPathMethod::new(&jobs).unwrap()
+    }
+}
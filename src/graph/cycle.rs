@@ -1,6 +1,6 @@
 //! 环形检测。
 
-use super::Graph;
+use super::AdjacencyGraph;
 
 pub struct Cycle {
     marked: Vec<bool>,
@@ -8,7 +8,7 @@ pub struct Cycle {
 }
 
 impl Cycle {
-    pub fn new(g: Graph) -> Self {
+    pub fn new<G: AdjacencyGraph>(g: &G) -> Self {
         let marked = vec![false; g.v()];
         let mut c = Self {
             marked,
@@ -16,19 +16,28 @@ impl Cycle {
         };
         for s in 0..g.v() {
             if !c.marked[s] {
-                c.dfs(&g, s, s);
+                c.dfs(g, s, s);
             }
         }
         c
     }
 
-    fn dfs(&mut self, g: &Graph, v: usize, u: usize) {
-        self.marked[v] = true;
-        for w in g.adj(v) {
-            if !self.marked[*w] {
-                self.dfs(g, *w, v);
-            } else if *w != u {
-                self.has_cycle = true;
+    fn dfs<G: AdjacencyGraph>(&mut self, g: &G, s: usize, u: usize) {
+        // 栈中的每一项是顶点、它的父顶点和尚未检查的邻接顶点。
+        self.marked[s] = true;
+        let mut stack = vec![(s, u, g.adj(s))];
+        while let Some((v, u, adj)) = stack.last_mut() {
+            let (v, u) = (*v, *u);
+            match adj.next() {
+                Some(w) if !self.marked[w] => {
+                    self.marked[w] = true;
+                    stack.push((w, v, g.adj(w)));
+                }
+                Some(w) if w != u => self.has_cycle = true,
+                Some(_) => {}
+                None => {
+                    stack.pop();
+                }
             }
         }
     }
@@ -46,7 +55,7 @@ mod tests {
 
     #[test]
     fn has_cycle_test() {
-        let c = Cycle::new(graph_data());
+        let c = Cycle::new(&graph_data());
         assert!(c.has_cycle());
     }
 }
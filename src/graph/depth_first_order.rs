@@ -2,7 +2,7 @@
 
 use std::{iter::Rev, slice::Iter};
 
-use super::digraph::Digraph;
+use super::AdjacencyGraph;
 
 pub struct DepthFirstOrder {
     marked: Vec<bool>,
@@ -12,7 +12,7 @@ pub struct DepthFirstOrder {
 }
 
 impl DepthFirstOrder {
-    pub fn new(g: &mut Digraph) -> Self {
+    pub fn new<G: AdjacencyGraph>(g: &G) -> Self {
         let mut d = DepthFirstOrder {
             marked: vec![false; g.v()],
             pre: vec![],
@@ -21,36 +21,47 @@ impl DepthFirstOrder {
         };
         for v in 0..g.v() {
             if !d.marked[v] {
-                d.dfs(&g, v);
+                d.dfs(g, v);
             }
         }
         d
     }
 
-    fn dfs(&mut self, g: &Digraph, v: usize) {
-        self.pre.push(v);
-        self.marked[v] = true;
-        for w in g.adj(v) {
-            if !self.marked[*w] {
-                self.dfs(g, *w);
+    fn dfs<G: AdjacencyGraph>(&mut self, g: &G, s: usize) {
+        // 顶点入栈时记录前序，邻接顶点检查完毕出栈时记录后序，与递归的顺序相同。
+        self.pre.push(s);
+        self.marked[s] = true;
+        let mut stack = vec![(s, g.adj(s))];
+        while let Some((v, adj)) = stack.last_mut() {
+            let v = *v;
+            match adj.next() {
+                Some(w) if !self.marked[w] => {
+                    self.pre.push(w);
+                    self.marked[w] = true;
+                    stack.push((w, g.adj(w)));
+                }
+                Some(_) => {}
+                None => {
+                    self.post.push(v);
+                    self.reverse_post.push(v);
+                    stack.pop();
+                }
             }
         }
-        self.post.push(v);
-        self.reverse_post.push(v);
     }
 
     /// 获取前序排列。
-    pub fn pre(&self) -> Iter<usize> {
+    pub fn pre(&self) -> Iter<'_, usize> {
         self.pre.iter()
     }
 
     /// 获取后序排列。
-    pub fn post(&self) -> Iter<usize> {
+    pub fn post(&self) -> Iter<'_, usize> {
         self.post.iter()
     }
 
     /// 获取逆后序排列。
-    pub fn reverse_post(&self) -> Rev<Iter<usize>> {
+    pub fn reverse_post(&self) -> Rev<Iter<'_, usize>> {
         self.reverse_post.iter().rev()
     }
 }
@@ -58,6 +69,7 @@ impl DepthFirstOrder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::digraph::Digraph;
 
     #[test]
     fn pre_test() {
@@ -76,16 +88,68 @@ mod tests {
     #[test]
     fn reverse_post_test() {
         let d = create();
-        let post: Vec<usize> = d.reverse_post().map(|i| *i).collect();
+        let post: Vec<usize> = d.reverse_post().copied().collect();
         assert_eq!(&[6, 1, 3, 5, 2, 4, 0], &post[..]);
     }
 
+    #[test]
+    fn recursive_test() {
+        // 与递归实现比较随机有向图上的前序和后序。
+        let mut seed = 7u64;
+        let mut rand = |n: usize| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as usize % n
+        };
+        for _ in 0..50 {
+            let v = 1 + rand(30);
+            let mut g = Digraph::new(v);
+            for _ in 0..rand(3 * v) {
+                g.add_edge(rand(v), rand(v));
+            }
+
+            let d = DepthFirstOrder::new(&g);
+            let (pre, post) = recursive(&g);
+            assert_eq!(pre, d.pre().as_slice());
+            assert_eq!(post, d.post().as_slice());
+        }
+    }
+
+    fn recursive(g: &Digraph) -> (Vec<usize>, Vec<usize>) {
+        fn dfs(
+            g: &Digraph,
+            v: usize,
+            marked: &mut [bool],
+            pre: &mut Vec<usize>,
+            post: &mut Vec<usize>,
+        ) {
+            marked[v] = true;
+            pre.push(v);
+            for &w in g.adj(v) {
+                if !marked[w] {
+                    dfs(g, w, marked, pre, post);
+                }
+            }
+            post.push(v);
+        }
+
+        let (mut pre, mut post) = (vec![], vec![]);
+        let mut marked = vec![false; g.v()];
+        for v in 0..g.v() {
+            if !marked[v] {
+                dfs(g, v, &mut marked, &mut pre, &mut post);
+            }
+        }
+        (pre, post)
+    }
+
     fn create() -> DepthFirstOrder {
         let mut g = Digraph::new(7);
         g.add_edge(1, 2);
         g.add_edge(1, 3);
         g.add_edge(2, 4);
         g.add_edge(3, 5);
-        DepthFirstOrder::new(&mut g)
+        DepthFirstOrder::new(&g)
     }
 }
@@ -1,6 +1,6 @@
 //! 深度优先搜索。
 
-use super::Graph;
+use super::AdjacencyGraph;
 
 pub struct DepthFirstSearch {
     marked: Vec<bool>,   // 是否已访问过。
@@ -10,21 +10,31 @@ pub struct DepthFirstSearch {
 
 impl DepthFirstSearch {
     /// 创建一个图的深度优先结果。
-    pub fn new(g: Graph, s: usize) -> Self {
+    pub fn new<G: AdjacencyGraph>(g: &G, s: usize) -> Self {
         let marked = vec![false; g.v()];
         let edge_to = vec![0; g.v()];
         let mut d = Self { marked, edge_to, s };
-        d.dfs(&g, s);
+        d.dfs(g, s);
         d
     }
 
     /// 深度优先算法。
-    fn dfs(&mut self, g: &Graph, s: usize) {
+    fn dfs<G: AdjacencyGraph>(&mut self, g: &G, s: usize) {
+        // 栈中保存顶点和它尚未检查的邻接顶点，代替递归调用。
         self.marked[s] = true;
-        for w in g.adj(s) {
-            if !self.marked[*w] {
-                self.edge_to[*w] = s;
-                self.dfs(g, *w);
+        let mut stack = vec![(s, g.adj(s))];
+        while let Some((v, adj)) = stack.last_mut() {
+            let v = *v;
+            match adj.next() {
+                Some(w) if !self.marked[w] => {
+                    self.marked[w] = true;
+                    self.edge_to[w] = v;
+                    stack.push((w, g.adj(w)));
+                }
+                Some(_) => {}
+                None => {
+                    stack.pop();
+                }
             }
         }
     }
@@ -59,14 +69,14 @@ mod tests {
     #[test]
     fn has_path_to_test() {
         let g = graph_data();
-        let d = DepthFirstSearch::new(g, 1);
+        let d = DepthFirstSearch::new(&g, 1);
         assert!(d.has_path_to(0));
     }
 
     #[test]
     fn path_to_test() {
         let g = graph_data();
-        let d = DepthFirstSearch::new(g, 0);
+        let d = DepthFirstSearch::new(&g, 0);
         let mut list = d.path_to(1);
         assert_eq!(Some(0), list.pop());
         assert_eq!(Some(2), list.pop());
@@ -1,5 +1,9 @@
 //! 有向图。
 
+use std::{ops::Index, vec};
+
+use super::{AdjacencyGraph, Edges};
+
 #[derive(Debug)]
 pub struct Digraph {
     v: usize,             // 顶点数。
@@ -38,6 +42,19 @@ impl Digraph {
         self.e
     }
 
+    /// 获取所有的边。
+    pub fn edges(&self) -> Edges<'_> {
+        Edges::new(&self.adj, true)
+    }
+
+    /// 顶点不足时扩充到 v + 1 个。
+    pub(super) fn ensure_vertex(&mut self, v: usize) {
+        if v >= self.v {
+            self.v = v + 1;
+            self.adj.resize(self.v, vec![]);
+        }
+    }
+
     /// 创建一个反向的图。
     pub fn reverse(&self) -> Self {
         let mut r = Self::new(self.v);
@@ -49,3 +66,77 @@ impl Digraph {
         r
     }
 }
+
+impl AdjacencyGraph for Digraph {
+    fn v(&self) -> usize {
+        self.v
+    }
+
+    fn adj(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adj[v].iter().copied()
+    }
+}
+
+impl Index<usize> for Digraph {
+    type Output = [usize];
+
+    /// 获取从 v 指出的边所连接的顶点。
+    fn index(&self, v: usize) -> &[usize] {
+        self.adj(v)
+    }
+}
+
+impl Extend<(usize, usize)> for Digraph {
+    /// 添加边，顶点不足时自动扩充。
+    fn extend<I: IntoIterator<Item = (usize, usize)>>(&mut self, iter: I) {
+        for (v, w) in iter {
+            self.ensure_vertex(v.max(w));
+            self.add_edge(v, w);
+        }
+    }
+}
+
+impl FromIterator<(usize, usize)> for Digraph {
+    /// 由边创建图，顶点数为最大的顶点加一。
+    fn from_iter<I: IntoIterator<Item = (usize, usize)>>(iter: I) -> Self {
+        let mut g = Self::new(0);
+        g.extend(iter);
+        g
+    }
+}
+
+impl IntoIterator for Digraph {
+    type Item = (usize, usize);
+    type IntoIter = vec::IntoIter<(usize, usize)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.edges().collect::<Vec<_>>().into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Digraph {
+    type Item = (usize, usize);
+    type IntoIter = Edges<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.edges()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iter_test() {
+        let mut g: Digraph = [(0, 1), (1, 0)].into_iter().collect();
+        g.extend([(3, 3), (1, 2)]);
+        assert_eq!(4, g.v());
+        assert_eq!(&[0, 2], &g[1]);
+
+        let edges: Vec<_> = (&g).into_iter().collect();
+        assert_eq!(vec![(0, 1), (1, 0), (1, 2), (3, 3)], edges);
+        let reversed: Vec<_> = g.reverse().into_iter().collect();
+        assert_eq!(vec![(0, 1), (1, 0), (2, 1), (3, 3)], reversed);
+    }
+}
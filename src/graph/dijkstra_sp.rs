@@ -0,0 +1,159 @@
+//! 最短路径的 Dijkstra 算法。
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use super::{directed_edge::DirectedEdge, edge_weighted_digraph::EdgeWeightedDigraph};
+use crate::sort::index_min_pq::IndexMinPQ;
+
+pub struct DijkstraSP {
+    edge_to: Vec<Option<DirectedEdge>>, // 最短路径树中指向顶点的边。
+    dist_to: Vec<f64>,                  // 起点到顶点的最短距离。
+}
+
+impl DijkstraSP {
+    /// 计算从 s 出发的最短路径树。
+    ///
+    /// 图中存在负权重的边时返回 [`NegativeWeightError`]。
+    pub fn new(g: &EdgeWeightedDigraph, s: usize) -> Result<Self, NegativeWeightError> {
+        if let Some(e) = (0..g.v()).flat_map(|v| g.adj(v)).find(|e| e.weight() < 0.0) {
+            return Err(NegativeWeightError { edge: e.clone() });
+        }
+
+        let mut d = Self {
+            edge_to: vec![None; g.v()],
+            dist_to: vec![f64::INFINITY; g.v()],
+        };
+        let mut pq = IndexMinPQ::new(g.v());
+
+        d.dist_to[s] = 0.0;
+        pq.insert(s, 0.0);
+        while let Some(v) = pq.delete_min() {
+            d.relax(g, v, &mut pq);
+        }
+
+        Ok(d)
+    }
+
+    /// 放松从 v 指出的所有边。
+    fn relax(&mut self, g: &EdgeWeightedDigraph, v: usize, pq: &mut IndexMinPQ<f64>) {
+        for e in g.adj(v) {
+            let w = e.to();
+            if self.dist_to[w] > self.dist_to[v] + e.weight() {
+                self.dist_to[w] = self.dist_to[v] + e.weight();
+                self.edge_to[w] = Some(e.clone());
+                if pq.contains(w) {
+                    pq.decrease_key(w, self.dist_to[w]);
+                } else {
+                    pq.insert(w, self.dist_to[w]);
+                }
+            }
+        }
+    }
+
+    /// 起点到 v 的最短距离，不可达时为无穷大。
+    pub fn dist_to(&self, v: usize) -> f64 {
+        self.dist_to[v]
+    }
+
+    /// 是否存在起点到 v 的路径。
+    pub fn has_path_to(&self, v: usize) -> bool {
+        self.dist_to[v] < f64::INFINITY
+    }
+
+    /// 最短路径树中指向 v 的边，v 为起点或不可达时为 [`None`]。
+    pub fn edge_to(&self, v: usize) -> Option<&DirectedEdge> {
+        self.edge_to[v].as_ref()
+    }
+
+    /// 获取起点到 v 的最短路径，边按从起点到 v 的顺序排列。
+    pub fn path_to(&self, v: usize) -> Vec<&DirectedEdge> {
+        let mut path = vec![];
+        let mut e = self.edge_to[v].as_ref();
+        while let Some(x) = e {
+            path.push(x);
+            e = self.edge_to[x.from()].as_ref();
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// 图中含有负权重的边。
+#[derive(Debug, Clone)]
+pub struct NegativeWeightError {
+    edge: DirectedEdge,
+}
+
+impl NegativeWeightError {
+    /// 获取负权重的边。
+    pub fn edge(&self) -> &DirectedEdge {
+        &self.edge
+    }
+}
+
+impl Display for NegativeWeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "边 {}->{} 的权重 {:.2} 为负数",
+            self.edge.from(),
+            self.edge.to(),
+            self.edge.weight()
+        )
+    }
+}
+
+impl Error for NegativeWeightError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::tiny_ewd;
+
+    #[test]
+    fn dist_to_test() {
+        let d = create();
+        let expect = [0.0, 1.05, 0.26, 0.99, 0.38, 0.73, 1.51, 0.60];
+        for (v, dist) in expect.iter().enumerate() {
+            assert!((d.dist_to(v) - dist).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn path_to_test() {
+        let d = create();
+        let path: Vec<(usize, usize)> = d.path_to(6).iter().map(|e| (e.from(), e.to())).collect();
+        assert_eq!(&[(0, 2), (2, 7), (7, 3), (3, 6)], &path[..]);
+        assert!(d.path_to(0).is_empty());
+        assert_eq!(Some(3), d.edge_to(6).map(|e| e.from()));
+        assert!(d.edge_to(0).is_none());
+    }
+
+    #[test]
+    fn has_path_to_test() {
+        let mut g = EdgeWeightedDigraph::new(3);
+        g.add_edge(DirectedEdge::new(0, 1, 1.0));
+        g.add_edge(DirectedEdge::new(2, 0, 1.0));
+        let d = DijkstraSP::new(&g, 0).unwrap();
+        assert!(d.has_path_to(1));
+        assert!(!d.has_path_to(2));
+        assert_eq!(f64::INFINITY, d.dist_to(2));
+        assert!(d.path_to(2).is_empty());
+    }
+
+    #[test]
+    fn negative_weight_test() {
+        let mut g = tiny_ewd();
+        g.add_edge(DirectedEdge::new(3, 1, -0.5));
+        let err = DijkstraSP::new(&g, 0).err().unwrap();
+        assert_eq!(3, err.edge().from());
+        assert_eq!("边 3->1 的权重 -0.50 为负数", err.to_string());
+    }
+
+    fn create() -> DijkstraSP {
+        DijkstraSP::new(&tiny_ewd(), 0).unwrap()
+    }
+}
@@ -0,0 +1,155 @@
+//! 最大流的 Dinic 算法。
+
+use std::{collections::VecDeque, slice::Iter};
+
+use super::{
+    flow_edge::{FlowEdge, EPSILON},
+    flow_network::FlowNetwork,
+};
+
+pub struct Dinic {
+    network: FlowNetwork,      // 记录最大流的网络副本。
+    level: Vec<Option<usize>>, // 顶点在层次图中的层数。
+    it: Vec<usize>,            // 顶点下一条待尝试的边在邻接表中的位置。
+    value: f64,                // 当前的流量值。
+}
+
+impl Dinic {
+    /// 计算网络 g 中从 s 到 t 的最大流。
+    ///
+    /// # Panics
+    ///
+    /// s 与 t 相同时 panic。
+    pub fn new(g: &FlowNetwork, s: usize, t: usize) -> Self {
+        assert_ne!(s, t, "源点与汇点不能相同");
+        let mut d = Self {
+            network: g.clone(),
+            level: vec![None; g.v()],
+            it: vec![0; g.v()],
+            value: g.excess(t),
+        };
+
+        while d.build_level_graph(s, t) {
+            // 在层次图中不断寻找阻塞流。
+            d.it.fill(0);
+            loop {
+                let pushed = d.dfs(s, t, f64::INFINITY);
+                if pushed <= EPSILON {
+                    break;
+                }
+                d.value += pushed;
+            }
+        }
+
+        d
+    }
+
+    /// 广度优先搜索剩余网络，为顶点分层，返回 t 是否可达。
+    fn build_level_graph(&mut self, s: usize, t: usize) -> bool {
+        self.level.fill(None);
+
+        let mut queue = VecDeque::new();
+        queue.push_back(s);
+        self.level[s] = Some(0);
+        while let Some(v) = queue.pop_front() {
+            for &i in self.network.adj(v) {
+                let e = self.network.edge(i);
+                let w = e.other(v).expect("边必定与顶点相连");
+                if self.level[w].is_none() && e.residual_capacity_to(w) > EPSILON {
+                    self.level[w] = self.level[v].map(|l| l + 1);
+                    queue.push_back(w);
+                }
+            }
+        }
+
+        self.level[t].is_some()
+    }
+
+    /// 沿层次图从 v 向 t 推送至多 limit 的流量，返回实际推送的流量。
+    fn dfs(&mut self, v: usize, t: usize, limit: f64) -> f64 {
+        if v == t {
+            return limit;
+        }
+
+        while self.it[v] < self.network.adj(v).len() {
+            let i = self.network.adj(v)[self.it[v]];
+            let e = self.network.edge(i);
+            let w = e.other(v).expect("边必定与顶点相连");
+            let residual = e.residual_capacity_to(w);
+            if residual > EPSILON && self.level[w] == self.level[v].map(|l| l + 1) {
+                let pushed = self.dfs(w, t, limit.min(residual));
+                if pushed > EPSILON {
+                    self.network.edge_mut(i).add_residual_flow_to(w, pushed);
+                    return pushed;
+                }
+            }
+            self.it[v] += 1;
+        }
+
+        0.0
+    }
+
+    /// 最大流的流量值。
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// v 是否在最小切分中 s 的一侧。
+    pub fn in_cut(&self, v: usize) -> bool {
+        self.level[v].is_some()
+    }
+
+    /// 索引为 i 的边上的流量。
+    pub fn flow(&self, i: usize) -> f64 {
+        self.network.edge(i).flow()
+    }
+
+    /// 获取所有的边及其流量，顺序与网络中添加的顺序相同。
+    pub fn edges(&self) -> Iter<'_, FlowEdge> {
+        self.network.edges()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{edmonds_karp::EdmondsKarp, tiny_fn};
+
+    #[test]
+    fn value_test() {
+        let g = tiny_fn();
+        let d = Dinic::new(&g, 0, 5);
+        let f = EdmondsKarp::new(&g, 0, 5);
+        assert_eq!(f.value(), d.value());
+        for v in 0..g.v() {
+            assert_eq!(f.in_cut(v), d.in_cut(v));
+        }
+
+        let mut net = g.clone();
+        for (i, e) in d.edges().enumerate() {
+            net.edge_mut(i).add_residual_flow_to(e.to(), e.flow());
+        }
+        assert!(net.is_feasible(0, 5));
+    }
+
+    #[test]
+    fn bipartite_matching_test() {
+        // 左侧 1..=3，右侧 4..=6，源点 0，汇点 7。
+        let mut g = FlowNetwork::new(8);
+        for &(l, r) in &[(1, 4), (1, 5), (2, 4), (3, 4)] {
+            g.add_edge(FlowEdge::new(l, r, 1.0));
+        }
+        for v in 1..=3 {
+            g.add_edge(FlowEdge::new(0, v, 1.0));
+            g.add_edge(FlowEdge::new(v + 3, 7, 1.0));
+        }
+
+        let d = Dinic::new(&g, 0, 7);
+        assert_eq!(2.0, d.value());
+        let matched = d
+            .edges()
+            .filter(|e| e.from() != 0 && e.to() != 7 && e.flow() == 1.0)
+            .count();
+        assert_eq!(2, matched);
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::{iter::Rev, slice::Iter};
 
-use super::digraph::Digraph;
+use super::AdjacencyGraph;
 
 pub struct DirectedCycle {
     marked: Vec<bool>,
@@ -13,7 +13,7 @@ pub struct DirectedCycle {
 
 impl DirectedCycle {
     /// 创建一个 [`DirectedCycle`]。
-    pub fn new(g: &mut Digraph) -> Self {
+    pub fn new<G: AdjacencyGraph>(g: &G) -> Self {
         let mut d = Self {
             marked: vec![false; g.v()],
             edge_to: vec![0; g.v()],
@@ -28,28 +28,39 @@ impl DirectedCycle {
         d
     }
 
-    fn dfs(&mut self, g: &Digraph, v: usize) {
-        self.on_stack[v] = true;
-        self.marked[v] = true;
-        for w in g.adj(v) {
+    fn dfs<G: AdjacencyGraph>(&mut self, g: &G, s: usize) {
+        self.on_stack[s] = true;
+        self.marked[s] = true;
+        let mut stack = vec![(s, g.adj(s))];
+        while let Some((v, adj)) = stack.last_mut() {
             if self.has_cycle() {
                 return;
             }
-            if !self.marked[*w] {
-                self.edge_to[*w] = v;
-                self.dfs(g, *w);
-            } else if self.on_stack[*w] {
-                self.cycle.clear();
-                let mut x = v;
-                while x != *w {
-                    self.cycle.push(x);
-                    x = self.edge_to[x];
+            let v = *v;
+            match adj.next() {
+                Some(w) if !self.marked[w] => {
+                    self.edge_to[w] = v;
+                    self.on_stack[w] = true;
+                    self.marked[w] = true;
+                    stack.push((w, g.adj(w)));
+                }
+                Some(w) if self.on_stack[w] => {
+                    self.cycle.clear();
+                    let mut x = v;
+                    while x != w {
+                        self.cycle.push(x);
+                        x = self.edge_to[x];
+                    }
+                    self.cycle.push(w);
+                    self.cycle.push(v);
+                }
+                Some(_) => {}
+                None => {
+                    self.on_stack[v] = false;
+                    stack.pop();
                 }
-                self.cycle.push(*w);
-                self.cycle.push(v);
             }
         }
-        self.on_stack[v] = false;
     }
 
     /// 是否有环。
@@ -58,7 +69,7 @@ impl DirectedCycle {
     }
 
     /// 获取有向环中的所有顶点。
-    pub fn cycle(&self) -> Rev<Iter<usize>> {
+    pub fn cycle(&self) -> Rev<Iter<'_, usize>> {
         self.cycle.iter().rev()
     }
 }
@@ -66,6 +77,7 @@ impl DirectedCycle {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::digraph::Digraph;
 
     #[test]
     fn has_cycle_test() {
@@ -89,6 +101,6 @@ mod tests {
         g.add_edge(1, 2);
         g.add_edge(2, 3);
         g.add_edge(3, 1);
-        DirectedCycle::new(&mut g)
+        DirectedCycle::new(&g)
     }
 }
@@ -1,25 +1,45 @@
 //! 有向图的可达性。
 
-use super::digraph::Digraph;
+use super::AdjacencyGraph;
 
 pub struct DirectedDFS {
     marked: Vec<bool>,
 }
 
 impl DirectedDFS {
-    pub fn new(g: &Digraph, s: usize) -> Self {
+    pub fn new<G: AdjacencyGraph>(g: &G, s: usize) -> Self {
+        Self::from_sources(g, [s])
+    }
+
+    /// 计算从 sources 中任意顶点出发可达的所有顶点。
+    pub fn from_sources<G: AdjacencyGraph, I>(g: &G, sources: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
         let mut d = Self {
             marked: vec![false; g.v()],
         };
-        d.dfs(&g, s);
+        for s in sources {
+            if !d.marked[s] {
+                d.dfs(g, s);
+            }
+        }
         d
     }
 
-    fn dfs(&mut self, g: &Digraph, s: usize) {
+    fn dfs<G: AdjacencyGraph>(&mut self, g: &G, s: usize) {
         self.marked[s] = true;
-        for w in g.adj(s) {
-            if !self.marked[*w] {
-                self.dfs(g, *w);
+        let mut stack = vec![g.adj(s)];
+        while let Some(adj) = stack.last_mut() {
+            match adj.next() {
+                Some(w) if !self.marked[w] => {
+                    self.marked[w] = true;
+                    stack.push(g.adj(w));
+                }
+                Some(_) => {}
+                None => {
+                    stack.pop();
+                }
             }
         }
     }
@@ -33,6 +53,7 @@ impl DirectedDFS {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::digraph::Digraph;
 
     #[test]
     fn test() {
@@ -40,6 +61,16 @@ mod tests {
         assert!(d.marked(1));
     }
 
+    #[test]
+    fn from_sources_test() {
+        let mut g = Digraph::new(6);
+        g.add_edge(0, 1);
+        g.add_edge(2, 3);
+        let d = DirectedDFS::from_sources(&g, [0, 2]);
+        let marked: Vec<usize> = (0..g.v()).filter(|&v| d.marked(v)).collect();
+        assert_eq!(&[0, 1, 2, 3], &marked[..]);
+    }
+
     fn create() -> DirectedDFS {
         let mut g = Digraph::new(6);
         g.add_edge(1, 3);
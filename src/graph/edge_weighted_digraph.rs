@@ -1,9 +1,10 @@
 //! 加权有向图。
 
-use std::slice::Iter;
+use std::{ops::Index, slice::Iter, vec};
 
-use super::directed_edge::DirectedEdge;
+use super::{directed_edge::DirectedEdge, AdjacencyGraph};
 
+#[derive(Debug)]
 pub struct EdgeWeightedDigraph {
     v: usize,                    // 顶点的总数。
     e: usize,                    // 边的总数。
@@ -11,7 +12,7 @@ pub struct EdgeWeightedDigraph {
 }
 
 impl EdgeWeightedDigraph {
-    /// Creates a new [`EdgeWeightedDigraph`].
+    /// 创建一个新的 [`EdgeWeightedDigraph`]。
     pub fn new(v: usize) -> Self {
         Self {
             v,
@@ -37,14 +38,83 @@ impl EdgeWeightedDigraph {
     }
 
     /// 获取从 v 指出的边。
-    pub fn adj(&self, v: usize) -> Iter<DirectedEdge> {
+    pub fn adj(&self, v: usize) -> Iter<'_, DirectedEdge> {
         self.adj[v].iter()
     }
 
     /// 获取所有的边。
-    pub fn edges(&self) -> Edges {
+    pub fn edges(&self) -> Edges<'_> {
         Edges::new(self.adj.iter())
     }
+
+    /// 顶点不足时扩充到 v + 1 个。
+    pub(super) fn ensure_vertex(&mut self, v: usize) {
+        if v >= self.v {
+            self.v = v + 1;
+            self.adj.resize(self.v, vec![]);
+        }
+    }
+}
+
+impl AdjacencyGraph for EdgeWeightedDigraph {
+    fn v(&self) -> usize {
+        self.v
+    }
+
+    fn adj(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adj[v].iter().map(|e| e.to())
+    }
+}
+
+impl Index<usize> for EdgeWeightedDigraph {
+    type Output = [DirectedEdge];
+
+    /// 获取从 i 指出的边。
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.adj[i]
+    }
+}
+
+impl Extend<DirectedEdge> for EdgeWeightedDigraph {
+    /// 添加边，顶点不足时自动扩充。
+    fn extend<I: IntoIterator<Item = DirectedEdge>>(&mut self, iter: I) {
+        for e in iter {
+            self.ensure_vertex(e.from().max(e.to()));
+            self.add_edge(e);
+        }
+    }
+}
+
+impl FromIterator<DirectedEdge> for EdgeWeightedDigraph {
+    /// 由边创建图，顶点数为最大的顶点加一。
+    fn from_iter<I: IntoIterator<Item = DirectedEdge>>(iter: I) -> Self {
+        let mut g = Self::new(0);
+        g.extend(iter);
+        g
+    }
+}
+
+impl IntoIterator for EdgeWeightedDigraph {
+    type Item = DirectedEdge;
+    type IntoIter = vec::IntoIter<DirectedEdge>;
+
+    /// 按起点的顺序取出所有的边。
+    fn into_iter(self) -> Self::IntoIter {
+        self.adj
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a EdgeWeightedDigraph {
+    type Item = &'a DirectedEdge;
+    type IntoIter = Edges<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.edges()
+    }
 }
 
 pub struct Edges<'a> {
@@ -62,12 +132,35 @@ impl<'a> Iterator for Edges<'a> {
     type Item = &'a DirectedEdge;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr.is_none() {
-            if let Some(next) = self.iter.next() {
-                self.curr = Some(next.iter());
+        loop {
+            if let Some(e) = self.curr.as_mut().and_then(|i| i.next()) {
+                return Some(e);
             }
+            // 当前顶点的边已取完，转到下一个顶点。
+            self.curr = Some(self.iter.next()?.iter());
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iter_test() {
+        let mut g: EdgeWeightedDigraph =
+            [DirectedEdge::new(0, 1, 0.5), DirectedEdge::new(2, 0, 0.2)]
+                .into_iter()
+                .collect();
+        g.extend([DirectedEdge::new(0, 3, 0.3)]);
+        assert_eq!(4, g.v());
+        assert_eq!(3, g.e());
+        assert_eq!(2, g[0].len());
+        assert!(g[1].is_empty());
 
-        self.curr.as_mut().map_or(None, |i| i.next())
+        // 跨越多个顶点的邻接表，包括空的邻接表。
+        let weights: Vec<f64> = (&g).into_iter().map(|e| e.weight()).collect();
+        assert_eq!(vec![0.5, 0.3, 0.2], weights);
+        assert_eq!(3, g.into_iter().count());
     }
 }
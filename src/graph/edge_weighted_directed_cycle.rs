@@ -0,0 +1,109 @@
+//! 加权有向图中的有向环。
+
+use super::{directed_edge::DirectedEdge, edge_weighted_digraph::EdgeWeightedDigraph};
+
+pub struct EdgeWeightedDirectedCycle {
+    marked: Vec<bool>,
+    edge_to: Vec<Option<DirectedEdge>>, // 指向顶点的边。
+    cycle: Vec<DirectedEdge>,           // 有向环中的边。
+    on_stack: Vec<bool>,                // 搜索栈上的顶点。
+}
+
+impl EdgeWeightedDirectedCycle {
+    /// 创建一个 [`EdgeWeightedDirectedCycle`]。
+    pub fn new(g: &EdgeWeightedDigraph) -> Self {
+        let mut d = Self {
+            marked: vec![false; g.v()],
+            edge_to: vec![None; g.v()],
+            cycle: vec![],
+            on_stack: vec![false; g.v()],
+        };
+        for v in 0..g.v() {
+            if !d.marked[v] {
+                d.dfs(g, v);
+            }
+        }
+        d
+    }
+
+    fn dfs(&mut self, g: &EdgeWeightedDigraph, s: usize) {
+        self.on_stack[s] = true;
+        self.marked[s] = true;
+        let mut stack = vec![(s, g.adj(s))];
+        while let Some((v, adj)) = stack.last_mut() {
+            if self.has_cycle() {
+                return;
+            }
+            let v = *v;
+            match adj.next() {
+                Some(e) if !self.marked[e.to()] => {
+                    let w = e.to();
+                    self.edge_to[w] = Some(e.clone());
+                    self.on_stack[w] = true;
+                    self.marked[w] = true;
+                    stack.push((w, g.adj(w)));
+                }
+                Some(e) if self.on_stack[e.to()] => {
+                    // 沿 edge_to 回溯到 w，得到逆序的环。
+                    let w = e.to();
+                    self.cycle.clear();
+                    let mut f = e.clone();
+                    while f.from() != w {
+                        let prev = self.edge_to[f.from()].clone();
+                        self.cycle.push(f);
+                        f = prev.expect("栈上的顶点必有指向它的边");
+                    }
+                    self.cycle.push(f);
+                    self.cycle.reverse();
+                }
+                Some(_) => {}
+                None => {
+                    self.on_stack[v] = false;
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    /// 是否有环。
+    pub fn has_cycle(&self) -> bool {
+        !self.cycle.is_empty()
+    }
+
+    /// 获取有向环中的所有边，首尾相接。
+    pub fn cycle(&self) -> &[DirectedEdge] {
+        &self.cycle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn has_cycle_test() {
+        let d = create();
+        assert!(d.has_cycle());
+
+        let mut g = EdgeWeightedDigraph::new(3);
+        g.add_edge(DirectedEdge::new(0, 1, 0.5));
+        g.add_edge(DirectedEdge::new(1, 2, 0.5));
+        assert!(!EdgeWeightedDirectedCycle::new(&g).has_cycle());
+    }
+
+    #[test]
+    fn cycle_test() {
+        let d = create();
+        let c: Vec<(usize, usize)> = d.cycle().iter().map(|e| (e.from(), e.to())).collect();
+        assert_eq!(&[(1, 2), (2, 3), (3, 1)], &c[..]);
+    }
+
+    fn create() -> EdgeWeightedDirectedCycle {
+        let mut g = EdgeWeightedDigraph::new(5);
+        g.add_edge(DirectedEdge::new(0, 1, 0.1));
+        g.add_edge(DirectedEdge::new(1, 2, 0.2));
+        g.add_edge(DirectedEdge::new(2, 3, 0.3));
+        g.add_edge(DirectedEdge::new(3, 1, 0.4));
+        EdgeWeightedDirectedCycle::new(&g)
+    }
+}
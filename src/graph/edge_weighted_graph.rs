@@ -1,14 +1,13 @@
 //! 加权无向图。
 
-use super::edge::Edge;
-use std::{ptr::NonNull, slice::Iter};
-
-type T = Option<NonNull<Edge>>;
+use super::{edge::Edge, AdjacencyGraph};
+use std::{ops::Index, slice::Iter, vec};
 
+#[derive(Debug)]
 pub struct EdgeWeightedGraph {
-    v: usize,         // 顶点的总数。
-    e: usize,         // 边的总数。
-    adj: Vec<Vec<T>>, // 邻接表。
+    v: usize,             // 顶点的总数。
+    edges: Vec<Edge>,     // 所有的边，按添加的顺序排列。
+    adj: Vec<Vec<usize>>, // 邻接表，保存边在 edges 中的下标。
 }
 
 impl EdgeWeightedGraph {
@@ -16,7 +15,7 @@ impl EdgeWeightedGraph {
     pub fn new(v: usize) -> Self {
         Self {
             v,
-            e: 0,
+            edges: vec![],
             adj: vec![vec![]; v],
         }
     }
@@ -28,27 +27,25 @@ impl EdgeWeightedGraph {
 
     /// 图的边数。
     pub fn e(&self) -> usize {
-        self.e
+        self.edges.len()
     }
 
     /// 添加一条边。
     pub fn add_edge(&mut self, e: Edge) {
+        let i = self.edges.len();
         let v = e.either();
-        let e = Box::leak(Box::new(e)).into();
-        self.adj[v].push(Some(e));
-
-        let temp_e = unsafe { e.as_ref() };
-        if let Some(w) = temp_e.other(v) {
-            self.adj[w].push(Some(e));
+        self.adj[v].push(i);
+        if let Some(w) = e.other(v) {
+            self.adj[w].push(i);
         }
-
-        self.e += 1;
+        self.edges.push(e);
     }
 
     /// 获取与 v 相关联的所有边。
-    pub fn adj(&self, v: usize) -> EdgeWeightedGraphIter {
+    pub fn adj(&self, v: usize) -> EdgeWeightedGraphIter<'_> {
         EdgeWeightedGraphIter {
-            it: self.adj[v].iter()
+            edges: &self.edges,
+            it: self.adj[v].iter(),
         }
     }
 
@@ -64,20 +61,85 @@ impl EdgeWeightedGraph {
         }
         b
     }
+
+    /// 顶点不足时扩充到 v + 1 个。
+    pub(super) fn ensure_vertex(&mut self, v: usize) {
+        if v >= self.v {
+            self.v = v + 1;
+            self.adj.resize(self.v, vec![]);
+        }
+    }
+}
+
+impl AdjacencyGraph for EdgeWeightedGraph {
+    fn v(&self) -> usize {
+        self.v
+    }
+
+    fn adj(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adj(v).filter_map(move |e| e.other(v))
+    }
+}
+
+impl Index<usize> for EdgeWeightedGraph {
+    type Output = Edge;
+
+    /// 获取第 i 条添加的边。
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.edges[i]
+    }
+}
+
+impl Extend<Edge> for EdgeWeightedGraph {
+    /// 添加边，顶点不足时自动扩充。
+    fn extend<I: IntoIterator<Item = Edge>>(&mut self, iter: I) {
+        for e in iter {
+            let v = e.either();
+            self.ensure_vertex(v.max(e.other(v).unwrap_or(v)));
+            self.add_edge(e);
+        }
+    }
+}
+
+impl FromIterator<Edge> for EdgeWeightedGraph {
+    /// 由边创建图，顶点数为最大的顶点加一。
+    fn from_iter<I: IntoIterator<Item = Edge>>(iter: I) -> Self {
+        let mut g = Self::new(0);
+        g.extend(iter);
+        g
+    }
+}
+
+impl IntoIterator for EdgeWeightedGraph {
+    type Item = Edge;
+    type IntoIter = vec::IntoIter<Edge>;
+
+    /// 按添加的顺序取出所有的边。
+    fn into_iter(self) -> Self::IntoIter {
+        self.edges.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a EdgeWeightedGraph {
+    type Item = &'a Edge;
+    type IntoIter = Iter<'a, Edge>;
+
+    /// 按添加的顺序获取所有的边。
+    fn into_iter(self) -> Self::IntoIter {
+        self.edges.iter()
+    }
 }
 
 pub struct EdgeWeightedGraphIter<'a> {
-    it: Iter<'a, T>,
+    edges: &'a [Edge],
+    it: Iter<'a, usize>,
 }
 
 impl<'a> Iterator for EdgeWeightedGraphIter<'a> {
     type Item = &'a Edge;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.it.next() {
-            None => None,
-            Some(e) => e.map(|i| unsafe { i.as_ref() }),
-        }
+        self.it.next().map(|&i| &self.edges[i])
     }
 }
 
@@ -93,6 +155,20 @@ mod tests {
         assert_eq!(r, list);
     }
 
+    #[test]
+    fn iter_test() {
+        let mut g: EdgeWeightedGraph = edges().into_iter().collect();
+        assert_eq!(5, g.v());
+        g.extend([Edge::new(6, 0, 0.1)]);
+        assert_eq!(7, g.v());
+        assert_eq!(4, g.e());
+        assert_eq!(0.6, g[2].weight());
+
+        let weights: Vec<f64> = (&g).into_iter().map(|e| e.weight()).collect();
+        assert_eq!(vec![0.5, 0.7, 0.6, 0.1], weights);
+        assert_eq!(4, g.into_iter().count());
+    }
+
     fn create() -> EdgeWeightedGraph {
         let mut g = EdgeWeightedGraph::new(5);
         for e in edges() {
@@ -0,0 +1,129 @@
+//! 最大流的 Edmonds-Karp 算法，即以最短增广路径实现的 Ford-Fulkerson 算法。
+
+use std::{collections::VecDeque, slice::Iter};
+
+use super::{
+    flow_edge::{FlowEdge, EPSILON},
+    flow_network::FlowNetwork,
+};
+
+pub struct EdmondsKarp {
+    network: FlowNetwork,        // 记录最大流的网络副本。
+    marked: Vec<bool>,           // 剩余网络中 s 可达的顶点。
+    edge_to: Vec<Option<usize>>, // 增广路径上指向顶点的边的索引。
+    value: f64,                  // 当前的流量值。
+}
+
+impl EdmondsKarp {
+    /// 计算网络 g 中从 s 到 t 的最大流。
+    ///
+    /// # Panics
+    ///
+    /// s 与 t 相同时 panic。
+    pub fn new(g: &FlowNetwork, s: usize, t: usize) -> Self {
+        assert_ne!(s, t, "源点与汇点不能相同");
+        let mut f = Self {
+            network: g.clone(),
+            marked: vec![false; g.v()],
+            edge_to: vec![None; g.v()],
+            value: g.excess(t),
+        };
+
+        while f.has_augmenting_path(s, t) {
+            // 计算增广路径的瓶颈容量。
+            let mut bottle = f64::INFINITY;
+            let mut v = t;
+            while let Some(i) = f.edge_to[v] {
+                let e = f.network.edge(i);
+                bottle = bottle.min(e.residual_capacity_to(v));
+                v = e.other(v).expect("边必定与顶点相连");
+            }
+
+            // 沿增广路径增加流量。
+            let mut v = t;
+            while let Some(i) = f.edge_to[v] {
+                let e = f.network.edge_mut(i);
+                e.add_residual_flow_to(v, bottle);
+                v = e.other(v).expect("边必定与顶点相连");
+            }
+
+            f.value += bottle;
+        }
+
+        f
+    }
+
+    /// 在剩余网络中广度优先搜索 s 到 t 的最短路径。
+    fn has_augmenting_path(&mut self, s: usize, t: usize) -> bool {
+        self.marked.fill(false);
+        self.edge_to.fill(None);
+
+        let mut queue = VecDeque::new();
+        queue.push_back(s);
+        self.marked[s] = true;
+        while let Some(v) = queue.pop_front() {
+            if self.marked[t] {
+                break;
+            }
+            for &i in self.network.adj(v) {
+                let e = self.network.edge(i);
+                let w = e.other(v).expect("边必定与顶点相连");
+                if !self.marked[w] && e.residual_capacity_to(w) > EPSILON {
+                    self.edge_to[w] = Some(i);
+                    self.marked[w] = true;
+                    queue.push_back(w);
+                }
+            }
+        }
+
+        self.marked[t]
+    }
+
+    /// 最大流的流量值。
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// v 是否在最小切分中 s 的一侧。
+    pub fn in_cut(&self, v: usize) -> bool {
+        self.marked[v]
+    }
+
+    /// 索引为 i 的边上的流量。
+    pub fn flow(&self, i: usize) -> f64 {
+        self.network.edge(i).flow()
+    }
+
+    /// 获取所有的边及其流量，顺序与网络中添加的顺序相同。
+    pub fn edges(&self) -> Iter<'_, FlowEdge> {
+        self.network.edges()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::tiny_fn;
+
+    #[test]
+    fn value_test() {
+        let g = tiny_fn();
+        let f = EdmondsKarp::new(&g, 0, 5);
+        assert_eq!(4.0, f.value());
+
+        let cut: Vec<usize> = (0..g.v()).filter(|&v| f.in_cut(v)).collect();
+        assert_eq!(&[0, 2], &cut[..]);
+    }
+
+    #[test]
+    fn flow_test() {
+        let g = tiny_fn();
+        let f = EdmondsKarp::new(&g, 0, 5);
+        assert_eq!(2.0, f.flow(0));
+        assert_eq!(2.0, f.flow(1));
+        assert_eq!(f.edges().count(), g.e());
+
+        // 流量网络本身不受影响。
+        assert!(g.edges().all(|e| e.flow() == 0.0));
+    }
+}
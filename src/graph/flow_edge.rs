@@ -0,0 +1,125 @@
+//! 流量网络中的边。
+
+use std::fmt::{self, Display, Formatter};
+
+/// 判断剩余容量是否为零时允许的浮点误差。
+pub(crate) const EPSILON: f64 = 1e-11;
+
+#[derive(Debug, Clone)]
+pub struct FlowEdge {
+    v: usize,      // 边的起点。
+    w: usize,      // 边的终点。
+    capacity: f64, // 容量。
+    flow: f64,     // 流量。
+}
+
+impl FlowEdge {
+    /// 创建一条流量为 0 的边。
+    ///
+    /// # Panics
+    ///
+    /// 容量为负数或 NaN 时 panic。
+    pub fn new(v: usize, w: usize, capacity: f64) -> Self {
+        assert!(capacity >= 0.0, "边 {v}->{w} 的容量 {capacity} 必须非负");
+        Self {
+            v,
+            w,
+            capacity,
+            flow: 0.0,
+        }
+    }
+
+    /// 获取边的起点。
+    pub fn from(&self) -> usize {
+        self.v
+    }
+
+    /// 获取边的终点。
+    pub fn to(&self) -> usize {
+        self.w
+    }
+
+    /// 获取边的容量。
+    pub fn capacity(&self) -> f64 {
+        self.capacity
+    }
+
+    /// 获取边的流量。
+    pub fn flow(&self) -> f64 {
+        self.flow
+    }
+
+    /// 获取指定顶点的另一个顶点。
+    pub fn other(&self, vertex: usize) -> Option<usize> {
+        if vertex == self.v {
+            Some(self.w)
+        } else if vertex == self.w {
+            Some(self.v)
+        } else {
+            None
+        }
+    }
+
+    /// 剩余网络中指向 vertex 的容量。
+    ///
+    /// 指向终点时为尚未使用的容量，指向起点时为可以退回的流量。
+    pub fn residual_capacity_to(&self, vertex: usize) -> f64 {
+        if vertex == self.w {
+            self.capacity - self.flow
+        } else if vertex == self.v {
+            self.flow
+        } else {
+            panic!("{vertex} 不是边 {self} 的顶点")
+        }
+    }
+
+    /// 沿指向 vertex 的方向增加 delta 的流量。
+    pub fn add_residual_flow_to(&mut self, vertex: usize, delta: f64) {
+        if vertex == self.w {
+            self.flow += delta;
+        } else if vertex == self.v {
+            self.flow -= delta;
+        } else {
+            panic!("{vertex} 不是边 {self} 的顶点")
+        }
+
+        // 消除浮点误差。
+        if self.flow.abs() <= EPSILON {
+            self.flow = 0.0;
+        }
+        if (self.flow - self.capacity).abs() <= EPSILON {
+            self.flow = self.capacity;
+        }
+    }
+}
+
+impl Display for FlowEdge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}->{} {:.2}/{:.2}",
+            self.v, self.w, self.flow, self.capacity
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn residual_test() {
+        let mut e = FlowEdge::new(1, 2, 3.0);
+        assert_eq!(3.0, e.residual_capacity_to(2));
+        assert_eq!(0.0, e.residual_capacity_to(1));
+
+        e.add_residual_flow_to(2, 2.0);
+        assert_eq!(1.0, e.residual_capacity_to(2));
+        assert_eq!(2.0, e.residual_capacity_to(1));
+
+        e.add_residual_flow_to(1, 0.5);
+        assert_eq!(1.5, e.flow());
+        assert_eq!(Some(1), e.other(2));
+        assert_eq!("1->2 1.50/3.00", e.to_string());
+    }
+}
@@ -0,0 +1,176 @@
+//! 流量网络。
+
+use std::{ops::Index, slice::Iter, vec};
+
+use super::flow_edge::{FlowEdge, EPSILON};
+
+#[derive(Debug, Clone)]
+pub struct FlowNetwork {
+    v: usize,             // 顶点的总数。
+    edges: Vec<FlowEdge>, // 所有的边。
+    adj: Vec<Vec<usize>>, // 邻接表，元素为边的索引。
+}
+
+impl FlowNetwork {
+    /// 创建一个新的 [`FlowNetwork`]。
+    pub fn new(v: usize) -> Self {
+        Self {
+            v,
+            edges: vec![],
+            adj: vec![vec![]; v],
+        }
+    }
+
+    /// 获取顶点的总数。
+    pub fn v(&self) -> usize {
+        self.v
+    }
+
+    /// 获取边的总数。
+    pub fn e(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// 添加一条边，返回它的索引。
+    pub fn add_edge(&mut self, e: FlowEdge) -> usize {
+        let i = self.edges.len();
+        self.adj[e.from()].push(i);
+        self.adj[e.to()].push(i);
+        self.edges.push(e);
+        i
+    }
+
+    /// 获取与 v 相关联的所有边（含指向 v 的边）的索引。
+    pub fn adj(&self, v: usize) -> &[usize] {
+        &self.adj[v]
+    }
+
+    /// 获取索引为 i 的边。
+    pub fn edge(&self, i: usize) -> &FlowEdge {
+        &self.edges[i]
+    }
+
+    /// 获取索引为 i 的边的可变引用。
+    pub fn edge_mut(&mut self, i: usize) -> &mut FlowEdge {
+        &mut self.edges[i]
+    }
+
+    /// 获取所有的边，顺序与添加顺序相同。
+    pub fn edges(&self) -> Iter<'_, FlowEdge> {
+        self.edges.iter()
+    }
+
+    /// 流量是否满足容量限制，且 s、t 之外的顶点流入等于流出。
+    pub fn is_feasible(&self, s: usize, t: usize) -> bool {
+        let capacity_ok = self
+            .edges
+            .iter()
+            .all(|e| e.flow() >= -EPSILON && e.flow() <= e.capacity() + EPSILON);
+
+        capacity_ok
+            && (0..self.v)
+                .filter(|&v| v != s && v != t)
+                .all(|v| self.excess(v).abs() <= EPSILON)
+    }
+
+    /// 流入 v 的流量减去流出 v 的流量。
+    pub fn excess(&self, v: usize) -> f64 {
+        self.adj[v]
+            .iter()
+            .map(|&i| {
+                let e = &self.edges[i];
+                if e.to() == v {
+                    e.flow()
+                } else {
+                    -e.flow()
+                }
+            })
+            .sum()
+    }
+
+    /// 顶点不足时扩充到 v + 1 个。
+    fn ensure_vertex(&mut self, v: usize) {
+        if v >= self.v {
+            self.v = v + 1;
+            self.adj.resize(self.v, vec![]);
+        }
+    }
+}
+
+impl Index<usize> for FlowNetwork {
+    type Output = FlowEdge;
+
+    /// 获取索引为 i 的边。
+    fn index(&self, i: usize) -> &Self::Output {
+        self.edge(i)
+    }
+}
+
+impl Extend<FlowEdge> for FlowNetwork {
+    /// 添加边，顶点不足时自动扩充。
+    fn extend<I: IntoIterator<Item = FlowEdge>>(&mut self, iter: I) {
+        for e in iter {
+            self.ensure_vertex(e.from().max(e.to()));
+            self.add_edge(e);
+        }
+    }
+}
+
+impl FromIterator<FlowEdge> for FlowNetwork {
+    /// 由边创建图，顶点数为最大的顶点加一。
+    fn from_iter<I: IntoIterator<Item = FlowEdge>>(iter: I) -> Self {
+        let mut g = Self::new(0);
+        g.extend(iter);
+        g
+    }
+}
+
+impl IntoIterator for FlowNetwork {
+    type Item = FlowEdge;
+    type IntoIter = vec::IntoIter<FlowEdge>;
+
+    /// 按添加的顺序取出所有的边。
+    fn into_iter(self) -> Self::IntoIter {
+        self.edges.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a FlowNetwork {
+    type Item = &'a FlowEdge;
+    type IntoIter = Iter<'a, FlowEdge>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.edges()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_edge_test() {
+        let mut g = FlowNetwork::new(3);
+        assert_eq!(0, g.add_edge(FlowEdge::new(0, 1, 1.0)));
+        assert_eq!(1, g.add_edge(FlowEdge::new(1, 2, 2.0)));
+        assert_eq!(2, g.e());
+        assert_eq!(&[0, 1], g.adj(1));
+
+        g.edge_mut(0).add_residual_flow_to(1, 1.0);
+        assert_eq!(1.0, g.excess(1));
+        assert!(!g.is_feasible(0, 2));
+
+        g.edge_mut(1).add_residual_flow_to(2, 1.0);
+        assert!(g.is_feasible(0, 2));
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut g: FlowNetwork = [FlowEdge::new(0, 1, 2.0)].into_iter().collect();
+        g.extend([FlowEdge::new(1, 3, 1.0)]);
+        assert_eq!(4, g.v());
+        assert_eq!(1.0, g[1].capacity());
+        assert_eq!(3.0, (&g).into_iter().map(|e| e.capacity()).sum::<f64>());
+        assert_eq!(2, g.into_iter().count());
+    }
+}
@@ -0,0 +1,159 @@
+//! 所有顶点对之间最短路径的 Floyd-Warshall 算法，适用于稠密图。
+
+use super::{
+    directed_edge::DirectedEdge, edge_weighted_digraph::EdgeWeightedDigraph,
+    edge_weighted_directed_cycle::EdgeWeightedDirectedCycle,
+};
+
+pub struct FloydWarshall {
+    dist_to: Vec<Vec<f64>>,                  // v 到 w 的最短距离。
+    edge_to: Vec<Vec<Option<DirectedEdge>>>, // v 到 w 最短路径的最后一条边。
+    cycle: Vec<DirectedEdge>,                // 负权重环。
+}
+
+impl FloydWarshall {
+    /// 计算所有顶点对之间的最短路径，边的权重可以为负。
+    pub fn new(g: &EdgeWeightedDigraph) -> Self {
+        let n = g.v();
+        let mut f = Self {
+            dist_to: vec![vec![f64::INFINITY; n]; n],
+            edge_to: vec![vec![None; n]; n],
+            cycle: vec![],
+        };
+
+        // 用边初始化，平行边取权重最小的一条。
+        for v in 0..n {
+            for e in g.adj(v) {
+                if e.weight() < f.dist_to[v][e.to()] {
+                    f.dist_to[v][e.to()] = e.weight();
+                    f.edge_to[v][e.to()] = Some(e.clone());
+                }
+            }
+            // 处理自环。
+            if f.dist_to[v][v] >= 0.0 {
+                f.dist_to[v][v] = 0.0;
+                f.edge_to[v][v] = None;
+            }
+        }
+
+        for i in 0..n {
+            for v in 0..n {
+                if f.edge_to[v][i].is_none() {
+                    continue;
+                }
+                for w in 0..n {
+                    if f.dist_to[v][w] > f.dist_to[v][i] + f.dist_to[i][w] {
+                        f.dist_to[v][w] = f.dist_to[v][i] + f.dist_to[i][w];
+                        f.edge_to[v][w] = f.edge_to[i][w].clone();
+                    }
+                }
+                // v 到自身的距离为负，说明存在负权重环。
+                if f.dist_to[v][v] < 0.0 {
+                    f.find_negative_cycle(v);
+                    return f;
+                }
+            }
+        }
+
+        f
+    }
+
+    /// 在 v 的最短路径树中查找负权重环。
+    fn find_negative_cycle(&mut self, v: usize) {
+        let n = self.edge_to.len();
+        let mut spt = EdgeWeightedDigraph::new(n);
+        for e in self.edge_to[v].iter().flatten() {
+            spt.add_edge(e.clone());
+        }
+
+        let finder = EdgeWeightedDirectedCycle::new(&spt);
+        self.cycle = finder.cycle().to_vec();
+    }
+
+    /// 是否存在负权重环。
+    pub fn has_negative_cycle(&self) -> bool {
+        !self.cycle.is_empty()
+    }
+
+    /// 获取负权重环中的边，首尾相接。
+    pub fn negative_cycle(&self) -> Option<&[DirectedEdge]> {
+        if self.has_negative_cycle() {
+            Some(&self.cycle)
+        } else {
+            None
+        }
+    }
+
+    /// s 到 t 的最短距离，不可达时为无穷大。
+    ///
+    /// 存在负权重环时最短距离没有意义，此时调用会 panic。
+    pub fn dist(&self, s: usize, t: usize) -> f64 {
+        self.assert_no_negative_cycle();
+        self.dist_to[s][t]
+    }
+
+    /// 是否存在 s 到 t 的路径。
+    pub fn has_path(&self, s: usize, t: usize) -> bool {
+        self.dist_to[s][t] < f64::INFINITY
+    }
+
+    /// 获取 s 到 t 的最短路径，边按从 s 到 t 的顺序排列。
+    ///
+    /// 存在负权重环时最短路径没有意义，此时调用会 panic。
+    pub fn path(&self, s: usize, t: usize) -> Vec<&DirectedEdge> {
+        self.assert_no_negative_cycle();
+        let mut path = vec![];
+        let mut e = self.edge_to[s][t].as_ref();
+        while let Some(x) = e {
+            path.push(x);
+            e = self.edge_to[s][x.from()].as_ref();
+        }
+        path.reverse();
+        path
+    }
+
+    fn assert_no_negative_cycle(&self) {
+        assert!(!self.has_negative_cycle(), "图中存在负权重环");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{bellman_ford_sp::BellmanFordSP, tiny_ewd, tiny_ewdn};
+
+    #[test]
+    fn dist_test() {
+        let g = tiny_ewdn();
+        let f = FloydWarshall::new(&g);
+        assert!(!f.has_negative_cycle());
+        for s in 0..g.v() {
+            let b = BellmanFordSP::new(&g, s);
+            for t in 0..g.v() {
+                assert_eq!(b.has_path_to(t), f.has_path(s, t));
+                assert!((b.dist_to(t) - f.dist(s, t)).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn path_test() {
+        let f = FloydWarshall::new(&tiny_ewdn());
+        let path: Vec<usize> = f.path(0, 1).iter().map(|e| e.to()).collect();
+        assert_eq!(&[2, 7, 3, 6, 4, 5, 1], &path[..]);
+        assert!(f.path(3, 3).is_empty());
+    }
+
+    #[test]
+    fn negative_cycle_test() {
+        let mut g = tiny_ewd();
+        g.add_edge(DirectedEdge::new(5, 4, -0.66));
+        let f = FloydWarshall::new(&g);
+        let cycle = f.negative_cycle().unwrap();
+        let weight: f64 = cycle.iter().map(|e| e.weight()).sum();
+        assert!(weight < 0.0);
+        for (i, e) in cycle.iter().enumerate() {
+            assert_eq!(e.to(), cycle[(i + 1) % cycle.len()].from());
+        }
+    }
+}
@@ -0,0 +1,874 @@
+//! 图的读写：《算法》的文本格式、CSV 边列表、Graphviz DOT 和 GraphML。
+
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::{self, Display},
+    io::{self, BufRead, Write},
+};
+
+use super::{
+    digraph::Digraph, directed_edge::DirectedEdge, edge::Edge,
+    edge_weighted_digraph::EdgeWeightedDigraph, edge_weighted_graph::EdgeWeightedGraph, Graph,
+};
+
+/// 读取图时允许的最大顶点数，超过时认为输入有误，避免按错误的顶点数分配内存。
+pub const MAX_VERTICES: usize = 1 << 24;
+
+/// 可以读写的图。
+pub trait GraphIo: Sized {
+    /// 是否为有向图。
+    const DIRECTED: bool;
+    /// 边是否带权重。
+    const WEIGHTED: bool;
+
+    /// 创建含有 v 个顶点的空图。
+    fn empty(v: usize) -> Self;
+
+    /// 顶点数。
+    fn vertices(&self) -> usize;
+
+    /// 添加边 v-w，不带权重的图忽略 weight。
+    fn insert_edge(&mut self, v: usize, w: usize, weight: f64);
+
+    /// 所有的边，不带权重的图中权重为 1。
+    fn edge_list(&self) -> Vec<(usize, usize, f64)>;
+}
+
+impl GraphIo for Graph {
+    const DIRECTED: bool = false;
+    const WEIGHTED: bool = false;
+
+    fn empty(v: usize) -> Self {
+        Self::new(v)
+    }
+
+    fn vertices(&self) -> usize {
+        self.v()
+    }
+
+    fn insert_edge(&mut self, v: usize, w: usize, _: f64) {
+        self.add_edge(v, w);
+    }
+
+    fn edge_list(&self) -> Vec<(usize, usize, f64)> {
+        self.edges().map(|(v, w)| (v, w, 1.0)).collect()
+    }
+}
+
+impl GraphIo for Digraph {
+    const DIRECTED: bool = true;
+    const WEIGHTED: bool = false;
+
+    fn empty(v: usize) -> Self {
+        Self::new(v)
+    }
+
+    fn vertices(&self) -> usize {
+        self.v()
+    }
+
+    fn insert_edge(&mut self, v: usize, w: usize, _: f64) {
+        self.add_edge(v, w);
+    }
+
+    fn edge_list(&self) -> Vec<(usize, usize, f64)> {
+        self.edges().map(|(v, w)| (v, w, 1.0)).collect()
+    }
+}
+
+impl GraphIo for EdgeWeightedGraph {
+    const DIRECTED: bool = false;
+    const WEIGHTED: bool = true;
+
+    fn empty(v: usize) -> Self {
+        Self::new(v)
+    }
+
+    fn vertices(&self) -> usize {
+        self.v()
+    }
+
+    fn insert_edge(&mut self, v: usize, w: usize, weight: f64) {
+        self.add_edge(Edge::new(v, w, weight));
+    }
+
+    fn edge_list(&self) -> Vec<(usize, usize, f64)> {
+        (0..self.e())
+            .map(|i| {
+                let e = &self[i];
+                let v = e.either();
+                (v, e.other(v).expect("边包含顶点 v"), e.weight())
+            })
+            .collect()
+    }
+}
+
+impl GraphIo for EdgeWeightedDigraph {
+    const DIRECTED: bool = true;
+    const WEIGHTED: bool = true;
+
+    fn empty(v: usize) -> Self {
+        Self::new(v)
+    }
+
+    fn vertices(&self) -> usize {
+        self.v()
+    }
+
+    fn insert_edge(&mut self, v: usize, w: usize, weight: f64) {
+        self.add_edge(DirectedEdge::new(v, w, weight));
+    }
+
+    fn edge_list(&self) -> Vec<(usize, usize, f64)> {
+        self.edges()
+            .map(|e| (e.from(), e.to(), e.weight()))
+            .collect()
+    }
+}
+
+/// 读取图时的错误。
+#[derive(Debug)]
+pub struct ParseError {
+    line: usize,          // 出错的行号，从 1 开始。
+    kind: ParseErrorKind, // 错误的类型。
+}
+
+/// [`ParseError`] 的类型。
+#[derive(Debug)]
+pub enum ParseErrorKind {
+    /// 读取输入失败。
+    Io(io::Error),
+    /// 输入在读完所有的边之前结束。
+    UnexpectedEof,
+    /// 无法解析的数字。
+    InvalidNumber(String),
+    /// 一行中的字段不足。
+    MissingField,
+    /// 一行中有多余的字段。
+    ExtraField,
+    /// 所有的边之后还有数据。
+    TrailingData,
+    /// 顶点不小于顶点数。
+    VertexOutOfRange { vertex: usize, v: usize },
+    /// 顶点数超过 [`MAX_VERTICES`]。
+    TooManyVertices(usize),
+    /// GraphML 中的边引用了不存在的顶点。
+    UnknownVertex(String),
+    /// GraphML 中图的方向与目标类型不一致。
+    Direction,
+    /// GraphML 的语法错误。
+    Syntax(&'static str),
+}
+
+impl ParseError {
+    fn new(line: usize, kind: ParseErrorKind) -> Self {
+        Self { line, kind }
+    }
+
+    /// 出错的行号，从 1 开始。
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 错误的类型。
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "读取失败：{e}"),
+            Self::UnexpectedEof => write!(f, "数据提前结束"),
+            Self::InvalidNumber(s) => write!(f, "无效的数字“{s}”"),
+            Self::MissingField => write!(f, "字段不足"),
+            Self::ExtraField => write!(f, "多余的字段"),
+            Self::TrailingData => write!(f, "多余的数据"),
+            Self::VertexOutOfRange { vertex, v } => write!(f, "顶点 {vertex} 超出范围 0..{v}"),
+            Self::TooManyVertices(v) => write!(f, "顶点数 {v} 超过上限 {MAX_VERTICES}"),
+            Self::UnknownVertex(id) => write!(f, "未知的顶点“{id}”"),
+            Self::Direction => write!(f, "图的方向与目标类型不一致"),
+            Self::Syntax(msg) => write!(f, "语法错误：{msg}"),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "第 {} 行：{}", self.line, self.kind)
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// 逐行读取，跳过空行，返回行号和去掉首尾空白的内容。
+fn lines<R: BufRead>(r: R) -> impl Iterator<Item = Result<(usize, String), ParseError>> {
+    r.lines()
+        .enumerate()
+        .map(|(i, line)| match line {
+            Ok(s) => Ok((i + 1, s.trim().to_owned())),
+            Err(e) => Err(ParseError::new(i + 1, ParseErrorKind::Io(e))),
+        })
+        .filter(|line| !matches!(line, Ok((_, s)) if s.is_empty()))
+}
+
+fn parse_usize(line: usize, s: &str) -> Result<usize, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError::new(line, ParseErrorKind::InvalidNumber(s.to_owned())))
+}
+
+fn parse_f64(line: usize, s: &str) -> Result<f64, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError::new(line, ParseErrorKind::InvalidNumber(s.to_owned())))
+}
+
+/// 解析一条边的字段：两个顶点，带权重的图还需要权重。
+fn parse_edge<'a, G: GraphIo>(
+    line: usize,
+    mut fields: impl Iterator<Item = &'a str>,
+) -> Result<(usize, usize, f64), ParseError> {
+    let mut next = || {
+        fields
+            .next()
+            .ok_or(ParseError::new(line, ParseErrorKind::MissingField))
+    };
+    let v = parse_usize(line, next()?)?;
+    let w = parse_usize(line, next()?)?;
+    let weight = if G::WEIGHTED {
+        parse_f64(line, next()?)?
+    } else {
+        1.0
+    };
+    if fields.next().is_some() {
+        return Err(ParseError::new(line, ParseErrorKind::ExtraField));
+    }
+    Ok((v, w, weight))
+}
+
+/// 检查顶点数是否合理，line 为给出顶点数的行。
+fn check_vertices(line: usize, v: usize) -> Result<(), ParseError> {
+    if v > MAX_VERTICES {
+        return Err(ParseError::new(line, ParseErrorKind::TooManyVertices(v)));
+    }
+    Ok(())
+}
+
+fn check_vertex(line: usize, vertex: usize, v: usize) -> Result<(), ParseError> {
+    if vertex >= v {
+        return Err(ParseError::new(
+            line,
+            ParseErrorKind::VertexOutOfRange { vertex, v },
+        ));
+    }
+    Ok(())
+}
+
+/// 读取《算法》中 tinyG.txt 样式的文本：第一行是顶点数，第二行是边数，
+/// 之后每行一条边 `v w`，带权重的图为 `v w weight`。
+pub fn read_text<G: GraphIo, R: BufRead>(r: R) -> Result<G, ParseError> {
+    let mut lines = lines(r);
+    let mut last = 0;
+    let mut next = |last: &mut usize| match lines.next() {
+        Some(line) => {
+            let (line, s) = line?;
+            *last = line;
+            Ok((line, s))
+        }
+        None => Err(ParseError::new(*last + 1, ParseErrorKind::UnexpectedEof)),
+    };
+    let (v_line, s) = next(&mut last)?;
+    let v = parse_usize(v_line, &s)?;
+    check_vertices(v_line, v)?;
+    let (e_line, s) = next(&mut last)?;
+    let e = parse_usize(e_line, &s)?;
+
+    // 先读完所有的边，输入不完整时不必为图分配内存。
+    let mut edges = vec![];
+    for _ in 0..e {
+        let (line, s) = next(&mut last)?;
+        let (a, b, weight) = parse_edge::<G>(line, s.split_ascii_whitespace())?;
+        check_vertex(line, a, v)?;
+        check_vertex(line, b, v)?;
+        edges.push((a, b, weight));
+    }
+    if let Some(line) = lines.next() {
+        return Err(ParseError::new(line?.0, ParseErrorKind::TrailingData));
+    }
+
+    let mut g = G::empty(v);
+    for (a, b, weight) in edges {
+        g.insert_edge(a, b, weight);
+    }
+    Ok(g)
+}
+
+/// 按 [`read_text`] 的格式写出图。
+pub fn write_text<G: GraphIo, W: Write>(g: &G, w: &mut W) -> io::Result<()> {
+    let edges = g.edge_list();
+    writeln!(w, "{}", g.vertices())?;
+    writeln!(w, "{}", edges.len())?;
+    for (a, b, weight) in edges {
+        if G::WEIGHTED {
+            writeln!(w, "{a} {b} {weight}")?;
+        } else {
+            writeln!(w, "{a} {b}")?;
+        }
+    }
+    Ok(())
+}
+
+/// 读取 CSV 边列表，每行 `v,w` 或 `v,w,weight`。
+/// 以 `#` 开头的行是注释，第一个非注释行不以数字开头时作为表头跳过，顶点数为最大的顶点加一。
+pub fn read_csv<G: GraphIo, R: BufRead>(r: R) -> Result<G, ParseError> {
+    let mut edges = vec![];
+    let mut v = 0;
+    let mut first = true;
+    for line in lines(r) {
+        let (line, s) = line?;
+        if s.starts_with('#') {
+            continue;
+        }
+        let field = s.split(',').next().unwrap_or_default().trim();
+        let header = first && field.parse::<usize>().is_err();
+        first = false;
+        if header {
+            continue;
+        }
+        let (a, b, weight) = parse_edge::<G>(line, s.split(',').map(str::trim))?;
+        let vertex = a.max(b);
+        match vertex.checked_add(1) {
+            Some(n) if n <= MAX_VERTICES => v = v.max(n),
+            _ => {
+                let kind = ParseErrorKind::VertexOutOfRange {
+                    vertex,
+                    v: MAX_VERTICES,
+                };
+                return Err(ParseError::new(line, kind));
+            }
+        }
+        edges.push((a, b, weight));
+    }
+
+    let mut g = G::empty(v);
+    for (a, b, weight) in edges {
+        g.insert_edge(a, b, weight);
+    }
+    Ok(g)
+}
+
+/// 按 [`read_csv`] 的格式写出图，第一行为表头。孤立的顶点不会被写出。
+pub fn write_csv<G: GraphIo, W: Write>(g: &G, w: &mut W) -> io::Result<()> {
+    if G::WEIGHTED {
+        writeln!(w, "v,w,weight")?;
+    } else {
+        writeln!(w, "v,w")?;
+    }
+    for (a, b, weight) in g.edge_list() {
+        if G::WEIGHTED {
+            writeln!(w, "{a},{b},{weight}")?;
+        } else {
+            writeln!(w, "{a},{b}")?;
+        }
+    }
+    Ok(())
+}
+
+/// DOT 输出中需要突出显示的顶点和边。
+#[derive(Debug, Default)]
+pub struct Highlight {
+    vertices: HashSet<usize>,       // 突出显示的顶点。
+    edges: HashSet<(usize, usize)>, // 突出显示的边。
+}
+
+impl Highlight {
+    /// 不突出显示任何内容。
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 突出显示依次经过 path 中顶点的路径。
+    pub fn path(path: &[usize]) -> Self {
+        let mut h = Self::edges(path.windows(2).map(|p| (p[0], p[1])));
+        h.vertices.extend(path);
+        h
+    }
+
+    /// 突出显示环，首尾顶点相同与否均可。
+    pub fn cycle(cycle: &[usize]) -> Self {
+        let mut h = Self::path(cycle);
+        if let (Some(&first), Some(&last)) = (cycle.first(), cycle.last()) {
+            h.edges.insert((last, first));
+        }
+        h
+    }
+
+    /// 突出显示指定的边及其顶点，例如最小生成树。
+    pub fn edges(edges: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let mut h = Self::new();
+        for (v, w) in edges {
+            h.vertices.extend([v, w]);
+            h.edges.insert((v, w));
+        }
+        h
+    }
+
+    fn has_vertex(&self, v: usize) -> bool {
+        self.vertices.contains(&v)
+    }
+
+    fn has_edge(&self, v: usize, w: usize, directed: bool) -> bool {
+        self.edges.contains(&(v, w)) || (!directed && self.edges.contains(&(w, v)))
+    }
+}
+
+/// 导出 Graphviz DOT，highlight 中的顶点和边以红色显示。
+pub fn write_dot<G: GraphIo, W: Write>(g: &G, w: &mut W, highlight: &Highlight) -> io::Result<()> {
+    let (kind, arrow) = if G::DIRECTED {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+    writeln!(w, "{kind} {{")?;
+    for v in 0..g.vertices() {
+        if highlight.has_vertex(v) {
+            writeln!(w, "  {v} [color=red];")?;
+        } else {
+            writeln!(w, "  {v};")?;
+        }
+    }
+    for (a, b, weight) in g.edge_list() {
+        let mut attrs = vec![];
+        if G::WEIGHTED {
+            attrs.push(format!("label=\"{weight}\""));
+        }
+        if highlight.has_edge(a, b, G::DIRECTED) {
+            attrs.push("color=red, penwidth=2".to_owned());
+        }
+        if attrs.is_empty() {
+            writeln!(w, "  {a} {arrow} {b};")?;
+        } else {
+            writeln!(w, "  {a} {arrow} {b} [{}];", attrs.join(", "))?;
+        }
+    }
+    writeln!(w, "}}")
+}
+
+/// 导出 GraphML，顶点 v 的标识符为 `n{v}`，权重保存在键为 `weight` 的数据中。
+pub fn write_graphml<G: GraphIo, W: Write>(g: &G, w: &mut W) -> io::Result<()> {
+    let direction = if G::DIRECTED {
+        "directed"
+    } else {
+        "undirected"
+    };
+    writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        w,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    )?;
+    if G::WEIGHTED {
+        writeln!(
+            w,
+            r#"  <key id="weight" for="edge" attr.name="weight" attr.type="double"/>"#
+        )?;
+    }
+    writeln!(w, r#"  <graph id="G" edgedefault="{direction}">"#)?;
+    for v in 0..g.vertices() {
+        writeln!(w, r#"    <node id="n{v}"/>"#)?;
+    }
+    for (a, b, weight) in g.edge_list() {
+        if G::WEIGHTED {
+            writeln!(w, r#"    <edge source="n{a}" target="n{b}">"#)?;
+            writeln!(w, r#"      <data key="weight">{weight}</data>"#)?;
+            writeln!(w, "    </edge>")?;
+        } else {
+            writeln!(w, r#"    <edge source="n{a}" target="n{b}"/>"#)?;
+        }
+    }
+    writeln!(w, "  </graph>")?;
+    writeln!(w, "</graphml>")
+}
+
+/// XML 中的一个片段。
+enum Xml<'a> {
+    Open(Tag<'a>),        // 开始标签或空元素标签。
+    Close(&'a str),       // 结束标签。
+    Text(&'a str, usize), // 标签之间的文本及其行号。
+}
+
+/// XML 开始标签。
+struct Tag<'a> {
+    name: &'a str,                  // 标签名。
+    attrs: Vec<(&'a str, &'a str)>, // 属性，值中的实体不会被转换。
+    empty: bool,                    // 是否为空元素标签。
+    line: usize,                    // 所在的行号。
+}
+
+impl<'a> Tag<'a> {
+    fn attr(&self, name: &str) -> Option<&'a str> {
+        self.attrs.iter().find(|(k, _)| *k == name).map(|(_, v)| *v)
+    }
+
+    fn require(&self, name: &'static str) -> Result<&'a str, ParseError> {
+        self.attr(name)
+            .ok_or(ParseError::new(self.line, ParseErrorKind::Syntax(name)))
+    }
+}
+
+/// 把 XML 切分为标签和文本，只支持 GraphML 用到的子集。
+fn xml(s: &str) -> Result<Vec<Xml<'_>>, ParseError> {
+    let syntax = |line, msg| ParseError::new(line, ParseErrorKind::Syntax(msg));
+    let newlines = |s: &str| s.matches('\n').count();
+
+    let mut items = vec![];
+    let mut line = 1;
+    let mut rest = s;
+    while let Some(start) = rest.find('<') {
+        let text = &rest[..start];
+        if !text.trim().is_empty() {
+            items.push(Xml::Text(text.trim(), line + newlines(text.trim_end())));
+        }
+        line += newlines(text);
+        rest = &rest[start..];
+
+        let close = if rest.starts_with("<!--") { "-->" } else { ">" };
+        let end = rest.find(close).ok_or(syntax(line, "标签没有结束"))?;
+        let body = &rest[1..end];
+        let tag_line = line;
+        line += newlines(&rest[..end]);
+        rest = &rest[end + close.len()..];
+
+        if body.starts_with(['?', '!']) {
+            continue; // 声明、注释和文档类型。
+        }
+        if let Some(name) = body.strip_prefix('/') {
+            items.push(Xml::Close(name.trim()));
+            continue;
+        }
+
+        let (body, empty) = match body.strip_suffix('/') {
+            Some(body) => (body, true),
+            None => (body, false),
+        };
+        let body = body.trim();
+        let split = body.find(char::is_whitespace).unwrap_or(body.len());
+        let (name, mut attrs_str) = body.split_at(split);
+        if name.is_empty() {
+            return Err(syntax(tag_line, "缺少标签名"));
+        }
+
+        let mut attrs = vec![];
+        loop {
+            attrs_str = attrs_str.trim_start();
+            if attrs_str.is_empty() {
+                break;
+            }
+            let eq = attrs_str.find('=').ok_or(syntax(tag_line, "属性缺少值"))?;
+            let key = attrs_str[..eq].trim();
+            let value = attrs_str[eq + 1..].trim_start();
+            let quote = value
+                .chars()
+                .next()
+                .filter(|c| *c == '"' || *c == '\'')
+                .ok_or(syntax(tag_line, "属性值缺少引号"))?;
+            let len = value[1..]
+                .find(quote)
+                .ok_or(syntax(tag_line, "属性值没有结束"))?;
+            attrs.push((key, &value[1..1 + len]));
+            attrs_str = &value[len + 2..];
+        }
+
+        items.push(Xml::Open(Tag {
+            name,
+            attrs,
+            empty,
+            line: tag_line,
+        }));
+    }
+    if !rest.trim().is_empty() {
+        items.push(Xml::Text(rest.trim(), line));
+    }
+    Ok(items)
+}
+
+/// 读取 GraphML。顶点按出现的顺序编号，带权重的图从 `attr.name` 为 `weight` 的键中读取权重。
+/// 缺少属性时，[`ParseErrorKind::Syntax`] 中是属性名。
+pub fn read_graphml<G: GraphIo, R: BufRead>(mut r: R) -> Result<G, ParseError> {
+    let mut s = String::new();
+    r.read_to_string(&mut s)
+        .map_err(|e| ParseError::new(1, ParseErrorKind::Io(e)))?;
+
+    let mut ids = HashMap::new();
+    let mut weight_key = None;
+    let mut edges = vec![];
+    let mut edge = None; // 正在读取的边：源、目标、权重和行号。
+    let mut in_weight = false;
+    let mut graph_line = 1; // graph 元素所在的行。
+    for item in xml(&s)? {
+        match item {
+            Xml::Open(tag) => match tag.name {
+                "graph" => {
+                    graph_line = tag.line;
+                    let directed = match tag.attr("edgedefault") {
+                        Some("directed") => true,
+                        Some("undirected") => false,
+                        _ => {
+                            return Err(ParseError::new(
+                                tag.line,
+                                ParseErrorKind::Syntax("edgedefault"),
+                            ))
+                        }
+                    };
+                    if directed != G::DIRECTED {
+                        return Err(ParseError::new(tag.line, ParseErrorKind::Direction));
+                    }
+                }
+                "key" if tag.attr("attr.name") == Some("weight") => {
+                    weight_key = Some(tag.require("id")?);
+                }
+                "node" => {
+                    let n = ids.len();
+                    ids.entry(tag.require("id")?).or_insert(n);
+                }
+                "edge" => {
+                    let e = (
+                        tag.require("source")?,
+                        tag.require("target")?,
+                        None,
+                        tag.line,
+                    );
+                    if tag.empty {
+                        edges.push(e);
+                    } else {
+                        edge = Some(e);
+                    }
+                }
+                "data" if edge.is_some() => {
+                    in_weight = weight_key.is_some() && tag.attr("key") == weight_key;
+                }
+                _ => {}
+            },
+            Xml::Text(text, line) if in_weight => {
+                if let Some(e) = edge.as_mut() {
+                    e.2 = Some(parse_f64(line, text)?);
+                }
+            }
+            Xml::Close("data") => in_weight = false,
+            Xml::Close("edge") => edges.extend(edge.take()),
+            _ => {}
+        }
+    }
+
+    check_vertices(graph_line, ids.len())?;
+    let mut g = G::empty(ids.len());
+    for (source, target, weight, line) in edges {
+        let vertex = |id: &str| {
+            ids.get(id).copied().ok_or(ParseError::new(
+                line,
+                ParseErrorKind::UnknownVertex(id.to_owned()),
+            ))
+        };
+        let weight = match weight {
+            Some(weight) => weight,
+            None if G::WEIGHTED => return Err(ParseError::new(line, ParseErrorKind::MissingField)),
+            None => 1.0,
+        };
+        g.insert_edge(vertex(source)?, vertex(target)?, weight);
+    }
+    Ok(g)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::tiny_ewd;
+
+    const TINY_G: &str = "6\n4\n0 1\n1 2\n\n3 3\n4 5\n";
+
+    fn to_string(f: impl FnOnce(&mut Vec<u8>) -> io::Result<()>) -> String {
+        let mut out = vec![];
+        f(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn text_test() {
+        let g: Graph = read_text(TINY_G.as_bytes()).unwrap();
+        assert_eq!(6, g.v());
+        assert_eq!(4, g.e());
+        assert_eq!(&[3, 3], &g[3]);
+        assert_eq!(
+            "6\n4\n0 1\n1 2\n3 3\n4 5\n",
+            to_string(|w| write_text(&g, w))
+        );
+
+        let g = tiny_ewd();
+        let text = to_string(|w| write_text(&g, w));
+        assert!(text.starts_with("8\n15\n0 4 0.38\n0 2 0.26\n"));
+        let h: EdgeWeightedDigraph = read_text(text.as_bytes()).unwrap();
+        assert_eq!(g.edge_list(), h.edge_list());
+    }
+
+    #[test]
+    fn text_error_test() {
+        let err = |s: &str| read_text::<EdgeWeightedGraph, _>(s.as_bytes()).unwrap_err();
+
+        let e = err("3\n2\n0 1 0.5\n1 x 0.5\n");
+        assert_eq!(4, e.line());
+        assert!(matches!(e.kind(), ParseErrorKind::InvalidNumber(s) if s == "x"));
+        assert_eq!("第 4 行：无效的数字“x”", e.to_string());
+
+        let e = err("3\n1\n\n0 1\n");
+        assert_eq!(4, e.line());
+        assert!(matches!(e.kind(), ParseErrorKind::MissingField));
+
+        let e = err("3\n1\n0 3 1.0\n");
+        assert!(matches!(
+            e.kind(),
+            ParseErrorKind::VertexOutOfRange { vertex: 3, v: 3 }
+        ));
+
+        let e = err("3\n2\n0 1 1.0\n");
+        assert_eq!(4, e.line());
+        assert!(matches!(e.kind(), ParseErrorKind::UnexpectedEof));
+
+        let e = err("3\n1\n0 1 1.0\n1 2 1.0\n");
+        assert_eq!(4, e.line());
+        assert!(matches!(e.kind(), ParseErrorKind::TrailingData));
+
+        let e = read_text::<Digraph, _>("2\n1\n0 1 0.5\n".as_bytes()).unwrap_err();
+        assert!(matches!(e.kind(), ParseErrorKind::ExtraField));
+        assert!(matches!(err("").kind(), ParseErrorKind::UnexpectedEof));
+
+        let e = err("\n99999999999\n0\n");
+        assert_eq!(2, e.line());
+        assert!(matches!(
+            e.kind(),
+            ParseErrorKind::TooManyVertices(99999999999)
+        ));
+    }
+
+    #[test]
+    fn csv_test() {
+        let g: Digraph = read_csv("from,to\n# 注释\n0, 1\n3,0\n".as_bytes()).unwrap();
+        assert_eq!(4, g.v());
+        assert_eq!(vec![(0, 1), (3, 0)], g.edges().collect::<Vec<_>>());
+        assert_eq!("v,w\n0,1\n3,0\n", to_string(|w| write_csv(&g, w)));
+
+        let g = tiny_ewd();
+        let csv = to_string(|w| write_csv(&g, w));
+        let h: EdgeWeightedDigraph = read_csv(csv.as_bytes()).unwrap();
+        assert_eq!(g.edge_list(), h.edge_list());
+
+        let e =
+            read_csv::<EdgeWeightedGraph, _>("v,w,weight\n0,1,1\n1,2\n".as_bytes()).unwrap_err();
+        assert_eq!(3, e.line());
+        assert!(matches!(e.kind(), ParseErrorKind::MissingField));
+    }
+
+    #[test]
+    fn csv_header_test() {
+        let g: Graph = read_csv("# 注释\nfrom,to\n0,1\n".as_bytes()).unwrap();
+        assert_eq!(2, g.v());
+        assert_eq!(1, g.e());
+
+        let e = read_csv::<Graph, _>("0,1\nfrom,to\n".as_bytes()).unwrap_err();
+        assert_eq!(2, e.line());
+        assert!(matches!(e.kind(), ParseErrorKind::InvalidNumber(s) if s == "from"));
+
+        let e =
+            read_csv::<Graph, _>("0,1\n# 注释\n0,18446744073709551615\n".as_bytes()).unwrap_err();
+        assert_eq!(3, e.line());
+        assert!(matches!(
+            e.kind(),
+            ParseErrorKind::VertexOutOfRange {
+                vertex: usize::MAX,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn dot_test() {
+        let g: Graph = read_text(TINY_G.as_bytes()).unwrap();
+        let dot = to_string(|w| write_dot(&g, w, &Highlight::path(&[2, 1, 0])));
+        assert_eq!(
+            "graph {\n  0 [color=red];\n  1 [color=red];\n  2 [color=red];\n  3;\n  4;\n  5;\n  \
+             0 -- 1 [color=red, penwidth=2];\n  1 -- 2 [color=red, penwidth=2];\n  3 -- 3;\n  4 -- 5;\n}\n",
+            dot
+        );
+
+        let mut g = EdgeWeightedDigraph::new(3);
+        g.insert_edge(0, 1, 0.5);
+        g.insert_edge(1, 0, 0.25);
+        g.insert_edge(1, 2, 1.0);
+        let dot = to_string(|w| write_dot(&g, w, &Highlight::cycle(&[0, 1])));
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.contains("  0 -> 1 [label=\"0.5\", color=red, penwidth=2];\n"));
+        assert!(dot.contains("  1 -> 0 [label=\"0.25\", color=red, penwidth=2];\n"));
+        assert!(dot.contains("  1 -> 2 [label=\"1\"];\n"));
+        assert!(dot.contains("  2;\n"));
+    }
+
+    #[test]
+    fn graphml_test() {
+        let g = tiny_ewd();
+        let xml = to_string(|w| write_graphml(&g, w));
+        assert!(xml.contains(r#"<graph id="G" edgedefault="directed">"#));
+        let h: EdgeWeightedDigraph = read_graphml(xml.as_bytes()).unwrap();
+        assert_eq!(g.v(), h.v());
+        assert_eq!(g.edge_list(), h.edge_list());
+
+        let g: Graph = read_text(TINY_G.as_bytes()).unwrap();
+        let xml = to_string(|w| write_graphml(&g, w));
+        let h: Graph = read_graphml(xml.as_bytes()).unwrap();
+        assert_eq!(g.edge_list(), h.edge_list());
+
+        // 方向不一致。
+        let e = read_graphml::<Digraph, _>(xml.as_bytes()).unwrap_err();
+        assert_eq!(3, e.line());
+        assert!(matches!(e.kind(), ParseErrorKind::Direction));
+    }
+
+    #[test]
+    fn graphml_read_test() {
+        let xml = r#"<?xml version="1.0"?>
+<graphml>
+  <key id="d0" for="edge" attr.name="weight" attr.type="double"/>
+  <graph edgedefault="undirected">
+    <!-- 边可以出现在顶点之前。 -->
+    <edge source="b" target="a"><data key="d0">2.5</data></edge>
+    <node id="a"/>
+    <node id='b'></node>
+  </graph>
+</graphml>"#;
+        let g: EdgeWeightedGraph = read_graphml(xml.as_bytes()).unwrap();
+        assert_eq!(2, g.v());
+        assert_eq!(vec![(1, 0, 2.5)], g.edge_list());
+
+        let e =
+            read_graphml::<EdgeWeightedGraph, _>(xml.replace("2.5", "?").as_bytes()).unwrap_err();
+        assert_eq!(6, e.line());
+        assert!(matches!(e.kind(), ParseErrorKind::InvalidNumber(_)));
+
+        let e = read_graphml::<EdgeWeightedGraph, _>(xml.replace("\"a\"/>", "\"c\"/>").as_bytes())
+            .unwrap_err();
+        assert_eq!(6, e.line());
+        assert!(matches!(e.kind(), ParseErrorKind::UnknownVertex(id) if id == "a"));
+
+        let e = read_graphml::<Graph, _>(
+            "<graph edgedefault=\"undirected\">\n<edge target=\"a\"/>".as_bytes(),
+        )
+        .unwrap_err();
+        assert_eq!(2, e.line());
+        assert!(matches!(e.kind(), ParseErrorKind::Syntax("source")));
+    }
+}
@@ -0,0 +1,182 @@
+//! 所有顶点对之间最短路径的 Johnson 算法，适用于稀疏图。
+
+use std::collections::HashMap;
+
+use super::{
+    bellman_ford_sp::BellmanFordSP, dijkstra_sp::DijkstraSP, directed_edge::DirectedEdge,
+    edge_weighted_digraph::EdgeWeightedDigraph,
+};
+
+pub struct Johnson {
+    dist_to: Vec<Vec<f64>>,                  // s 到 t 的最短距离。
+    edge_to: Vec<Vec<Option<DirectedEdge>>>, // s 到 t 最短路径的最后一条边。
+    cycle: Vec<DirectedEdge>,                // 负权重环。
+}
+
+impl Johnson {
+    /// 用 Bellman-Ford 算法重新赋权，再对每个顶点运行 Dijkstra 算法。
+    pub fn new(g: &EdgeWeightedDigraph) -> Self {
+        let n = g.v();
+        let mut j = Self {
+            dist_to: vec![vec![f64::INFINITY; n]; n],
+            edge_to: vec![vec![None; n]; n],
+            cycle: vec![],
+        };
+
+        // 新增顶点 q，以权重为 0 的边指向所有顶点。
+        let mut aug = EdgeWeightedDigraph::new(n + 1);
+        for v in 0..n {
+            for e in g.adj(v) {
+                aug.add_edge(e.clone());
+            }
+            aug.add_edge(DirectedEdge::new(n, v, 0.0));
+        }
+
+        let bf = BellmanFordSP::new(&aug, n);
+        if let Some(cycle) = bf.negative_cycle() {
+            j.cycle = cycle.to_vec();
+            return j;
+        }
+
+        // 平行边中只有权重最小的一条可能出现在最短路径上，按端点记下它在 adj(v) 中的位置。
+        let mut lightest: HashMap<(usize, usize), (usize, &DirectedEdge)> = HashMap::new();
+        for v in 0..n {
+            for (i, e) in g.adj(v).enumerate() {
+                let best = lightest.entry((v, e.to())).or_insert((i, e));
+                if e.weight() < best.1.weight() {
+                    *best = (i, e);
+                }
+            }
+        }
+
+        // 势函数 h(v) 使重新赋权后的边 u->v 的权重 w + h(u) - h(v) 非负。
+        let h: Vec<f64> = (0..n).map(|v| bf.dist_to(v)).collect();
+        let mut reweighted = EdgeWeightedDigraph::new(n);
+        for v in 0..n {
+            for (i, e) in g.adj(v).enumerate() {
+                if lightest[&(v, e.to())].0 == i {
+                    let weight = (e.weight() + h[v] - h[e.to()]).max(0.0);
+                    reweighted.add_edge(DirectedEdge::new(v, e.to(), weight));
+                }
+            }
+        }
+
+        for s in 0..n {
+            let sp = DijkstraSP::new(&reweighted, s).expect("重新赋权后的边权重非负");
+            for t in 0..n {
+                if !sp.has_path_to(t) {
+                    continue;
+                }
+                j.dist_to[s][t] = sp.dist_to(t) - h[s] + h[t];
+                j.edge_to[s][t] = sp
+                    .edge_to(t)
+                    .map(|e| lightest[&(e.from(), e.to())].1.clone());
+            }
+        }
+
+        j
+    }
+
+    /// 是否存在负权重环。
+    pub fn has_negative_cycle(&self) -> bool {
+        !self.cycle.is_empty()
+    }
+
+    /// 获取负权重环中的边，首尾相接。
+    pub fn negative_cycle(&self) -> Option<&[DirectedEdge]> {
+        if self.has_negative_cycle() {
+            Some(&self.cycle)
+        } else {
+            None
+        }
+    }
+
+    /// s 到 t 的最短距离，不可达时为无穷大。
+    ///
+    /// 存在负权重环时最短距离没有意义，此时调用会 panic。
+    pub fn dist(&self, s: usize, t: usize) -> f64 {
+        self.assert_no_negative_cycle();
+        self.dist_to[s][t]
+    }
+
+    /// 是否存在 s 到 t 的路径。
+    pub fn has_path(&self, s: usize, t: usize) -> bool {
+        self.dist_to[s][t] < f64::INFINITY
+    }
+
+    /// 获取 s 到 t 的最短路径，边按从 s 到 t 的顺序排列。
+    ///
+    /// 存在负权重环时最短路径没有意义，此时调用会 panic。
+    pub fn path(&self, s: usize, t: usize) -> Vec<&DirectedEdge> {
+        self.assert_no_negative_cycle();
+        let mut path = vec![];
+        let mut e = self.edge_to[s][t].as_ref();
+        while let Some(x) = e {
+            path.push(x);
+            e = self.edge_to[s][x.from()].as_ref();
+        }
+        path.reverse();
+        path
+    }
+
+    fn assert_no_negative_cycle(&self) {
+        assert!(!self.has_negative_cycle(), "图中存在负权重环");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{floyd_warshall::FloydWarshall, tiny_ewd, tiny_ewdn};
+
+    #[test]
+    fn dist_test() {
+        let g = tiny_ewdn();
+        let j = Johnson::new(&g);
+        let f = FloydWarshall::new(&g);
+        assert!(!j.has_negative_cycle());
+        for s in 0..g.v() {
+            for t in 0..g.v() {
+                assert_eq!(f.has_path(s, t), j.has_path(s, t));
+                assert!((f.dist(s, t) - j.dist(s, t)).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn path_test() {
+        let j = Johnson::new(&tiny_ewdn());
+        let path = j.path(0, 1);
+        let to: Vec<usize> = path.iter().map(|e| e.to()).collect();
+        assert_eq!(&[2, 7, 3, 6, 4, 5, 1], &to[..]);
+
+        let weight: f64 = path.iter().map(|e| e.weight()).sum();
+        assert!((weight - 0.93).abs() < 1e-9);
+    }
+
+    #[test]
+    fn parallel_edge_test() {
+        // 最短路径使用平行边中较轻的一条，并保留原来的权重。
+        let mut g = EdgeWeightedDigraph::new(3);
+        g.add_edge(DirectedEdge::new(0, 1, 2.0));
+        g.add_edge(DirectedEdge::new(0, 1, -1.0));
+        g.add_edge(DirectedEdge::new(1, 2, 0.5));
+        let j = Johnson::new(&g);
+        let path = j.path(0, 2);
+        assert_eq!(2, path.len());
+        assert_eq!(-1.0, path[0].weight());
+        assert_eq!(0.5, path[1].weight());
+        assert!((j.dist(0, 2) + 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn negative_cycle_test() {
+        let mut g = tiny_ewd();
+        g.add_edge(DirectedEdge::new(5, 4, -0.66));
+        let j = Johnson::new(&g);
+        assert!(j.has_negative_cycle());
+
+        let weight: f64 = j.negative_cycle().unwrap().iter().map(|e| e.weight()).sum();
+        assert!(weight < 0.0);
+    }
+}
@@ -1,6 +1,6 @@
 //! 计算强连通分量的 Kosaraju 算法。
 
-use super::{depth_first_order::DepthFirstOrder, digraph::Digraph};
+use super::{depth_first_order::DepthFirstOrder, digraph::Digraph, AdjacencyGraph};
 
 pub struct KosarajuSCC {
     marked: Vec<bool>, // 已访问过的顶点。
@@ -9,14 +9,14 @@ pub struct KosarajuSCC {
 }
 
 impl KosarajuSCC {
-    pub fn new(g: &mut Digraph) -> Self {
-        let g = &mut g.reverse();
+    pub fn new<G: AdjacencyGraph>(g: &G) -> Self {
         let mut k = Self {
             marked: vec![false; g.v()],
             id: vec![0; g.v()],
             count: 0,
         };
-        let order = DepthFirstOrder::new(g);
+        // 按反向图的逆后序在原图中搜索。
+        let order = DepthFirstOrder::new(&reverse(g));
         for s in order.reverse_post() {
             if !k.marked[*s] {
                 k.dfs(g, *s);
@@ -26,12 +26,21 @@ impl KosarajuSCC {
         k
     }
 
-    fn dfs(&mut self, g: &Digraph, s: usize) {
+    fn dfs<G: AdjacencyGraph>(&mut self, g: &G, s: usize) {
         self.marked[s] = true;
         self.id[s] = self.count;
-        for w in g.adj(s) {
-            if !self.marked[*w] {
-                self.dfs(g, *w);
+        let mut stack = vec![g.adj(s)];
+        while let Some(adj) = stack.last_mut() {
+            match adj.next() {
+                Some(w) if !self.marked[w] => {
+                    self.marked[w] = true;
+                    self.id[w] = self.count;
+                    stack.push(g.adj(w));
+                }
+                Some(_) => {}
+                None => {
+                    stack.pop();
+                }
             }
         }
     }
@@ -52,6 +61,17 @@ impl KosarajuSCC {
     }
 }
 
+/// 创建 g 的反向图。
+fn reverse<G: AdjacencyGraph>(g: &G) -> Digraph {
+    let mut r = Digraph::new(g.v());
+    for v in 0..g.v() {
+        for w in g.adj(v) {
+            r.add_edge(w, v);
+        }
+    }
+    r
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,11 +98,26 @@ mod tests {
         assert_eq!(0, k.id(3));
     }
 
+    #[test]
+    fn chain_test() {
+        // 0 -> {1, 2} -> 3，其中 1 和 2 互相可达。
+        let mut g = Digraph::new(4);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 1);
+        g.add_edge(2, 3);
+        let k = KosarajuSCC::new(&g);
+        assert_eq!(3, k.count());
+        assert!(k.strongly_connected(1, 2));
+        assert!(!k.strongly_connected(0, 1));
+        assert!(!k.strongly_connected(2, 3));
+    }
+
     fn create() -> KosarajuSCC {
         let mut g = Digraph::new(4);
         g.add_edge(1, 2);
         g.add_edge(2, 3);
         g.add_edge(3, 1);
-        KosarajuSCC::new(&mut g)
+        KosarajuSCC::new(&g)
     }
 }
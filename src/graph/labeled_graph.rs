@@ -0,0 +1,870 @@
+//! 以任意可散列的值作为顶点的图。
+//!
+//! [`Labeled`] 在内部维护一个以索引表示的图，添加边时同步更新，查询时直接在其上运行算法。
+//! 需要多次查询时，先用 [`Labeled::cc`]、[`Labeled::scc`] 或最短路径算法得到结果对象，
+//! 再通过结果对象回答查询，避免重复计算。
+
+use std::{
+    error::Error,
+    fmt::{self, Debug, Display},
+    hash::{BuildHasher, Hash, RandomState},
+};
+
+use super::{
+    acyclic_sp::AcyclicSP, bellman_ford_sp::BellmanFordSP, breadth_first_paths::BreadthFirstPaths,
+    cc::CC, digraph::Digraph, dijkstra_sp::DijkstraSP, directed_dfs::DirectedDFS,
+    directed_edge::DirectedEdge, edge::Edge, edge_weighted_digraph::EdgeWeightedDigraph,
+    edge_weighted_graph::EdgeWeightedGraph, kosaraju_scc::KosarajuSCC, prim_mst::PrimMST,
+    topological::Topological, AdjacencyGraph, Graph,
+};
+
+const INIT_CAPACITY: usize = 16; // 散列表的初始大小。
+
+/// 顶点标签与顶点索引之间的双向映射。
+///
+/// 每个标签只保存一次：散列表中存放的是标签在 names 中的索引，因此标签不需要实现 [`Clone`]。
+struct Labels<N> {
+    names: Vec<N>,             // 索引-标签。
+    slots: Vec<Option<usize>>, // 线性探测散列表，保存标签的索引。
+    hasher: RandomState,       // 散列函数。
+}
+
+impl<N> Labels<N>
+where
+    N: Hash + Eq,
+{
+    fn new() -> Self {
+        Self {
+            names: vec![],
+            slots: vec![None; INIT_CAPACITY],
+            hasher: RandomState::new(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    fn index(&self, n: &N) -> Option<usize> {
+        self.find(n).1
+    }
+
+    fn name(&self, v: usize) -> &N {
+        &self.names[v]
+    }
+
+    /// 获取标签对应的索引，不存在时分配一个新索引。
+    fn intern(&mut self, n: N) -> usize {
+        if let (_, Some(v)) = self.find(&n) {
+            return v;
+        }
+        // 装填因子不超过 1/2。
+        if 2 * (self.names.len() + 1) > self.slots.len() {
+            self.resize(2 * self.slots.len());
+        }
+        let (i, _) = self.find(&n);
+        let v = self.names.len();
+        self.slots[i] = Some(v);
+        self.names.push(n);
+        v
+    }
+
+    /// 查找标签在散列表中的位置及其索引，不存在时返回可以插入的空位。
+    fn find(&self, n: &N) -> (usize, Option<usize>) {
+        let mut i = self.home(n);
+        while let Some(v) = self.slots[i] {
+            if self.names[v] == *n {
+                return (i, Some(v));
+            }
+            i = (i + 1) % self.slots.len();
+        }
+        (i, None)
+    }
+
+    /// 标签的散列位置。
+    fn home(&self, n: &N) -> usize {
+        (self.hasher.hash_one(n) % self.slots.len() as u64) as usize
+    }
+
+    fn resize(&mut self, m: usize) {
+        self.slots = vec![None; m];
+        for v in 0..self.names.len() {
+            let mut i = self.home(&self.names[v]);
+            while self.slots[i].is_some() {
+                i = (i + 1) % m;
+            }
+            self.slots[i] = Some(v);
+        }
+    }
+
+    /// 把以索引表示的有向边转换为标签。
+    fn edge(&self, e: &DirectedEdge) -> (&N, &N, f64) {
+        (self.name(e.from()), self.name(e.to()), e.weight())
+    }
+}
+
+/// 可以逐个添加顶点的图，[`Labeled`] 用它保存以索引表示的图。
+pub trait GrowableGraph: AdjacencyGraph {
+    /// 创建一个空图。
+    fn empty() -> Self;
+
+    /// 边数。
+    fn e(&self) -> usize;
+
+    /// 顶点不足时扩充到 v + 1 个。
+    fn ensure_vertex(&mut self, v: usize);
+}
+
+/// 无向图，可以计算连通分量。
+pub trait UndirectedGraph: GrowableGraph {}
+
+/// 有向图，可以计算强连通分量和拓扑排序。
+pub trait DirectedGraph: GrowableGraph {}
+
+macro_rules! growable {
+    ($($t:ty: $kind:ident),*) => {
+        $(
+            impl GrowableGraph for $t {
+                fn empty() -> Self {
+                    Self::new(0)
+                }
+
+                fn e(&self) -> usize {
+                    self.e()
+                }
+
+                fn ensure_vertex(&mut self, v: usize) {
+                    self.ensure_vertex(v);
+                }
+            }
+
+            impl $kind for $t {}
+        )*
+    };
+}
+
+growable!(
+    Graph: UndirectedGraph,
+    EdgeWeightedGraph: UndirectedGraph,
+    Digraph: DirectedGraph,
+    EdgeWeightedDigraph: DirectedGraph
+);
+
+/// 顶点为任意标签的图，G 为内部以索引表示的图。
+pub struct Labeled<N, G> {
+    labels: Labels<N>, // 顶点标签。
+    g: G,              // 以索引表示的图。
+}
+
+/// 顶点为任意标签的无向图。
+pub type LabeledGraph<N> = Labeled<N, Graph>;
+
+/// 顶点为任意标签的有向图。
+pub type LabeledDigraph<N> = Labeled<N, Digraph>;
+
+/// 顶点为任意标签的加权无向图。
+pub type LabeledEdgeWeightedGraph<N> = Labeled<N, EdgeWeightedGraph>;
+
+/// 顶点为任意标签的加权有向图。
+pub type LabeledEdgeWeightedDigraph<N> = Labeled<N, EdgeWeightedDigraph>;
+
+impl<N, G> Labeled<N, G>
+where
+    N: Hash + Eq,
+    G: GrowableGraph,
+{
+    /// 创建一个空图。
+    pub fn new() -> Self {
+        Self {
+            labels: Labels::new(),
+            g: G::empty(),
+        }
+    }
+
+    /// 顶点数。
+    pub fn v(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// 边数。
+    pub fn e(&self) -> usize {
+        self.g.e()
+    }
+
+    /// 添加顶点，返回它的索引；顶点已存在时返回原有索引。
+    pub fn add_vertex(&mut self, n: N) -> usize {
+        let v = self.labels.intern(n);
+        self.g.ensure_vertex(v);
+        v
+    }
+
+    /// 是否包含顶点 n。
+    pub fn contains(&self, n: &N) -> bool {
+        self.labels.index(n).is_some()
+    }
+
+    /// 顶点 n 的索引。
+    pub fn index(&self, n: &N) -> Option<usize> {
+        self.labels.index(n)
+    }
+
+    /// 索引 v 对应的顶点。
+    pub fn name(&self, v: usize) -> &N {
+        self.labels.name(v)
+    }
+
+    /// 以索引表示的图。
+    pub fn graph(&self) -> &G {
+        &self.g
+    }
+
+    /// 由 n 出发的边所连接的所有顶点。
+    pub fn adj(&self, n: &N) -> Vec<&N> {
+        match self.index(n) {
+            Some(v) => self.g.adj(v).map(|w| self.name(w)).collect(),
+            None => vec![],
+        }
+    }
+
+    /// 从 from 出发进行广度优先搜索，from 不存在时返回 [`None`]。
+    pub fn bfs(&self, from: &N) -> Option<Paths<'_, N>> {
+        let s = self.index(from)?;
+        Some(Paths {
+            labels: &self.labels,
+            bfs: BreadthFirstPaths::new(&self.g, s),
+        })
+    }
+
+    /// 边数最少的从 from 到 to 的路径（含两端），不存在时返回 [`None`]。
+    ///
+    /// 对同一起点多次查询时使用 [`Labeled::bfs`]。
+    pub fn shortest_path(&self, from: &N, to: &N) -> Option<Vec<&N>> {
+        self.bfs(from)?.path_to(to)
+    }
+
+    /// 从 sources 中任意顶点出发可达的所有顶点，不存在的起点会被忽略。
+    pub fn reachable<'a>(&self, sources: impl IntoIterator<Item = &'a N>) -> Vec<&N>
+    where
+        N: 'a,
+    {
+        let sources: Vec<_> = sources.into_iter().filter_map(|n| self.index(n)).collect();
+        let dfs = DirectedDFS::from_sources(&self.g, sources);
+        (0..self.v())
+            .filter(|&v| dfs.marked(v))
+            .map(|v| self.name(v))
+            .collect()
+    }
+
+    /// 添加边时获取两端的索引，缺少的顶点会被自动添加。
+    fn intern_pair(&mut self, a: N, b: N) -> (usize, usize) {
+        let v = self.add_vertex(a);
+        let w = self.add_vertex(b);
+        (v, w)
+    }
+}
+
+impl<N> Labeled<N, Graph>
+where
+    N: Hash + Eq,
+{
+    /// 添加边 a-b，缺少的顶点会被自动添加。
+    pub fn add_edge(&mut self, a: N, b: N) {
+        let (v, w) = self.intern_pair(a, b);
+        self.g.add_edge(v, w);
+    }
+}
+
+impl<N> Labeled<N, Digraph>
+where
+    N: Hash + Eq,
+{
+    /// 添加边 from->to，缺少的顶点会被自动添加。
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let (v, w) = self.intern_pair(from, to);
+        self.g.add_edge(v, w);
+    }
+}
+
+impl<N> Labeled<N, EdgeWeightedGraph>
+where
+    N: Hash + Eq,
+{
+    /// 添加带权重的边 a-b，缺少的顶点会被自动添加。
+    pub fn add_edge(&mut self, a: N, b: N, weight: f64) {
+        let (v, w) = self.intern_pair(a, b);
+        self.g.add_edge(Edge::new(v, w, weight));
+    }
+
+    /// 最小生成树（图不连通时为最小生成森林）的所有边。
+    pub fn minimum_spanning_tree(&self) -> Vec<(&N, &N, f64)> {
+        PrimMST::new(&self.g)
+            .edges()
+            .map(|e| {
+                let v = e.either();
+                let w = e.other(v).expect("边包含顶点 v");
+                (self.name(v), self.name(w), e.weight())
+            })
+            .collect()
+    }
+}
+
+impl<N> Labeled<N, EdgeWeightedDigraph>
+where
+    N: Hash + Eq,
+{
+    /// 添加带权重的边 from->to，缺少的顶点会被自动添加。
+    pub fn add_edge(&mut self, from: N, to: N, weight: f64) {
+        let (v, w) = self.intern_pair(from, to);
+        self.g.add_edge(DirectedEdge::new(v, w, weight));
+    }
+
+    /// 用 Dijkstra 算法计算从 from 出发的最短路径，要求所有边的权重非负。
+    pub fn dijkstra(&self, from: &N) -> Result<ShortestPaths<'_, N>, PathError<'_, N>> {
+        let s = self.index(from).ok_or(PathError::UnknownVertex)?;
+        let sp = DijkstraSP::new(&self.g, s).map_err(|e| {
+            let (v, w, weight) = self.labels.edge(e.edge());
+            PathError::NegativeWeight(v, w, weight)
+        })?;
+        Ok(self.shortest_paths(|v| (sp.dist_to(v), sp.edge_to(v))))
+    }
+
+    /// 用 Bellman-Ford 算法计算从 from 出发的最短路径，允许负权重但不允许负权重环。
+    pub fn bellman_ford(&self, from: &N) -> Result<ShortestPaths<'_, N>, PathError<'_, N>> {
+        let s = self.index(from).ok_or(PathError::UnknownVertex)?;
+        let sp = BellmanFordSP::new(&self.g, s);
+        if let Some(cycle) = sp.negative_cycle() {
+            let cycle = cycle.iter().map(|e| self.labels.edge(e)).collect();
+            return Err(PathError::NegativeCycle(cycle));
+        }
+        Ok(self.shortest_paths(|v| (sp.dist_to(v), sp.edge_to(v))))
+    }
+
+    /// 用拓扑排序计算无环图中从 from 出发的最短路径，允许负权重。
+    pub fn acyclic(&self, from: &N) -> Result<ShortestPaths<'_, N>, PathError<'_, N>> {
+        let s = self.index(from).ok_or(PathError::UnknownVertex)?;
+        let sp = AcyclicSP::new(&self.g, s).map_err(|_| PathError::NotDag)?;
+        Ok(self.shortest_paths(|v| (sp.dist_to(v), sp.edge_to(v))))
+    }
+
+    /// 复制最短路径算法的结果。
+    fn shortest_paths<'a>(
+        &self,
+        f: impl Fn(usize) -> (f64, Option<&'a DirectedEdge>),
+    ) -> ShortestPaths<'_, N> {
+        let (dist_to, edge_to) = (0..self.v())
+            .map(|v| {
+                let (dist, e) = f(v);
+                (dist, e.cloned())
+            })
+            .unzip();
+        ShortestPaths {
+            labels: &self.labels,
+            dist_to,
+            edge_to,
+        }
+    }
+}
+
+impl<N, G> Labeled<N, G>
+where
+    N: Hash + Eq,
+    G: UndirectedGraph,
+{
+    /// 计算连通分量。
+    pub fn cc(&self) -> Components<'_, N> {
+        let cc = CC::new(&self.g);
+        Components::new(&self.labels, cc.count(), |v| cc.id(v))
+    }
+
+    /// 所有的连通分量，每个分量中的顶点按添加顺序排列。
+    pub fn connected_components(&self) -> Vec<Vec<&N>> {
+        self.cc().components()
+    }
+}
+
+impl<N, G> Labeled<N, G>
+where
+    N: Hash + Eq,
+    G: DirectedGraph,
+{
+    /// 计算强连通分量。
+    pub fn scc(&self) -> Components<'_, N> {
+        let scc = KosarajuSCC::new(&self.g);
+        Components::new(&self.labels, scc.count(), |v| scc.id(v))
+    }
+
+    /// 所有的强连通分量，每个分量中的顶点按添加顺序排列。
+    pub fn strong_components(&self) -> Vec<Vec<&N>> {
+        self.scc().components()
+    }
+
+    /// 拓扑排序，图中有环时返回 [`None`]。
+    pub fn topological_order(&self) -> Option<Vec<&N>> {
+        let t = Topological::new(&self.g);
+        if !t.is_dag() {
+            return None;
+        }
+        Some(t.order().iter().map(|&v| self.name(v)).collect())
+    }
+}
+
+impl<N, G> Default for Labeled<N, G>
+where
+    N: Hash + Eq,
+    G: GrowableGraph,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N> Extend<(N, N)> for Labeled<N, Graph>
+where
+    N: Hash + Eq,
+{
+    fn extend<T: IntoIterator<Item = (N, N)>>(&mut self, iter: T) {
+        for (a, b) in iter {
+            self.add_edge(a, b);
+        }
+    }
+}
+
+impl<N> Extend<(N, N)> for Labeled<N, Digraph>
+where
+    N: Hash + Eq,
+{
+    fn extend<T: IntoIterator<Item = (N, N)>>(&mut self, iter: T) {
+        for (from, to) in iter {
+            self.add_edge(from, to);
+        }
+    }
+}
+
+impl<N> Extend<(N, N, f64)> for Labeled<N, EdgeWeightedGraph>
+where
+    N: Hash + Eq,
+{
+    fn extend<T: IntoIterator<Item = (N, N, f64)>>(&mut self, iter: T) {
+        for (a, b, weight) in iter {
+            self.add_edge(a, b, weight);
+        }
+    }
+}
+
+impl<N> Extend<(N, N, f64)> for Labeled<N, EdgeWeightedDigraph>
+where
+    N: Hash + Eq,
+{
+    fn extend<T: IntoIterator<Item = (N, N, f64)>>(&mut self, iter: T) {
+        for (from, to, weight) in iter {
+            self.add_edge(from, to, weight);
+        }
+    }
+}
+
+impl<N, G, E> FromIterator<E> for Labeled<N, G>
+where
+    N: Hash + Eq,
+    G: GrowableGraph,
+    Self: Extend<E>,
+{
+    fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
+        let mut g = Self::new();
+        g.extend(iter);
+        g
+    }
+}
+
+/// 广度优先搜索的结果，由 [`Labeled::bfs`] 创建。
+pub struct Paths<'a, N> {
+    labels: &'a Labels<N>,  // 顶点标签。
+    bfs: BreadthFirstPaths, // 以索引表示的搜索结果。
+}
+
+impl<'a, N> Paths<'a, N>
+where
+    N: Hash + Eq,
+{
+    /// 是否存在起点到 n 的路径。
+    pub fn has_path_to(&self, n: &N) -> bool {
+        self.labels
+            .index(n)
+            .is_some_and(|v| self.bfs.has_path_to(v))
+    }
+
+    /// 边数最少的从起点到 n 的路径（含两端），不存在时返回 [`None`]。
+    pub fn path_to(&self, n: &N) -> Option<Vec<&'a N>> {
+        let v = self.labels.index(n)?;
+        if !self.bfs.has_path_to(v) {
+            return None;
+        }
+        Some(
+            self.bfs
+                .path_to(v)
+                .into_iter()
+                .rev()
+                .map(|x| self.labels.name(x))
+                .collect(),
+        )
+    }
+}
+
+/// 连通分量或强连通分量，由 [`Labeled::cc`] 或 [`Labeled::scc`] 创建。
+pub struct Components<'a, N> {
+    labels: &'a Labels<N>, // 顶点标签。
+    id: Vec<usize>,        // 分量的标识符。
+    count: usize,          // 分量的数量。
+}
+
+impl<'a, N> Components<'a, N>
+where
+    N: Hash + Eq,
+{
+    fn new(labels: &'a Labels<N>, count: usize, id: impl Fn(usize) -> usize) -> Self {
+        Self {
+            labels,
+            id: (0..labels.len()).map(id).collect(),
+            count,
+        }
+    }
+
+    /// 分量的数量。
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// n 所在分量的标识符，n 不存在时返回 [`None`]。
+    pub fn id(&self, n: &N) -> Option<usize> {
+        self.labels.index(n).map(|v| self.id[v])
+    }
+
+    /// a 和 b 是否在同一个分量中。
+    pub fn connected(&self, a: &N, b: &N) -> bool {
+        match (self.id(a), self.id(b)) {
+            (Some(x), Some(y)) => x == y,
+            _ => false,
+        }
+    }
+
+    /// 所有的分量，每个分量中的顶点按添加顺序排列。
+    pub fn components(&self) -> Vec<Vec<&'a N>> {
+        let mut groups = vec![vec![]; self.count];
+        for (n, &id) in self.labels.names.iter().zip(&self.id) {
+            groups[id].push(n);
+        }
+        groups
+    }
+}
+
+/// 加权有向图中从一个起点出发的最短路径，由 [`Labeled::dijkstra`]、
+/// [`Labeled::bellman_ford`] 或 [`Labeled::acyclic`] 创建。
+pub struct ShortestPaths<'a, N> {
+    labels: &'a Labels<N>,              // 顶点标签。
+    dist_to: Vec<f64>,                  // 起点到顶点的最短距离。
+    edge_to: Vec<Option<DirectedEdge>>, // 最短路径树中指向顶点的边。
+}
+
+impl<'a, N> ShortestPaths<'a, N>
+where
+    N: Hash + Eq,
+{
+    /// 起点到 n 的最短距离，不可达或 n 不存在时为无穷大。
+    pub fn dist_to(&self, n: &N) -> f64 {
+        self.labels
+            .index(n)
+            .map_or(f64::INFINITY, |v| self.dist_to[v])
+    }
+
+    /// 是否存在起点到 n 的路径。
+    pub fn has_path_to(&self, n: &N) -> bool {
+        self.dist_to(n) < f64::INFINITY
+    }
+
+    /// 起点到 n 的最短路径，边按从起点到 n 的顺序排列，不存在时返回 [`None`]。
+    pub fn path_to(&self, n: &N) -> Option<Vec<(&'a N, &'a N, f64)>> {
+        if !self.has_path_to(n) {
+            return None;
+        }
+        let mut path = vec![];
+        let mut e = self.labels.index(n).and_then(|v| self.edge_to[v].as_ref());
+        while let Some(x) = e {
+            path.push(self.labels.edge(x));
+            e = self.edge_to[x.from()].as_ref();
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// 带标签的加权有向图中最短路径算法的错误。
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathError<'a, N> {
+    /// 起点不存在。
+    UnknownVertex,
+    /// Dijkstra 算法遇到了负权重的边。
+    NegativeWeight(&'a N, &'a N, f64),
+    /// Bellman-Ford 算法发现了负权重环，边按环的顺序排列。
+    NegativeCycle(Vec<(&'a N, &'a N, f64)>),
+    /// 图中有环，无法使用无环图的算法。
+    NotDag,
+}
+
+impl<N: Debug> Display for PathError<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownVertex => write!(f, "起点不存在"),
+            Self::NegativeWeight(v, w, weight) => {
+                write!(f, "边 {v:?}->{w:?} 的权重 {weight} 为负数")
+            }
+            Self::NegativeCycle(_) => write!(f, "图中存在负权重环"),
+            Self::NotDag => write!(f, "图不是有向无环图"),
+        }
+    }
+}
+
+impl<N: Debug> Error for PathError<'_, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vertex_test() {
+        let mut g = LabeledGraph::new();
+        assert_eq!(0, g.add_vertex("a"));
+        g.add_edge("a", "b");
+        g.add_edge("b", "c");
+        assert_eq!(1, g.add_vertex("b"));
+        assert_eq!(3, g.v());
+        assert_eq!(2, g.e());
+        assert_eq!(3, g.graph().v());
+        assert_eq!(Some(2), g.index(&"c"));
+        assert_eq!(&"c", g.name(2));
+        assert!(!g.contains(&"d"));
+        let mut adj = g.adj(&"b");
+        adj.sort();
+        assert_eq!(vec![&"a", &"c"], adj);
+
+        g.add_vertex("d");
+        assert_eq!(4, g.graph().v());
+        assert!(g.adj(&"d").is_empty());
+    }
+
+    #[test]
+    fn shortest_path_test() {
+        let g: LabeledGraph<_> = [
+            ("JFK", "ORD"),
+            ("ORD", "DEN"),
+            ("DEN", "LAS"),
+            ("JFK", "ATL"),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            Some(vec![&"JFK", &"ORD", &"DEN", &"LAS"]),
+            g.shortest_path(&"JFK", &"LAS")
+        );
+        assert_eq!(Some(vec![&"ATL"]), g.shortest_path(&"ATL", &"ATL"));
+        assert_eq!(None, g.shortest_path(&"JFK", &"PHX"));
+
+        let paths = g.bfs(&"ATL").unwrap();
+        assert!(paths.has_path_to(&"LAS"));
+        assert!(!paths.has_path_to(&"PHX"));
+        assert_eq!(Some(vec![&"ATL", &"JFK", &"ORD"]), paths.path_to(&"ORD"));
+        assert!(g.bfs(&"PHX").is_none());
+    }
+
+    #[test]
+    fn connected_components_test() {
+        let mut g: LabeledGraph<_> = [(1, 2), (3, 4), (2, 5)].into_iter().collect();
+        g.add_vertex(6);
+        assert_eq!(
+            vec![vec![&1, &2, &5], vec![&3, &4], vec![&6]],
+            g.connected_components()
+        );
+        let cc = g.cc();
+        assert_eq!(3, cc.count());
+        assert!(cc.connected(&1, &5));
+        assert!(!cc.connected(&1, &3));
+        assert!(!cc.connected(&1, &7));
+        assert_eq!(cc.id(&3), cc.id(&4));
+        assert_eq!(None, cc.id(&7));
+    }
+
+    #[test]
+    fn minimum_spanning_tree_test() {
+        let g: LabeledEdgeWeightedGraph<_> = [
+            ('a', 'b', 1.0),
+            ('b', 'c', 2.0),
+            ('a', 'c', 5.0),
+            ('x', 'y', 0.5),
+        ]
+        .into_iter()
+        .collect();
+        let mut mst = g.minimum_spanning_tree();
+        mst.sort_by(|a, b| a.2.total_cmp(&b.2));
+        assert_eq!(3, mst.len());
+        assert_eq!(0.5, mst[0].2);
+        assert_eq!(3.5, mst.iter().map(|e| e.2).sum::<f64>());
+        assert!(mst.iter().all(|e| e.2 != 5.0));
+        assert_eq!(2, g.cc().count());
+    }
+
+    #[test]
+    fn topological_order_test() {
+        let g: LabeledDigraph<_> = [
+            ("shirt", "tie"),
+            ("tie", "jacket"),
+            ("pants", "shoes"),
+            ("pants", "belt"),
+            ("belt", "jacket"),
+            ("socks", "shoes"),
+        ]
+        .into_iter()
+        .collect();
+        let order = g.topological_order().unwrap();
+        assert_eq!(g.v(), order.len());
+        let pos = |n: &str| order.iter().position(|x| **x == n).unwrap();
+        for (from, to) in [("shirt", "tie"), ("tie", "jacket"), ("pants", "belt")] {
+            assert!(pos(from) < pos(to));
+        }
+
+        let cyclic: LabeledDigraph<_> = [(1, 2), (2, 1)].into_iter().collect();
+        assert_eq!(None, cyclic.topological_order());
+        assert_eq!(
+            Some(vec![]),
+            LabeledDigraph::<u8>::new().topological_order()
+        );
+    }
+
+    #[test]
+    fn strong_components_test() {
+        let g: LabeledDigraph<_> = [("a", "b"), ("b", "c"), ("c", "b"), ("c", "d")]
+            .into_iter()
+            .collect();
+        let mut components = g.strong_components();
+        components.sort();
+        assert_eq!(vec![vec![&"a"], vec![&"b", &"c"], vec![&"d"]], components);
+        let scc = g.scc();
+        assert_eq!(3, scc.count());
+        assert!(scc.connected(&"b", &"c"));
+        assert!(!scc.connected(&"a", &"b"));
+    }
+
+    #[test]
+    fn reachable_test() {
+        let g: LabeledDigraph<_> = [("a", "b"), ("b", "c"), ("d", "a")].into_iter().collect();
+        assert_eq!(vec![&"a", &"b", &"c"], g.reachable([&"a"]));
+        assert_eq!(vec![&"c"], g.reachable([&"c", &"z"]));
+        assert_eq!(vec![&"b"], g.adj(&"a"));
+    }
+
+    #[test]
+    fn non_clone_label_test() {
+        #[derive(Debug, PartialEq, Eq, Hash)]
+        struct City(String);
+
+        let mut g = LabeledGraph::new();
+        for i in 0..100 {
+            g.add_edge(City(i.to_string()), City((i + 1).to_string()));
+        }
+        assert_eq!(101, g.v());
+        assert_eq!(Some(42), g.index(&City("42".into())));
+        assert_eq!(&City("7".into()), g.name(7));
+        assert_eq!(
+            Some(3),
+            g.shortest_path(&City("0".into()), &City("2".into()))
+                .map(|p| p.len())
+        );
+    }
+
+    fn flights() -> LabeledEdgeWeightedDigraph<&'static str> {
+        [
+            ("SFO", "LAX", 1.5),
+            ("SFO", "DEN", 2.5),
+            ("LAX", "DEN", 2.0),
+            ("DEN", "ORD", 2.5),
+            ("LAX", "ORD", 4.5),
+            ("ORD", "JFK", 2.0),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn dijkstra_test() {
+        let g = flights();
+        assert_eq!(6, g.e());
+        let sp = g.dijkstra(&"SFO").unwrap();
+        assert_eq!(7.0, sp.dist_to(&"JFK"));
+        assert_eq!(
+            Some(vec![
+                (&"SFO", &"DEN", 2.5),
+                (&"DEN", &"ORD", 2.5),
+                (&"ORD", &"JFK", 2.0)
+            ]),
+            sp.path_to(&"JFK")
+        );
+        assert_eq!(Some(vec![]), sp.path_to(&"SFO"));
+        assert!(!sp.has_path_to(&"BOS"));
+        assert_eq!(None, sp.path_to(&"BOS"));
+
+        let sp = g.dijkstra(&"JFK").unwrap();
+        assert!(!sp.has_path_to(&"SFO"));
+        assert_eq!(f64::INFINITY, sp.dist_to(&"SFO"));
+        assert_eq!(PathError::UnknownVertex, g.dijkstra(&"BOS").err().unwrap());
+
+        let mut g = g;
+        g.add_edge("JFK", "SFO", -1.0);
+        assert_eq!(
+            PathError::NegativeWeight(&"JFK", &"SFO", -1.0),
+            g.dijkstra(&"SFO").err().unwrap()
+        );
+    }
+
+    #[test]
+    fn bellman_ford_test() {
+        let mut g = flights();
+        g.add_edge("DEN", "LAX", -1.0);
+        let sp = g.bellman_ford(&"SFO").unwrap();
+        assert_eq!(1.5, sp.dist_to(&"LAX"));
+        assert_eq!(5.0, sp.dist_to(&"ORD"));
+        assert_eq!(
+            Some(vec![(&"SFO", &"DEN", 2.5), (&"DEN", &"ORD", 2.5)]),
+            sp.path_to(&"ORD")
+        );
+        assert!(g.acyclic(&"SFO").is_err());
+
+        g.add_edge("JFK", "SFO", -8.0);
+        let Err(PathError::NegativeCycle(cycle)) = g.bellman_ford(&"SFO") else {
+            panic!("应当发现负权重环");
+        };
+        assert!(cycle.iter().map(|e| e.2).sum::<f64>() < 0.0);
+        for (e, next) in cycle.iter().zip(cycle.iter().cycle().skip(1)) {
+            assert_eq!(e.1, next.0);
+        }
+    }
+
+    #[test]
+    fn acyclic_test() {
+        let mut g = flights();
+        g.add_edge("LAX", "JFK", -2.0);
+        let sp = g.acyclic(&"SFO").unwrap();
+        assert_eq!(-0.5, sp.dist_to(&"JFK"));
+        assert_eq!(
+            Some(vec![(&"SFO", &"LAX", 1.5), (&"LAX", &"JFK", -2.0)]),
+            sp.path_to(&"JFK")
+        );
+        assert_eq!(
+            sp.dist_to(&"ORD"),
+            g.bellman_ford(&"SFO").unwrap().dist_to(&"ORD")
+        );
+
+        g.add_edge("JFK", "SFO", 1.0);
+        assert_eq!(PathError::NotDag, g.acyclic(&"SFO").err().unwrap());
+        assert_eq!(
+            "图不是有向无环图",
+            g.acyclic(&"SFO").err().unwrap().to_string()
+        );
+    }
+}
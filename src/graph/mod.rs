@@ -1,26 +1,47 @@
 //! 图。
 
-mod breadth_first_paths;
-mod cc;
-mod cycle;
-mod depth_first_search;
-mod digraph;
-mod directed_cycle;
-mod directed_dfs;
+pub mod breadth_first_paths;
+pub mod cc;
+pub mod cycle;
+pub mod depth_first_search;
+pub mod digraph;
+pub mod directed_cycle;
+pub mod directed_dfs;
 mod symbol_graph;
-mod two_color;
-mod depth_first_order;
-mod topological;
-mod kosaraju_scc;
-mod transitive_closure;
+pub mod two_color;
+pub mod depth_first_order;
+pub mod topological;
+pub mod kosaraju_scc;
+pub mod transitive_closure;
 mod edge;
 mod edge_weighted_graph;
 mod lazy_prim_mst;
 mod prim_mst;
 mod kruskal_mst;
 mod union_find;
-mod directed_edge;
-mod edge_weighted_digraph;
+pub mod directed_edge;
+pub mod edge_weighted_digraph;
+pub mod dijkstra_sp;
+pub mod edge_weighted_directed_cycle;
+pub mod bellman_ford_sp;
+pub mod acyclic_sp;
+pub mod acyclic_lp;
+pub mod cpm;
+pub mod floyd_warshall;
+pub mod johnson;
+pub mod flow_edge;
+pub mod flow_network;
+pub mod edmonds_karp;
+pub mod dinic;
+pub mod labeled_graph;
+pub mod io;
+
+use std::{ops::Index, vec};
+
+use flow_edge::FlowEdge;
+use flow_network::FlowNetwork;
+
+use edge_weighted_digraph::EdgeWeightedDigraph;
 
 #[derive(Debug)]
 pub struct Graph {
@@ -60,6 +81,134 @@ impl Graph {
     pub fn e(&self) -> usize {
         self.e
     }
+
+    /// 获取所有的边，每条边只出现一次。
+    pub fn edges(&self) -> Edges<'_> {
+        Edges::new(&self.adj, false)
+    }
+
+    /// 顶点不足时扩充到 v + 1 个。
+    fn ensure_vertex(&mut self, v: usize) {
+        if v >= self.v {
+            self.v = v + 1;
+            self.adj.resize(self.v, vec![]);
+        }
+    }
+}
+
+/// 以邻接表访问的图，搜索算法借用它而不必关心图的具体类型。
+pub trait AdjacencyGraph {
+    /// 顶点数。
+    fn v(&self) -> usize;
+
+    /// 从 v 出发的边所连接的顶点，无向图中为与 v 相邻的顶点。
+    fn adj(&self, v: usize) -> impl Iterator<Item = usize> + '_;
+}
+
+impl AdjacencyGraph for Graph {
+    fn v(&self) -> usize {
+        self.v
+    }
+
+    fn adj(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adj[v].iter().copied()
+    }
+}
+
+impl Index<usize> for Graph {
+    type Output = [usize];
+
+    /// 获取与 v 相邻的顶点。
+    fn index(&self, v: usize) -> &[usize] {
+        self.adj(v)
+    }
+}
+
+impl Extend<(usize, usize)> for Graph {
+    /// 添加边，顶点不足时自动扩充。
+    fn extend<I: IntoIterator<Item = (usize, usize)>>(&mut self, iter: I) {
+        for (v, w) in iter {
+            self.ensure_vertex(v.max(w));
+            self.add_edge(v, w);
+        }
+    }
+}
+
+impl FromIterator<(usize, usize)> for Graph {
+    /// 由边创建图，顶点数为最大的顶点加一。
+    fn from_iter<I: IntoIterator<Item = (usize, usize)>>(iter: I) -> Self {
+        let mut g = Self::new(0);
+        g.extend(iter);
+        g
+    }
+}
+
+impl IntoIterator for Graph {
+    type Item = (usize, usize);
+    type IntoIter = vec::IntoIter<(usize, usize)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.edges().collect::<Vec<_>>().into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Graph {
+    type Item = (usize, usize);
+    type IntoIter = Edges<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.edges()
+    }
+}
+
+/// 邻接表中的边，由 [`Graph::edges`] 和 [`Digraph::edges`](digraph::Digraph::edges) 创建。
+pub struct Edges<'a> {
+    adj: &'a [Vec<usize>], // 邻接表。
+    directed: bool,        // 是否为有向图。
+    v: usize,              // 当前的顶点。
+    i: usize,              // 下一条边在 adj[v] 中的位置。
+    self_loops: usize,     // 当前顶点已经遇到的自环数。
+}
+
+impl<'a> Edges<'a> {
+    fn new(adj: &'a [Vec<usize>], directed: bool) -> Self {
+        Self {
+            adj,
+            directed,
+            v: 0,
+            i: 0,
+            self_loops: 0,
+        }
+    }
+}
+
+impl Iterator for Edges<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let list = self.adj.get(self.v)?;
+            let Some(&w) = list.get(self.i) else {
+                self.v += 1;
+                self.i = 0;
+                self.self_loops = 0;
+                continue;
+            };
+            self.i += 1;
+
+            let v = self.v;
+            // 无向图的每条边在邻接表中出现两次，自环在同一个顶点中出现两次。
+            if self.directed || v < w {
+                return Some((v, w));
+            }
+            if v == w {
+                self.self_loops += 1;
+                if self.self_loops % 2 == 1 {
+                    return Some((v, w));
+                }
+            }
+        }
+    }
 }
 
 // 0 ------ 2
@@ -87,3 +236,191 @@ fn graph_data() -> Graph {
     g.add_edge(5, 0);
     g
 }
+
+// 《算法》中的 tinyEWD.txt。
+const TINY_EWD: &str = "8
+15
+4 5 0.35
+5 4 0.35
+4 7 0.37
+5 7 0.28
+7 5 0.28
+5 1 0.32
+0 4 0.38
+0 2 0.26
+7 3 0.39
+1 3 0.29
+2 7 0.34
+6 2 0.40
+3 6 0.52
+6 0 0.58
+6 4 0.93
+";
+
+fn tiny_ewd() -> EdgeWeightedDigraph {
+    io::read_text(TINY_EWD.as_bytes()).expect("tinyEWD.txt 格式正确")
+}
+
+// 《算法》中的 tinyEWDAG.txt。
+const TINY_EWDAG: &str = "8
+13
+5 4 0.35
+4 7 0.37
+5 7 0.28
+5 1 0.32
+4 0 0.38
+0 2 0.26
+3 7 0.39
+1 3 0.29
+7 2 0.34
+6 2 0.40
+3 6 0.52
+6 0 0.58
+6 4 0.93
+";
+
+fn tiny_ewdag() -> EdgeWeightedDigraph {
+    io::read_text(TINY_EWDAG.as_bytes()).expect("tinyEWDAG.txt 格式正确")
+}
+
+// 《算法》中的 tinyEWDn.txt，含有负权重的边。
+const TINY_EWDN: &str = "8
+15
+4 5 0.35
+5 4 0.35
+4 7 0.37
+5 7 0.28
+7 5 0.28
+5 1 0.32
+0 4 0.38
+0 2 0.26
+7 3 0.39
+1 3 0.29
+2 7 0.34
+6 2 -1.20
+3 6 0.52
+6 0 -1.40
+6 4 -1.25
+";
+
+fn tiny_ewdn() -> EdgeWeightedDigraph {
+    io::read_text(TINY_EWDN.as_bytes()).expect("tinyEWDn.txt 格式正确")
+}
+
+// 《算法》中的 tinyFN.txt。
+fn tiny_fn() -> FlowNetwork {
+    let mut g = FlowNetwork::new(6);
+    for &(v, w, capacity) in &[
+        (0, 1, 2.0),
+        (0, 2, 3.0),
+        (1, 3, 3.0),
+        (1, 4, 1.0),
+        (2, 3, 1.0),
+        (2, 4, 1.0),
+        (3, 5, 2.0),
+        (4, 5, 3.0),
+    ] {
+        g.add_edge(FlowEdge::new(v, w, capacity));
+    }
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graph_iter_test() {
+        let mut g: Graph = [(0, 1), (1, 2)].into_iter().collect();
+        assert_eq!(3, g.v());
+        g.extend([(4, 4), (2, 0)]);
+        assert_eq!(5, g.v());
+        assert_eq!(4, g.e());
+        assert_eq!(&[1, 2], &g[0]);
+
+        let edges: Vec<_> = (&g).into_iter().collect();
+        assert_eq!(vec![(0, 1), (0, 2), (1, 2), (4, 4)], edges);
+        assert_eq!(edges, g.into_iter().collect::<Vec<_>>());
+    }
+
+    /// 不保存邻接表的环形图，顶点 v 与 v - 1 和 v + 1 相邻。
+    struct Ring(usize);
+
+    impl AdjacencyGraph for Ring {
+        fn v(&self) -> usize {
+            self.0
+        }
+
+        fn adj(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+            [(v + self.0 - 1) % self.0, (v + 1) % self.0].into_iter()
+        }
+    }
+
+    #[test]
+    fn adjacency_graph_test() {
+        use breadth_first_paths::BreadthFirstPaths;
+        use cc::CC;
+        use kosaraju_scc::KosarajuSCC;
+
+        // 同一个图可以被多个算法借用。
+        let g = graph_data();
+        let bfs = BreadthFirstPaths::new(&g, 0);
+        let cc = CC::new(&g);
+        assert_eq!(vec![4, 2, 0], bfs.path_to(4));
+        assert_eq!(1, cc.count());
+        assert_eq!(6, g.v());
+
+        let ring = Ring(7);
+        assert_eq!(vec![3, 2, 1, 0], BreadthFirstPaths::new(&ring, 0).path_to(3));
+        assert_eq!(1, CC::new(&ring).count());
+
+        // 加权有向图也可以使用无权重的算法。
+        let scc = KosarajuSCC::new(&tiny_ewd());
+        assert_eq!(1, scc.count());
+    }
+
+    #[test]
+    fn large_path_test() {
+        use cc::CC;
+        use cycle::Cycle;
+        use depth_first_order::DepthFirstOrder;
+        use depth_first_search::DepthFirstSearch;
+        use directed_cycle::DirectedCycle;
+        use directed_dfs::DirectedDFS;
+        use edge_weighted_directed_cycle::EdgeWeightedDirectedCycle;
+        use kosaraju_scc::KosarajuSCC;
+        use two_color::TwoColor;
+
+        // 一百万个顶点的路径图，递归实现会栈溢出。
+        const N: usize = 1_000_000;
+        let g: Graph = (1..N).map(|v| (v - 1, v)).collect();
+        assert!(DepthFirstSearch::new(&g, 0).has_path_to(N - 1));
+        assert_eq!(1, CC::new(&g).count());
+        assert!(!Cycle::new(&g).has_cycle());
+        assert!(TwoColor::new(&g).is_bipartite());
+
+        let mut d: digraph::Digraph = (1..N).map(|v| (v - 1, v)).collect();
+        assert!(DirectedDFS::new(&d, 0).marked(N - 1));
+        assert!(!DirectedCycle::new(&d).has_cycle());
+        let order = DepthFirstOrder::new(&d);
+        assert!(order.reverse_post().copied().eq(0..N));
+        assert!(order.pre().copied().eq(0..N));
+        assert_eq!(N, KosarajuSCC::new(&d).count());
+
+        d.add_edge(N - 1, 0);
+        let c = DirectedCycle::new(&d);
+        assert!(c.has_cycle());
+        assert_eq!(N + 1, c.cycle().len());
+        assert_eq!(1, KosarajuSCC::new(&d).count());
+
+        let mut w = edge_weighted_digraph::EdgeWeightedDigraph::new(N);
+        for v in 1..N {
+            w.add_edge(directed_edge::DirectedEdge::new(v - 1, v, 1.0));
+        }
+        assert!(!EdgeWeightedDirectedCycle::new(&w).has_cycle());
+        w.add_edge(directed_edge::DirectedEdge::new(N - 1, 0, -(N as f64)));
+        let c = EdgeWeightedDirectedCycle::new(&w);
+        assert_eq!(N, c.cycle().len());
+        assert_eq!(0, c.cycle()[0].from());
+    }
+}
@@ -1,30 +1,37 @@
 //! 最小生成树 Prim 的即时实现。
 
-use super::{super::sort::min_pq::MinPQ, edge::Edge, edge_weighted_graph::EdgeWeightedGraph};
+use super::{edge::Edge, edge_weighted_graph::EdgeWeightedGraph};
+use crate::sort::index_min_pq::IndexMinPQ;
 
 pub struct PrimMST {
-    marked: Vec<bool>,    // 是否访问过。
-    edge_to: Vec<Edge>,   // 离树最近的边。
-    dist_to: Vec<f64>,    // edge_to[i].weight()。
-    pq: MinPQ<EdgeIndex>, // 有效的横切边。
+    marked: Vec<bool>,          // 是否访问过。
+    edge_to: Vec<Option<Edge>>, // 离树最近的边。
+    dist_to: Vec<f64>,          // edge_to[i].weight()。
+    pq: IndexMinPQ<f64>,        // 有效的横切边。
 }
 
 impl PrimMST {
-    /// 创建新的 [`LazyPrimMST`]。
+    /// 创建新的 [`PrimMST`]，图不连通时得到最小生成森林。
     pub fn new(g: &EdgeWeightedGraph) -> Self {
         let mut l = Self {
             marked: vec![false; g.v()],
-            edge_to: Vec::with_capacity(g.v()),
+            edge_to: vec![None; g.v()],
             dist_to: vec![f64::INFINITY; g.v()],
-            pq: MinPQ::new(g.v()),
+            pq: IndexMinPQ::new(g.v()),
         };
 
-        l.dist_to[0] = 0.0;
-        l.pq.insert(EdgeIndex::new(0, 0.0));
+        for s in 0..g.v() {
+            if l.marked[s] {
+                continue;
+            }
 
-        // 获取权重最小的边。
-        while let Some(ei) = l.pq.delete_min() {
-            l.visit(g, ei.v);
+            l.dist_to[s] = 0.0;
+            l.pq.insert(s, 0.0);
+
+            // 获取权重最小的边。
+            while let Some(v) = l.pq.delete_min() {
+                l.visit(g, v);
+            }
         }
 
         l
@@ -33,39 +40,73 @@ impl PrimMST {
     fn visit(&mut self, g: &EdgeWeightedGraph, v: usize) {
         self.marked[v] = true;
         for e in g.adj(v) {
-            e.other(v).map(|w| {
+            if let Some(w) = e.other(v) {
                 if self.marked[w] || e.weight() >= self.dist_to[w] {
-                    return; // v-w 失效。
+                    continue; // v-w 失效。
                 }
 
                 // 连接 w 和树的最佳边。
-                self.edge_to[w] = e.clone();
+                self.edge_to[w] = Some(e.clone());
                 self.dist_to[w] = e.weight();
-                match self.pq.get_mut(|v| v.v == w) {
-                    Some(v) => v.weight = self.dist_to[w],
-                    None => self.pq.insert(EdgeIndex::new(w, self.dist_to[w])),
+                if self.pq.contains(w) {
+                    self.pq.decrease_key(w, self.dist_to[w]);
+                } else {
+                    self.pq.insert(w, self.dist_to[w]);
                 }
-            });
+            }
         }
     }
-}
 
-#[derive(Debug, PartialEq, PartialOrd)]
-struct EdgeIndex {
-    v: usize,
-    weight: f64,
-}
+    /// 获取树的边。
+    pub fn edges(&self) -> impl Iterator<Item = &Edge> {
+        self.edge_to.iter().flatten()
+    }
 
-impl EdgeIndex {
-    fn new(v: usize, weight: f64) -> Self {
-        Self { v, weight }
+    /// 获取树的总权重。
+    pub fn weight(&self) -> f64 {
+        self.edges().map(|e| e.weight()).sum()
     }
 }
 
-impl Eq for EdgeIndex {}
-impl Ord for EdgeIndex {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other)
-            .expect(&format!("{:?} 不能与 {:?} 比较", self, other))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edges_test() {
+        let m = create();
+        let mut edges: Vec<String> = m.edges().map(|e| e.to_string()).collect();
+        edges.sort();
+        assert_eq!(
+            &["0-2 0.26", "0-7 0.16", "1-7 0.19", "2-3 0.17", "4-5 0.35", "5-7 0.28", "6-2 0.40"],
+            &edges[..]
+        );
+        assert!((m.weight() - 1.81).abs() < 1e-9);
+    }
+
+    // 《算法》中的 tinyEWG.txt。
+    fn create() -> PrimMST {
+        let mut g = EdgeWeightedGraph::new(8);
+        for &(v, w, weight) in &[
+            (4, 5, 0.35),
+            (4, 7, 0.37),
+            (5, 7, 0.28),
+            (0, 7, 0.16),
+            (1, 5, 0.32),
+            (0, 4, 0.38),
+            (2, 3, 0.17),
+            (1, 7, 0.19),
+            (0, 2, 0.26),
+            (1, 2, 0.36),
+            (1, 3, 0.29),
+            (2, 7, 0.34),
+            (6, 2, 0.40),
+            (3, 6, 0.52),
+            (6, 0, 0.58),
+            (6, 4, 0.93),
+        ] {
+            g.add_edge(Edge::new(v, w, weight));
+        }
+        PrimMST::new(&g)
     }
 }
@@ -1,60 +1,62 @@
 //! 符号表。
 
 use super::Graph;
-use std::{collections::HashMap, slice, str};
+use crate::search::tst::TST;
 
 pub struct SymbolGraph {
-    st: HashMap<String, usize>,    // 符号-索引。
-    keys: Vec<(*const u8, usize)>, // 索引-符号（符号由指针和字符串长度构成）。
-    g: Graph,                      // 图。
+    st: TST<usize>,    // 符号-索引。
+    keys: Vec<String>, // 索引-符号。
+    g: Graph,          // 图。
 }
 
 impl SymbolGraph {
     /// 创建一个新的 [`SymbolGraph`]。
     /// 参数 list 由字符串构成，字符串以空格分隔不同符号。
     pub fn new(list: &[&str]) -> Self {
-        let mut s = Self {
-            st: HashMap::with_capacity(list.len()),
-            keys: Vec::with_capacity(list.len()),
-            g: Graph::new(list.len()),
-        };
+        let mut st = TST::new();
+        let mut keys = vec![];
         for &v in list {
             for k in v.split_ascii_whitespace() {
-                if !s.contains(k) {
-                    let m = k.to_owned();
-                    s.keys.push((m.as_ptr(), m.len()));
-                    s.st.insert(m, s.st.len());
+                if !st.contains(k) {
+                    st.put(k, keys.len());
+                    keys.push(k.to_owned());
                 }
             }
+        }
 
+        let mut g = Graph::new(keys.len());
+        for &v in list {
             // 取第一个符号索引作为顶点。
             let mut a = v.split_ascii_whitespace();
-            let v = a.next().map(|k| s.st[k]).expect("顶点之间必须有空格");
+            let v = a.next().and_then(|k| st.get(k)).copied();
+            let v = v.expect("顶点之间必须有空格");
             for k in a {
-                let w = s.st[k];
-                s.g.add_edge(v, w);
+                let w = st.get(k).copied().expect("符号已在第一遍中加入");
+                g.add_edge(v, w);
             }
         }
-        s
+
+        Self { st, keys, g }
     }
 
     /// 是否包含符号。
     pub fn contains(&self, s: &str) -> bool {
-        self.st.contains_key(s)
+        self.st.contains(s)
     }
 
     /// 获取符号对应的索引。
     pub fn index(&self, s: &str) -> usize {
-        self.st[s]
+        *self.st.get(s).unwrap_or_else(|| panic!("符号 {s} 不存在"))
     }
 
     /// 获取索引对应的符号。
     pub fn name(&self, v: usize) -> &str {
-        unsafe {
-            let (ptr, len) = self.keys[v];
-            let slice = slice::from_raw_parts(ptr, len);
-            std::str::from_utf8_unchecked(slice)
-        }
+        &self.keys[v]
+    }
+
+    /// 获取所有以 prefix 开头的符号。
+    pub fn keys_with_prefix(&self, prefix: &str) -> Vec<String> {
+        self.st.keys_with_prefix(prefix)
     }
 
     /// 获取图。
@@ -85,6 +87,15 @@ mod tests {
         assert_eq!("a", s.name(0));
     }
 
+    #[test]
+    fn keys_with_prefix_test() {
+        let s = SymbolGraph::new(&["JFK MCO", "ORD DEN", "ORD HOU", "DFW PHX", "JFK ATL"]);
+        assert_eq!(vec!["JFK"], s.keys_with_prefix("J"));
+        assert_eq!(vec!["ORD"], s.keys_with_prefix("OR"));
+        assert_eq!(vec!["DEN", "DFW"], s.keys_with_prefix("D"));
+        assert_eq!(8, s.graph().v());
+    }
+
     fn create() -> SymbolGraph {
         let list = ["a b", "b c", "c a"];
         let s = SymbolGraph::new(&list[..]);
@@ -1,20 +1,22 @@
 //! 拓扑排序。
 
-use super::{depth_first_order::DepthFirstOrder, digraph::Digraph, directed_cycle::DirectedCycle};
+use super::{depth_first_order::DepthFirstOrder, directed_cycle::DirectedCycle, AdjacencyGraph};
 
 pub struct Topological {
-    order: Vec<usize>,
+    order: Vec<usize>, // 拓扑有序的顶点。
+    is_dag: bool,      // 是否为有向无环图。
 }
 
 impl Topological {
-    /// 创建一个空的拓扑排序结构。
-    pub fn new(g: &mut Digraph) -> Self {
-        let mut t = Self { order: vec![] };
-        let d = DirectedCycle::new(g);
-        if !d.has_cycle() {
+    /// 计算有向图的拓扑排序，图中有环时没有拓扑序。
+    pub fn new<G: AdjacencyGraph>(g: &G) -> Self {
+        let mut t = Self {
+            order: vec![],
+            is_dag: !DirectedCycle::new(g).has_cycle(),
+        };
+        if t.is_dag {
             let dfs = DepthFirstOrder::new(g);
-            let order = dfs.reverse_post();
-            t.order = order.map(|&v| v).collect();
+            t.order = dfs.reverse_post().copied().collect();
         }
         t
     }
@@ -26,13 +28,14 @@ impl Topological {
 
     /// 是否是有向无环图。
     pub fn is_dag(&self) -> bool {
-        !self.order.is_empty()
+        self.is_dag
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::{digraph::Digraph, tiny_ewd, tiny_ewdag};
 
     #[test]
     fn is_dag_test() {
@@ -43,7 +46,24 @@ mod tests {
     #[test]
     fn order_test() {
         let t = create();
-        assert_eq!(&[1,2,3,4,0], t.order());
+        assert_eq!(&[1, 2, 3, 4, 0], t.order());
+    }
+
+    #[test]
+    fn edge_weighted_test() {
+        let t = Topological::new(&tiny_ewdag());
+        assert!(t.is_dag());
+        assert_eq!(&[5, 1, 3, 6, 4, 7, 0, 2], t.order());
+
+        let t = Topological::new(&tiny_ewd());
+        assert!(!t.is_dag());
+    }
+
+    #[test]
+    fn empty_test() {
+        let t = Topological::new(&Digraph::new(0));
+        assert!(t.is_dag());
+        assert!(t.order().is_empty());
     }
 
     fn create() -> Topological {
@@ -51,6 +71,6 @@ mod tests {
         g.add_edge(1, 2);
         g.add_edge(2, 3);
         g.add_edge(3, 4);
-        Topological::new(&mut g)
+        Topological::new(&g)
     }
 }
@@ -1,13 +1,13 @@
 //! 顶点对可达性。
 
-use super::{digraph::Digraph, directed_dfs::DirectedDFS};
+use super::{directed_dfs::DirectedDFS, AdjacencyGraph};
 
 pub struct TransitiveClosure {
     all: Vec<DirectedDFS>,
 }
 
 impl TransitiveClosure {
-    pub fn new(g: &Digraph) -> Self {
+    pub fn new<G: AdjacencyGraph>(g: &G) -> Self {
         let mut t = Self { all: vec![] };
         for v in 0..g.v() {
             t.all.push(DirectedDFS::new(g, v));
@@ -24,6 +24,7 @@ impl TransitiveClosure {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::digraph::Digraph;
 
     #[test]
     fn test() {
@@ -37,4 +38,4 @@ mod tests {
         g.add_edge(2, 3);
         TransitiveClosure::new(&g)
     }
-}
\ No newline at end of file
+}
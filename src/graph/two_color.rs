@@ -1,6 +1,6 @@
 //! 双色问题。
 
-use super::Graph;
+use super::AdjacencyGraph;
 
 pub struct TwoColor {
     marked: Vec<bool>,
@@ -9,7 +9,7 @@ pub struct TwoColor {
 }
 
 impl TwoColor {
-    pub fn new(g: Graph) -> Self {
+    pub fn new<G: AdjacencyGraph>(g: &G) -> Self {
         let marked = vec![false; g.v()];
         let color = vec![false; g.v()];
         let mut c = Self {
@@ -19,20 +19,28 @@ impl TwoColor {
         };
         for s in 0..g.v() {
             if !c.marked[s] {
-                c.dfs(&g, s);
+                c.dfs(g, s);
             }
         }
         c
     }
 
-    fn dfs(&mut self, g: &Graph, s: usize) {
+    fn dfs<G: AdjacencyGraph>(&mut self, g: &G, s: usize) {
         self.marked[s] = true;
-        for w in g.adj(s) {
-            if !self.marked[*w] {
-                self.color[*w] = !self.color[*w];
-                self.dfs(g, *w);
-            } else if self.color[s] == self.color[*w] {
-                self.is_two_colorable = false;
+        let mut stack = vec![(s, g.adj(s))];
+        while let Some((v, adj)) = stack.last_mut() {
+            let v = *v;
+            match adj.next() {
+                Some(w) if !self.marked[w] => {
+                    self.marked[w] = true;
+                    self.color[w] = !self.color[v];
+                    stack.push((w, g.adj(w)));
+                }
+                Some(w) if self.color[v] == self.color[w] => self.is_two_colorable = false,
+                Some(_) => {}
+                None => {
+                    stack.pop();
+                }
             }
         }
     }
@@ -46,11 +54,18 @@ impl TwoColor {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::graph::graph_data;
+    use crate::graph::{graph_data, Graph};
 
     #[test]
     fn test() {
-        let c = TwoColor::new(graph_data());
+        let c = TwoColor::new(&graph_data());
         assert!(!c.is_bipartite());
     }
+
+    #[test]
+    fn bipartite_test() {
+        // 长度为偶数的环是二分图。
+        let g: Graph = [(0, 1), (1, 2), (2, 3), (3, 0)].into_iter().collect();
+        assert!(TwoColor::new(&g).is_bipartite());
+    }
 }
@@ -1,8 +1,11 @@
 #![allow(dead_code)]
 
-mod sort;
-mod search;
-mod graph; 
+pub mod sort;
+pub mod search;
+pub mod graph;
+pub mod string_search;
+pub mod regex;
+pub mod compress;
 
 #[cfg(test)]
 mod tests {
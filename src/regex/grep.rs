@@ -0,0 +1,65 @@
+//! 类似 grep 的文本行过滤。
+
+use std::io::{self, BufRead};
+
+use super::{RegexError, NFA};
+
+/// 查找含有与正则表达式匹配的子字符串的文本行。
+pub struct Grep {
+    nfa: NFA,
+}
+
+impl Grep {
+    /// 由正则表达式构造行过滤器。
+    pub fn new(regexp: &str) -> Result<Self, RegexError> {
+        // 先检查原始的正则表达式，错误的位置才与输入一致。
+        NFA::new(regexp)?;
+        let nfa = NFA::new(&format!(".*({regexp}).*"))?;
+        Ok(Self { nfa })
+    }
+
+    /// 该行是否含有匹配的子字符串。
+    pub fn is_match(&self, line: &str) -> bool {
+        self.nfa.recognizes(line)
+    }
+
+    /// 读取所有文本行，返回其中匹配的行。
+    pub fn filter<R>(&self, reader: R) -> io::Result<Vec<String>>
+    where
+        R: BufRead,
+    {
+        let mut results = vec![];
+        for line in reader.lines() {
+            let line = line?;
+            if self.is_match(&line) {
+                results.push(line);
+            }
+        }
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_test() {
+        let text = "let x = 1;\nfn main() {}\n// fn comment\npub fn run() {}\n";
+        let g = Grep::new("fn [a-z]+\\(").unwrap();
+        assert_eq!(
+            vec!["fn main() {}", "pub fn run() {}"],
+            g.filter(text.as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn error_test() {
+        // 包装成 .*(...).* 后括号恰好配对，原始表达式仍然有错。
+        assert_eq!(
+            Some(RegexError::UnbalancedParen(1)),
+            Grep::new("a)(b").err()
+        );
+        assert_eq!(Some(RegexError::UnbalancedParen(0)), Grep::new("(ab").err());
+    }
+}
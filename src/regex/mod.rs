@@ -0,0 +1,192 @@
+//! 基于非确定有限状态自动机（NFA）的正则表达式。
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::graph::{digraph::Digraph, directed_dfs::DirectedDFS};
+use token::Token;
+
+pub mod grep;
+mod token;
+
+/// 正则表达式对应的 NFA，ε 转换保存在有向图中。
+///
+/// 支持连接、`|`、`*`、`+`、`?`、`.`、字符集 `[...]` 和括号，`\` 用于转义元字符。
+#[allow(clippy::upper_case_acronyms)]
+pub struct NFA {
+    re: Vec<Token>, // 匹配转换。
+    g: Digraph,     // ε 转换。
+    m: usize,       // 状态数，状态 m 为接受状态。
+}
+
+impl NFA {
+    /// 由正则表达式构造 NFA。
+    pub fn new(regexp: &str) -> Result<Self, RegexError> {
+        let tokens = token::tokenize(regexp)?;
+        check_parens(&tokens)?;
+
+        // 外层加上括号，使顶层的 `|` 也能正确处理。
+        let mut re = vec![Token::LParen];
+        re.extend(tokens.into_iter().map(|(t, _)| t));
+        re.push(Token::RParen);
+
+        let m = re.len();
+        let mut g = Digraph::new(m + 1);
+        let mut ops = vec![];
+        for i in 0..m {
+            let mut lp = i;
+            match re[i] {
+                Token::LParen | Token::Or => ops.push(i),
+                Token::RParen => {
+                    // 收集同一层括号中的所有 `|`。
+                    let mut ors = vec![];
+                    let mut op = ops.pop().expect("括号已经匹配");
+                    while re[op] == Token::Or {
+                        ors.push(op);
+                        op = ops.pop().expect("括号已经匹配");
+                    }
+                    lp = op;
+                    for or in ors {
+                        g.add_edge(lp, or + 1);
+                        g.add_edge(or, i);
+                    }
+                }
+                _ => {}
+            }
+
+            // 查看下一个字符是否为闭包运算。
+            match re.get(i + 1) {
+                Some(Token::Star) => {
+                    g.add_edge(lp, i + 1);
+                    g.add_edge(i + 1, lp);
+                }
+                Some(Token::Plus) => g.add_edge(i + 1, lp),
+                Some(Token::Question) => g.add_edge(lp, i + 1),
+                _ => {}
+            }
+
+            if matches!(
+                re[i],
+                Token::LParen | Token::RParen | Token::Star | Token::Plus | Token::Question
+            ) {
+                g.add_edge(i, i + 1);
+            }
+        }
+
+        Ok(Self { re, g, m })
+    }
+
+    /// 文本是否与正则表达式完全匹配。
+    pub fn recognizes(&self, text: &str) -> bool {
+        let dfs = DirectedDFS::new(&self.g, 0);
+        let mut pc: Vec<usize> = (0..=self.m).filter(|&v| dfs.marked(v)).collect();
+
+        for c in text.chars() {
+            // 读入 c 之后可能到达的状态。
+            let matched = pc
+                .iter()
+                .filter(|&&v| v < self.m && self.re[v].matches(c))
+                .map(|&v| v + 1);
+            let dfs = DirectedDFS::from_sources(&self.g, matched);
+            pc = (0..=self.m).filter(|&v| dfs.marked(v)).collect();
+            if pc.is_empty() {
+                return false;
+            }
+        }
+
+        pc.contains(&self.m)
+    }
+}
+
+/// 检查括号是否匹配。
+fn check_parens(tokens: &[(Token, usize)]) -> Result<(), RegexError> {
+    let mut lps = vec![];
+    for (t, p) in tokens {
+        match t {
+            Token::LParen => lps.push(*p),
+            Token::RParen => {
+                lps.pop().ok_or(RegexError::UnbalancedParen(*p))?;
+            }
+            _ => {}
+        }
+    }
+    match lps.pop() {
+        Some(p) => Err(RegexError::UnbalancedParen(p)),
+        None => Ok(()),
+    }
+}
+
+/// 正则表达式的语法错误，位置为字符在表达式中的索引。
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegexError {
+    UnbalancedParen(usize), // 括号不匹配。
+    UnclosedClass(usize),   // 字符集缺少 `]`。
+    DanglingEscape(usize),  // `\` 位于表达式末尾。
+    MissingOperand(usize),  // 闭包运算缺少操作数。
+}
+
+impl Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegexError::UnbalancedParen(i) => write!(f, "位置 {i} 处的括号不匹配"),
+            RegexError::UnclosedClass(i) => write!(f, "位置 {i} 处的字符集缺少 ]"),
+            RegexError::DanglingEscape(i) => write!(f, "位置 {i} 处的 \\ 缺少被转义的字符"),
+            RegexError::MissingOperand(i) => write!(f, "位置 {i} 处的运算符缺少操作数"),
+        }
+    }
+}
+
+impl Error for RegexError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_test() {
+        let nfa = NFA::new("((A*B|AC)D)").unwrap();
+        assert!(nfa.recognizes("AABD"));
+        assert!(nfa.recognizes("ACD"));
+        assert!(!nfa.recognizes("AAAAC"));
+        assert!(!nfa.recognizes("ABDX"));
+    }
+
+    #[test]
+    fn operators_test() {
+        let nfa = NFA::new("a|b|cd").unwrap();
+        assert!(nfa.recognizes("b"));
+        assert!(nfa.recognizes("cd"));
+        assert!(!nfa.recognizes("ab"));
+
+        let nfa = NFA::new("(ab)+c?").unwrap();
+        assert!(nfa.recognizes("ab"));
+        assert!(nfa.recognizes("ababc"));
+        assert!(!nfa.recognizes("c"));
+        assert!(!nfa.recognizes("abcc"));
+
+        let nfa = NFA::new(r"[a-z_][a-z0-9_]*\.rs").unwrap();
+        assert!(nfa.recognizes("main.rs"));
+        assert!(nfa.recognizes("min_pq2.rs"));
+        assert!(!nfa.recognizes("2pq.rs"));
+        assert!(!nfa.recognizes("main_rs"));
+    }
+
+    #[test]
+    fn empty_test() {
+        let nfa = NFA::new("a*").unwrap();
+        assert!(nfa.recognizes(""));
+        assert!(!NFA::new("a").unwrap().recognizes(""));
+    }
+
+    #[test]
+    fn error_test() {
+        assert_eq!(Some(RegexError::UnbalancedParen(2)), NFA::new("ab)").err());
+        assert_eq!(Some(RegexError::UnbalancedParen(1)), NFA::new("a(b").err());
+        assert_eq!(
+            "位置 0 处的运算符缺少操作数",
+            NFA::new("*").err().unwrap().to_string()
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! 正则表达式的词法分析。
+
+use super::RegexError;
+
+/// 正则表达式中的一个记号，每个记号对应 NFA 中的一个状态。
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Token {
+    Char(char),       // 普通字符。
+    Any,              // 通配符 `.`。
+    Class(CharClass), // 字符集 `[...]`。
+    LParen,           // `(`。
+    RParen,           // `)`。
+    Or,               // `|`。
+    Star,             // `*`，零次或多次。
+    Plus,             // `+`，一次或多次。
+    Question,         // `?`，零次或一次。
+}
+
+impl Token {
+    /// 该状态能否读入字符 c。
+    pub(crate) fn matches(&self, c: char) -> bool {
+        match self {
+            Token::Char(x) => *x == c,
+            Token::Any => true,
+            Token::Class(class) => class.contains(c),
+            _ => false,
+        }
+    }
+
+    /// 是否能作为闭包运算的操作数的结尾。
+    fn is_operand_end(&self) -> bool {
+        !matches!(self, Token::LParen | Token::Or)
+    }
+}
+
+/// 字符集，如 `[a-z_]` 或 `[^0-9]`。
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CharClass {
+    negated: bool,             // 是否取反。
+    ranges: Vec<(char, char)>, // 闭区间。
+}
+
+impl CharClass {
+    fn contains(&self, c: char) -> bool {
+        self.ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi) != self.negated
+    }
+}
+
+/// 将正则表达式拆分为记号，同时返回每个记号在表达式中的位置。
+pub(crate) fn tokenize(regexp: &str) -> Result<Vec<(Token, usize)>, RegexError> {
+    let chars: Vec<char> = regexp.chars().collect();
+    let mut tokens: Vec<(Token, usize)> = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        let token = match chars[i] {
+            '\\' => {
+                i += 1;
+                Token::Char(*chars.get(i).ok_or(RegexError::DanglingEscape(i - 1))?)
+            }
+            '.' => Token::Any,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '|' => Token::Or,
+            '[' => {
+                let (class, end) = parse_class(&chars, i)?;
+                i = end;
+                Token::Class(class)
+            }
+            op @ ('*' | '+' | '?') => {
+                if !tokens.last().is_some_and(|(t, _)| t.is_operand_end()) {
+                    return Err(RegexError::MissingOperand(i));
+                }
+                match op {
+                    '*' => Token::Star,
+                    '+' => Token::Plus,
+                    _ => Token::Question,
+                }
+            }
+            c => Token::Char(c),
+        };
+        tokens.push((token, start));
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+/// 解析从 start 处的 `[` 开始的字符集，返回字符集和 `]` 的位置。
+fn parse_class(chars: &[char], start: usize) -> Result<(CharClass, usize), RegexError> {
+    let mut i = start + 1;
+    let negated = chars.get(i) == Some(&'^');
+    if negated {
+        i += 1;
+    }
+
+    let mut ranges = vec![];
+    loop {
+        let lo = match chars.get(i) {
+            None => return Err(RegexError::UnclosedClass(start)),
+            Some(']') => return Ok((CharClass { negated, ranges }, i)),
+            Some('\\') => {
+                i += 1;
+                *chars.get(i).ok_or(RegexError::DanglingEscape(i - 1))?
+            }
+            Some(&c) => c,
+        };
+
+        // 形如 a-z 的区间；末尾的 `-` 视为普通字符。
+        let hi = match (chars.get(i + 1), chars.get(i + 2)) {
+            (Some('-'), Some(&c)) if c != ']' => {
+                i += 2;
+                c
+            }
+            _ => lo,
+        };
+        ranges.push((lo, hi));
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_test() {
+        let tokens: Vec<Token> = tokenize(r"(a|\.)*[^0-9x-]")
+            .unwrap()
+            .into_iter()
+            .map(|(t, _)| t)
+            .collect();
+        assert_eq!(7, tokens.len());
+        assert_eq!(Token::Char('.'), tokens[3]);
+        assert_eq!(Token::Star, tokens[5]);
+        assert!(tokens[6].matches('a'));
+        assert!(!tokens[6].matches('5'));
+        assert!(!tokens[6].matches('-'));
+    }
+
+    #[test]
+    fn error_test() {
+        assert_eq!(Err(RegexError::MissingOperand(0)), tokenize("*a"));
+        assert_eq!(Err(RegexError::MissingOperand(2)), tokenize("a|+"));
+        assert_eq!(Err(RegexError::UnclosedClass(1)), tokenize("a[bc"));
+        assert_eq!(Err(RegexError::DanglingEscape(1)), tokenize(r"a\"));
+    }
+}
@@ -0,0 +1,122 @@
+//! 节点池：以下标代替指针，保存链表和树的节点。
+
+use std::ops::{Index, IndexMut};
+
+/// 节点池，被删除节点的位置会在之后插入时复用。
+pub(crate) struct Arena<T> {
+    slots: Vec<Slot<T>>, // 所有的位置。
+    free: Option<usize>, // 空闲位置链表的头部。
+    len: usize,          // 节点的数量。
+}
+
+enum Slot<T> {
+    Occupied(T),
+    Vacant(Option<usize>), // 下一个空闲位置。
+}
+
+impl<T> Arena<T> {
+    /// 创建一个空的节点池。
+    pub fn new() -> Self {
+        Self {
+            slots: vec![],
+            free: None,
+            len: 0,
+        }
+    }
+
+    /// 节点的数量。
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 放入一个节点，返回它的位置。
+    pub fn insert(&mut self, value: T) -> usize {
+        self.len += 1;
+        match self.free {
+            Some(i) => {
+                let Slot::Vacant(next) = self.slots[i] else {
+                    unreachable!("空闲链表中的位置 {i} 已被占用");
+                };
+                self.free = next;
+                self.slots[i] = Slot::Occupied(value);
+                i
+            }
+            None => {
+                self.slots.push(Slot::Occupied(value));
+                self.slots.len() - 1
+            }
+        }
+    }
+
+    /// 取出位置 i 的节点，该位置留待复用。
+    pub fn remove(&mut self, i: usize) -> T {
+        match std::mem::replace(&mut self.slots[i], Slot::Vacant(self.free)) {
+            Slot::Occupied(value) => {
+                self.free = Some(i);
+                self.len -= 1;
+                value
+            }
+            vacant => {
+                self.slots[i] = vacant;
+                panic!("位置 {i} 没有节点");
+            }
+        }
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<usize> for Arena<T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        match &self.slots[i] {
+            Slot::Occupied(value) => value,
+            Slot::Vacant(_) => panic!("位置 {i} 没有节点"),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Arena<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        match &mut self.slots[i] {
+            Slot::Occupied(value) => value,
+            Slot::Vacant(_) => panic!("位置 {i} 没有节点"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuse_test() {
+        let mut a = Arena::new();
+        let x = a.insert("x");
+        let y = a.insert("y");
+        assert_eq!(2, a.len());
+
+        assert_eq!("x", a.remove(x));
+        assert_eq!(1, a.len());
+
+        // 复用被删除的位置。
+        let z = a.insert("z");
+        assert_eq!(x, z);
+        assert_eq!("z", a[z]);
+        assert_eq!("y", a[y]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_vacant_test() {
+        let mut a = Arena::new();
+        let x = a.insert(1);
+        a.remove(x);
+        a.remove(x);
+    }
+}
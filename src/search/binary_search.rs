@@ -1,95 +1,200 @@
 //! 二分查找。
 
-struct BinarySearchST {
-    keys: Vec<i32>,
-    values: Vec<i32>,
-    len: usize,
+use std::{iter::Zip, ops::Index, slice, vec};
+
+use super::{OrderedSymbolTable, SymbolTable};
+
+/// 基于有序数组。
+pub struct BinarySearchST<K, V> {
+    keys: Vec<K>,
+    values: Vec<V>,
 }
 
-impl BinarySearchST {
+impl<K, V> BinarySearchST<K, V>
+where
+    K: Ord,
+{
     /// 创建一个新的二分查找符号表。
     pub fn new() -> Self {
         Self {
             keys: vec![],
             values: vec![],
-            len: 0,
         }
     }
 
-    /// 获取指定的键值。
-    pub fn get(&self, key: i32) -> Option<i32> {
-        if self.len == 0 {
-            return None;
-        }
+    /// 获取所有键值对的迭代器，按键的顺序排列。
+    pub fn iter(&self) -> Zip<slice::Iter<'_, K>, slice::Iter<'_, V>> {
+        self.keys.iter().zip(self.values.iter())
+    }
+
+    fn contains_key(&self, i: usize, key: &K) -> bool {
+        i < self.keys.len() && *key == self.keys[i]
+    }
+}
 
+impl<K, V> SymbolTable<K, V> for BinarySearchST<K, V>
+where
+    K: Ord,
+{
+    fn get(&self, key: &K) -> Option<&V> {
         let i = self.rank(key);
         if !self.contains_key(i, key) {
             return None;
         }
 
-        Some(self.values[i])
+        Some(&self.values[i])
     }
 
-    /// 添加一对键值。
-    pub fn put(&mut self, key: i32, value: i32) {
-        let i = self.rank(key);
-        if self.contains_key(i, key) {
+    fn put(&mut self, key: K, value: V) {
+        let i = self.rank(&key);
+        if self.contains_key(i, &key) {
             self.values[i] = value;
             return;
         }
 
-        // 调整长度。
-        self.resize();
-
-        for k in (i + 1..=self.len).rev() {
-            self.keys[k] = self.keys[k - 1];
-            self.values[k] = self.values[k - 1];
-        }
-        self.keys[i] = key;
-        self.values[i] = value;
-        self.len += 1;
+        self.keys.insert(i, key);
+        self.values.insert(i, value);
     }
 
-    /// 删除指定的键值对。
-    pub fn delete(&mut self, key: i32) {
+    fn delete(&mut self, key: &K) -> Option<V> {
         let i = self.rank(key);
         if !self.contains_key(i, key) {
-            return;
+            return None;
         }
 
-        for k in i + 1..self.len {
-            self.keys[k - 1] = self.keys[k];
-            self.values[k - 1] = self.values[k];
-        }
-        self.len -= 1;
+        self.keys.remove(i);
+        Some(self.values.remove(i))
+    }
+
+    fn len(&self) -> usize {
+        self.keys.len()
     }
+}
 
-    fn contains_key(&self, i: usize, key: i32) -> bool {
-        i < self.len && key == self.keys[i]
+impl<K, V> OrderedSymbolTable<K, V> for BinarySearchST<K, V>
+where
+    K: Ord,
+{
+    fn min(&self) -> Option<&K> {
+        self.keys.first()
     }
 
-    fn resize(&mut self) {
-        if self.len < self.keys.len() {
-            return;
+    fn max(&self) -> Option<&K> {
+        self.keys.last()
+    }
+
+    fn floor(&self, key: &K) -> Option<&K> {
+        let i = self.rank(key);
+        if self.contains_key(i, key) {
+            Some(&self.keys[i])
+        } else {
+            i.checked_sub(1).map(|i| &self.keys[i])
         }
-        self.keys.push(0);
-        self.values.push(0);
     }
 
-    fn rank(&self, key: i32) -> usize {
-        let (mut lo, mut hi) = (0, self.len as i32 - 1);
-        while lo <= hi {
+    fn ceiling(&self, key: &K) -> Option<&K> {
+        self.keys.get(self.rank(key))
+    }
+
+    fn rank(&self, key: &K) -> usize {
+        let (mut lo, mut hi) = (0, self.keys.len());
+        while lo < hi {
             let mid = lo + (hi - lo) / 2;
-            if self.keys[mid as usize] == key {
-                return mid as usize;
-            }
-            if self.keys[mid as usize] < key {
+            if self.keys[mid] < *key {
                 lo = mid + 1;
             } else {
-                hi = mid - 1;
+                hi = mid;
             }
         }
-        lo as usize
+        lo
+    }
+
+    fn select(&self, k: usize) -> Option<&K> {
+        self.keys.get(k)
+    }
+
+    fn range_keys(&self, lo: &K, hi: &K) -> Vec<&K> {
+        if lo > hi {
+            return vec![];
+        }
+        let end = self.rank(hi) + usize::from(self.contains(hi));
+        self.keys[self.rank(lo)..end].iter().collect()
+    }
+}
+
+impl<K, V> Default for BinarySearchST<K, V>
+where
+    K: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Index<&K> for BinarySearchST<K, V>
+where
+    K: Ord,
+{
+    type Output = V;
+
+    /// 获取键对应的值，键不存在时 panic。
+    fn index(&self, key: &K) -> &V {
+        self.get(key).expect("键不存在")
+    }
+}
+
+impl<K, V> Extend<(K, V)> for BinarySearchST<K, V>
+where
+    K: Ord,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.put(k, v);
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for BinarySearchST<K, V>
+where
+    K: Ord,
+{
+    /// 先排序再去重，避免逐个插入时移动数组。重复的键保留最后的值。
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut pairs: Vec<(K, V)> = iter.into_iter().collect();
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut st = Self::new();
+        for (k, v) in pairs {
+            match st.keys.last() {
+                Some(last) if *last == k => *st.values.last_mut().unwrap() = v,
+                _ => {
+                    st.keys.push(k);
+                    st.values.push(v);
+                }
+            }
+        }
+        st
+    }
+}
+
+impl<K, V> IntoIterator for BinarySearchST<K, V> {
+    type Item = (K, V);
+    type IntoIter = Zip<vec::IntoIter<K>, vec::IntoIter<V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.into_iter().zip(self.values)
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a BinarySearchST<K, V>
+where
+    K: Ord,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Zip<slice::Iter<'a, K>, slice::Iter<'a, V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
@@ -104,13 +209,43 @@ mod tests {
         bs.put(9, 350);
         bs.put(2, 999);
 
-        assert_eq!(3, bs.len);
-        assert_eq!(Some(999), bs.get(2));
-        assert_eq!(Some(350), bs.get(9));
-        assert_eq!(Some(105), bs.get(5));
+        assert_eq!(3, bs.len());
+        assert_eq!(Some(&999), bs.get(&2));
+        assert_eq!(Some(&350), bs.get(&9));
+        assert_eq!(Some(&105), bs.get(&5));
 
-        bs.delete(2);
-        assert_eq!(2, bs.len);
-        assert_eq!(None, bs.get(2));
+        assert_eq!(Some(999), bs.delete(&2));
+        assert_eq!(2, bs.len());
+        assert_eq!(None, bs.get(&2));
+    }
+
+    #[test]
+    fn string_key_test() {
+        let mut bs = BinarySearchST::new();
+        for (i, k) in ["she", "sells", "sea", "shells", "sea"]
+            .into_iter()
+            .enumerate()
+        {
+            bs.put(k.to_string(), i);
+        }
+        assert_eq!(4, bs.len());
+        assert_eq!(Some(&4), bs.get(&"sea".to_string()));
+        assert_eq!(1, bs.rank(&"sells".to_string()));
+        assert_eq!(4, bs.rank(&"zoo".to_string()));
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut bs: BinarySearchST<_, _> = [(3, 'c'), (1, 'a'), (3, 'x')].into_iter().collect();
+        bs.extend([(2, 'b')]);
+        assert_eq!('x', bs[&3]);
+        assert_eq!(
+            vec![(&1, &'a'), (&2, &'b'), (&3, &'x')],
+            (&bs).into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 'a'), (2, 'b'), (3, 'x')],
+            bs.into_iter().collect::<Vec<_>>()
+        );
     }
 }
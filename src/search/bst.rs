@@ -1,289 +1,455 @@
 //! 基于二叉查找树的符号表。
 
-use std::{collections::VecDeque, fmt::Display, marker::PhantomData, ptr::NonNull};
+use std::{cmp::Ordering, fmt::Display, ops::Index};
 
-type Link = Option<NonNull<Node>>;
+use super::{arena::Arena, check_rank_select, InvariantError, OrderedSymbolTable, SymbolTable};
 
-pub struct BST {
+/// 子节点在节点池中的位置。
+type Link = Option<usize>;
+
+#[allow(clippy::upper_case_acronyms)]
+pub struct BST<K, V> {
+    nodes: Arena<Node<K, V>>, // 保存树的所有节点。
     root: Link,
-    marker: PhantomData<Box<Node>>,
 }
 
-impl BST {
+impl<K, V> BST<K, V>
+where
+    K: Ord,
+{
     /// 创建一个空的符号表。
     pub fn new() -> Self {
         Self {
+            nodes: Arena::new(),
             root: None,
-            marker: PhantomData,
         }
     }
 
-    /// 获取节点数。
-    pub fn size(&self) -> usize {
-        self.size_of(self.root)
+    /// 删除最小节点。
+    pub fn delete_min(&mut self) {
+        if let Some(root) = self.root {
+            let (rest, min) = self.take_min(root);
+            self.root = rest;
+            self.nodes.remove(min);
+        }
     }
 
-    fn size_of(&self, node: Link) -> usize {
-        node.map_or(0, |n| unsafe { (*n.as_ptr()).len })
+    /// 检查二叉查找树的性质：对称顺序、子树大小以及 rank 与 select 的一致性。
+    pub fn check(&self) -> Result<(), InvariantError> {
+        if !self.is_ordered(self.root, None, None) {
+            return Err(InvariantError::Order);
+        }
+        if !self.is_size_consistent(self.root) {
+            return Err(InvariantError::Size);
+        }
+        check_rank_select(self)
     }
 
-    /// 获取指定键对应的值。
-    pub fn get(&self, key: i32) -> Option<i32> {
-        self.get_from(self.root, key)
+    /// 是否满足 [`BST::check`] 检查的所有性质。
+    pub fn is_valid(&self) -> bool {
+        self.check().is_ok()
     }
 
-    fn get_from(&self, root: Link, key: i32) -> Option<i32> {
-        root.map_or(None, |node| unsafe {
-            let n = node.as_ptr();
-            if (*n).key == key {
-                Some((*n).value)
-            } else if (*n).key < key {
-                self.get_from((*n).right, key)
-            } else {
-                self.get_from((*n).left, key)
-            }
-        })
+    /// 获取所有键值对的迭代器，按键的顺序排列。
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut it = Iter {
+            nodes: &self.nodes,
+            stack: vec![],
+        };
+        it.push_left(self.root);
+        it
     }
 
-    /// 插入键值对。
-    pub fn put(&mut self, key: i32, value: i32) {
-        self.root = self.put_with(self.root, key, value);
+    fn put_with(&mut self, x: Link, key: K, value: V) -> usize {
+        let Some(i) = x else {
+            return self.nodes.insert(Node::new(key, value, 1));
+        };
+        match key.cmp(&self.nodes[i].key) {
+            Ordering::Equal => self.nodes[i].value = value,
+            Ordering::Greater => {
+                let r = self.put_with(self.nodes[i].right, key, value);
+                self.nodes[i].right = Some(r);
+            }
+            Ordering::Less => {
+                let l = self.put_with(self.nodes[i].left, key, value);
+                self.nodes[i].left = Some(l);
+            }
+        }
+        self.update_size(i);
+        i
     }
 
-    fn put_with(&self, root: Link, key: i32, value: i32) -> Link {
-        match root {
-            None => {
-                let n = Node::new(key, value, 1);
-                Some(Box::leak(Box::new(n)).into())
-            }
-            Some(node) => unsafe {
-                let mut n = node.as_ptr();
-                if key == (*n).key {
-                    (*n).value = value;
-                } else if key > (*n).key {
-                    (*n).right = self.put_with((*n).right, key, value);
-                } else {
-                    (*n).left = self.put_with((*n).left, key, value);
-                }
-                (*n).len = self.size_of((*n).left) + self.size_of((*n).right) + 1;
-                root
-            },
+    fn floor_node(&self, x: Link, key: &K) -> Link {
+        let n = &self.nodes[x?];
+        match key.cmp(&n.key) {
+            Ordering::Equal => x,
+            Ordering::Less => self.floor_node(n.left, key),
+            Ordering::Greater => self.floor_node(n.right, key).or(x),
         }
     }
 
-    /// 获取最小键。
-    pub fn min(&self) -> Option<i32> {
-        self.min_node(self.root)
-            .map(|n| unsafe { (*n.as_ptr()).key })
+    fn ceiling_node(&self, x: Link, key: &K) -> Link {
+        let n = &self.nodes[x?];
+        match key.cmp(&n.key) {
+            Ordering::Equal => x,
+            Ordering::Greater => self.ceiling_node(n.right, key),
+            Ordering::Less => self.ceiling_node(n.left, key).or(x),
+        }
     }
 
-    fn min_node(&self, root: Link) -> Link {
-        root.map_or(None, |n| unsafe {
-            let node = n.as_ptr();
-            if (*node).left.is_none() {
-                root
-            } else {
-                self.min_node((*node).left)
+    fn rank_from(&self, x: Link, key: &K) -> usize {
+        x.map_or(0, |i| {
+            let n = &self.nodes[i];
+            match key.cmp(&n.key) {
+                Ordering::Equal => self.size_of(n.left),
+                Ordering::Less => self.rank_from(n.left, key),
+                Ordering::Greater => 1 + self.size_of(n.left) + self.rank_from(n.right, key),
             }
         })
     }
 
-    /// 获取最大的键。
-    pub fn max(&self) -> Option<i32> {
-        self.max_node(self.root)
-            .map(|n| unsafe { (*n.as_ptr()).key })
+    /// 删除键，被删除的值保存在 removed 中。
+    fn delete_node(&mut self, x: Link, key: &K, removed: &mut Option<V>) -> Link {
+        let i = x?;
+        match key.cmp(&self.nodes[i].key) {
+            Ordering::Less => {
+                let l = self.delete_node(self.nodes[i].left, key, removed);
+                self.nodes[i].left = l;
+            }
+            Ordering::Greater => {
+                let r = self.delete_node(self.nodes[i].right, key, removed);
+                self.nodes[i].right = r;
+            }
+            Ordering::Equal => {
+                // 回收节点。
+                let t = self.nodes.remove(i);
+                *removed = Some(t.value);
+                let (Some(left), Some(right)) = (t.left, t.right) else {
+                    return t.left.or(t.right);
+                };
+                // 用右子树中的最小节点代替被删除的节点。
+                let (rest, m) = self.take_min(right);
+                self.nodes[m].right = rest;
+                self.nodes[m].left = Some(left);
+                self.update_size(m);
+                return Some(m);
+            }
+        }
+        self.update_size(i);
+        x
     }
 
-    fn max_node(&self, root: Link) -> Link {
-        root.map_or(None, |n| unsafe {
-            let node = n.as_ptr();
-            if (*node).right.is_none() {
-                root
-            } else {
-                self.max_node((*node).right)
+    fn collect_range<'a>(&'a self, x: Link, q: &mut Vec<&'a K>, scope: (&K, &K)) {
+        if let Some(i) = x {
+            let n = &self.nodes[i];
+            let (lo, hi) = scope;
+            if n.key > *lo {
+                self.collect_range(n.left, q, scope);
             }
-        })
+            if n.key >= *lo && n.key <= *hi {
+                q.push(&n.key);
+            }
+            if n.key < *hi {
+                self.collect_range(n.right, q, scope);
+            }
+        }
     }
 
-    /// 向下取整。
-    pub fn floor(&self, key: i32) -> Option<i32> {
-        self.floor_node(self.root, key)
-            .map(|n| unsafe { (*n.as_ptr()).key })
-    }
-
-    fn floor_node(&self, root: Link, key: i32) -> Link {
-        root.map_or(None, |n| unsafe {
-            let node = n.as_ptr();
-            if key == (*node).key {
-                root
-            } else if key < (*node).key {
-                self.floor_node((*node).left, key)
-            } else {
-                self.floor_node((*node).right, key).or(root)
-            }
-        })
+    /// 所有键是否都在 min 和 max 之间，且满足对称顺序。
+    fn is_ordered(&self, x: Link, min: Option<&K>, max: Option<&K>) -> bool {
+        let Some(i) = x else {
+            return true;
+        };
+        let n = &self.nodes[i];
+        if min.is_some_and(|k| n.key <= *k) || max.is_some_and(|k| n.key >= *k) {
+            return false;
+        }
+        self.is_ordered(n.left, min, Some(&n.key)) && self.is_ordered(n.right, Some(&n.key), max)
     }
+}
 
-    /// 选择排名为 k 的键。
-    pub fn select(&self, k: usize) -> Option<i32> {
-        self.select_node(self.root, k)
-            .map(|n| unsafe { (*n.as_ptr()).key })
-    }
-
-    fn select_node(&self, root: Link, k: usize) -> Link {
-        root.map_or(None, |n| unsafe {
-            let node = n.as_ptr();
-            let t = self.size_of((*node).left);
-            if t == k {
-                root
-            } else if t > k {
-                self.select_node((*node).left, k)
-            } else {
-                self.select_node((*node).right, k - t - 1)
-            }
-        })
+impl<K, V> BST<K, V> {
+    fn size_of(&self, x: Link) -> usize {
+        x.map_or(0, |i| self.nodes[i].len)
     }
 
-    /// 获取指定键的排名。
-    pub fn rank(&self, key: i32) -> usize {
-        self.rank_from(self.root, key)
+    fn update_size(&mut self, i: usize) {
+        let n = &self.nodes[i];
+        self.nodes[i].len = self.size_of(n.left) + self.size_of(n.right) + 1;
     }
 
-    fn rank_from(&self, root: Link, key: i32) -> usize {
-        root.map_or(0, |n| unsafe {
-            let node = n.as_ptr();
-            if key == (*node).key {
-                self.size_of((*node).left)
-            } else if key < (*node).key {
-                self.rank_from((*node).left, key)
-            } else {
-                1 + self.size_of((*node).left) + self.rank_from((*node).right, key)
+    fn min_node(&self, mut i: usize) -> usize {
+        while let Some(l) = self.nodes[i].left {
+            i = l;
+        }
+        i
+    }
+
+    fn select_node(&self, x: Link, k: usize) -> Link {
+        let n = &self.nodes[x?];
+        let t = self.size_of(n.left);
+        match t.cmp(&k) {
+            Ordering::Equal => x,
+            Ordering::Greater => self.select_node(n.left, k),
+            Ordering::Less => self.select_node(n.right, k - t - 1),
+        }
+    }
+
+    /// 从以 i 为根的树中摘下最小节点，返回剩余的树和该节点。
+    fn take_min(&mut self, i: usize) -> (Link, usize) {
+        match self.nodes[i].left {
+            None => (self.nodes[i].right, i),
+            Some(left) => {
+                let (rest, min) = self.take_min(left);
+                self.nodes[i].left = rest;
+                self.update_size(i);
+                (Some(i), min)
             }
-        })
+        }
     }
 
-    /// 删除最小节点。
-    pub fn delete_min(&mut self) {
-        self.root = self.delete_min_node(self.root);
+    /// 每个节点记录的子树大小是否正确。
+    fn is_size_consistent(&self, x: Link) -> bool {
+        let Some(i) = x else {
+            return true;
+        };
+        let n = &self.nodes[i];
+        n.len == self.size_of(n.left) + self.size_of(n.right) + 1
+            && self.is_size_consistent(n.left)
+            && self.is_size_consistent(n.right)
     }
+}
 
-    fn delete_min_node(&self, root: Link) -> Link {
-        root.map_or(None, |n| unsafe {
-            let node = n.as_ptr();
-            if (*node).left.is_none() {
-                // 回收节点内存。
-                Box::from_raw(node).right
-            } else {
-                (*node).left = self.delete_min_node((*node).left);
-                (*node).len = self.size_of((*node).left) + self.size_of((*node).right) + 1;
-                root
+impl<K, V> SymbolTable<K, V> for BST<K, V>
+where
+    K: Ord,
+{
+    /// 获取指定键对应的值。
+    fn get(&self, key: &K) -> Option<&V> {
+        let mut x = self.root;
+        while let Some(i) = x {
+            let n = &self.nodes[i];
+            match key.cmp(&n.key) {
+                Ordering::Equal => return Some(&n.value),
+                Ordering::Less => x = n.left,
+                Ordering::Greater => x = n.right,
             }
-        })
+        }
+        None
+    }
+
+    /// 插入键值对。
+    fn put(&mut self, key: K, value: V) {
+        self.root = Some(self.put_with(self.root, key, value));
     }
 
     /// 删除指定节点。
-    pub fn delete(&mut self, key: i32) {
-        self.root = self.delete_node(self.root, key);
+    fn delete(&mut self, key: &K) -> Option<V> {
+        let mut value = None;
+        self.root = self.delete_node(self.root, key, &mut value);
+        value
     }
 
-    fn delete_node(&self, mut root: Link, key: i32) -> Link {
-        root.map_or(None, |n| unsafe {
-            let node = n.as_ptr();
-            if key < (*node).key {
-                (*node).left = self.delete_node((*node).left, key);
-            } else if key > (*node).key {
-                (*node).right = self.delete_node((*node).right, key);
-            } else {
-                if (*node).left.is_none() {
-                    return (*node).right;
-                }
-                if (*node).right.is_none() {
-                    return (*node).left;
-                }
-                // 回收节点内存。
-                let t = Box::from_raw(node);
-                root = self.min_node(t.right);
-                root.map(|x| {
-                    (*x.as_ptr()).right = self.delete_min_node(t.right);
-                    (*x.as_ptr()).left = t.left;
-                });
-            }
-            (*node).len = self.size_of((*node).left) + self.size_of((*node).right) + 1;
-            root
-        })
+    /// 获取节点数。
+    fn len(&self) -> usize {
+        self.size_of(self.root)
     }
+}
 
-    /// 获取所有的键。
-    pub fn keys(&self) -> VecDeque<i32> {
-        self.min()
-            .zip(self.max())
-            .map_or_else(|| VecDeque::new(), |scope| self.keys_from(scope))
+impl<K, V> OrderedSymbolTable<K, V> for BST<K, V>
+where
+    K: Ord,
+{
+    /// 获取最小键。
+    fn min(&self) -> Option<&K> {
+        self.root.map(|i| &self.nodes[self.min_node(i)].key)
+    }
+
+    /// 获取最大的键。
+    fn max(&self) -> Option<&K> {
+        let mut i = self.root?;
+        while let Some(r) = self.nodes[i].right {
+            i = r;
+        }
+        Some(&self.nodes[i].key)
+    }
+
+    /// 向下取整。
+    fn floor(&self, key: &K) -> Option<&K> {
+        self.floor_node(self.root, key).map(|i| &self.nodes[i].key)
+    }
+
+    /// 向上取整。
+    fn ceiling(&self, key: &K) -> Option<&K> {
+        self.ceiling_node(self.root, key)
+            .map(|i| &self.nodes[i].key)
+    }
+
+    /// 获取指定键的排名。
+    fn rank(&self, key: &K) -> usize {
+        self.rank_from(self.root, key)
+    }
+
+    /// 选择排名为 k 的键。
+    fn select(&self, k: usize) -> Option<&K> {
+        self.select_node(self.root, k).map(|i| &self.nodes[i].key)
     }
 
     /// 获取指定范围的键。
-    pub fn keys_from(&self, scope: (i32, i32)) -> VecDeque<i32> {
-        let mut q = VecDeque::new();
-        self.keys_from_impl(self.root, &mut q, scope);
+    fn range_keys(&self, lo: &K, hi: &K) -> Vec<&K> {
+        let mut q = vec![];
+        self.collect_range(self.root, &mut q, (lo, hi));
         q
     }
+}
 
-    fn keys_from_impl(&self, root: Link, q: &mut VecDeque<i32>, scope: (i32, i32)) {
-        root.map(|n| unsafe {
-            let x = n.as_ptr();
-            let (lo, hi) = scope;
-            if (*x).key > lo {
-                self.keys_from_impl((*x).left, q, scope);
-            }
-            if (*x).key >= lo && (*x).key <= hi {
-                q.push_front((*x).key);
-            }
-            if (*x).key < hi {
-                self.keys_from_impl((*x).right, q, scope);
-            }
-        });
+impl<K, V> Default for BST<K, V>
+where
+    K: Ord,
+{
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl Display for BST {
+impl<K, V> Display for BST<K, V>
+where
+    K: Display,
+    V: Display,
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        unsafe fn print(node: Link, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            if let Some(n) = node {
-                let x = n.as_ptr();
-                print((*x).left, f)?;
-                write!(f, "\n({}, {})", (*x).key, (*x).value)?;
-                print((*x).right, f)
+        fn print<K: Display, V: Display>(
+            bst: &BST<K, V>,
+            x: Link,
+            f: &mut std::fmt::Formatter<'_>,
+        ) -> std::fmt::Result {
+            if let Some(i) = x {
+                let n = &bst.nodes[i];
+                print(bst, n.left, f)?;
+                write!(f, "\n({}, {})", n.key, n.value)?;
+                print(bst, n.right, f)
             } else {
                 write!(f, "")
             }
         }
-        unsafe { print(self.root, f) }
+        print(self, self.root, f)
+    }
+}
+
+impl<K, V> Index<&K> for BST<K, V>
+where
+    K: Ord,
+{
+    type Output = V;
+
+    /// 获取键对应的值，键不存在时 panic。
+    fn index(&self, key: &K) -> &V {
+        self.get(key).expect("键不存在")
     }
 }
 
-impl Drop for BST {
-    fn drop(&mut self) {
-        fn drop_impl(node: Link) {
-            node.map(|n| unsafe {
-                // 释放节点内存。
-                let x = Box::from_raw(n.as_ptr());
-                drop_impl(x.left);
-                drop_impl(x.right);
-            });
+impl<K, V> Extend<(K, V)> for BST<K, V>
+where
+    K: Ord,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.put(k, v);
         }
-        drop_impl(self.root);
     }
 }
 
-struct Node {
-    key: i32,
-    value: i32,
+impl<K, V> FromIterator<(K, V)> for BST<K, V>
+where
+    K: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut st = Self::new();
+        st.extend(iter);
+        st
+    }
+}
+
+impl<K, V> IntoIterator for BST<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    /// 按键的顺序取出所有键值对。
+    fn into_iter(mut self) -> Self::IntoIter {
+        // 先按中序记录节点的位置，再逐个取出。
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut stack = vec![];
+        let mut x = self.root;
+        while x.is_some() || !stack.is_empty() {
+            while let Some(i) = x {
+                stack.push(i);
+                x = self.nodes[i].left;
+            }
+            if let Some(i) = stack.pop() {
+                order.push(i);
+                x = self.nodes[i].right;
+            }
+        }
+        order
+            .into_iter()
+            .map(|i| {
+                let n = self.nodes.remove(i);
+                (n.key, n.value)
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a BST<K, V>
+where
+    K: Ord,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// 键值对的迭代器，由 [`BST::iter`] 创建。
+pub struct Iter<'a, K, V> {
+    nodes: &'a Arena<Node<K, V>>, // 树的所有节点。
+    stack: Vec<&'a Node<K, V>>,   // 栈顶为下一个返回的节点。
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    /// 将 x 及其左侧路径上的节点压入栈中。
+    fn push_left(&mut self, mut x: Link) {
+        while let Some(i) = x {
+            let n = &self.nodes[i];
+            self.stack.push(n);
+            x = n.left;
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.stack.pop()?;
+        self.push_left(n.right);
+        Some((&n.key, &n.value))
+    }
+}
+
+struct Node<K, V> {
+    key: K,
+    value: V,
     len: usize,
     left: Link,
     right: Link,
 }
 
-impl Node {
-    fn new(key: i32, value: i32, len: usize) -> Self {
+impl<K, V> Node<K, V> {
+    fn new(key: K, value: V, len: usize) -> Self {
         Self {
             key,
             value,
@@ -302,16 +468,16 @@ mod tests {
     fn put_test() {
         let mut bst = create_bst();
 
-        assert_eq!(3, bst.size());
-        assert_eq!(Some(42), bst.get(1));
-        assert_eq!(Some(98), bst.get(2));
-        assert_eq!(Some(75), bst.get(5));
+        assert_eq!(3, bst.len());
+        assert_eq!(Some(&42), bst.get(&1));
+        assert_eq!(Some(&98), bst.get(&2));
+        assert_eq!(Some(&75), bst.get(&5));
 
         bst.put(2, 99);
-        assert_eq!(Some(99), bst.get(2));
+        assert_eq!(Some(&99), bst.get(&2));
     }
 
-    fn create_bst() -> BST {
+    fn create_bst() -> BST<i32, i32> {
         let mut bst = BST::new();
         bst.put(2, 98);
         bst.put(1, 42);
@@ -323,71 +489,113 @@ mod tests {
     #[test]
     fn min_key_test() {
         let bst = create_bst();
-        assert_eq!(Some(1), bst.min());
+        assert_eq!(Some(&1), bst.min());
     }
 
     #[test]
     fn max_key_test() {
         let bst = create_bst();
-        assert_eq!(Some(5), bst.max());
+        assert_eq!(Some(&5), bst.max());
     }
 
     #[test]
     fn floor_test() {
         let bst = create_bst();
-        assert_eq!(Some(2), bst.floor(3));
+        assert_eq!(Some(&2), bst.floor(&3));
     }
 
     #[test]
     fn select_test() {
         let bst = create_bst();
-        assert_eq!(Some(1), bst.select(0));
+        assert_eq!(Some(&1), bst.select(0));
     }
 
     #[test]
     fn rank_test() {
         let bst = create_bst();
-        assert_eq!(0, bst.rank(1));
+        assert_eq!(0, bst.rank(&1));
     }
 
     #[test]
     fn delete_min_test() {
         let mut bst = create_bst();
-        assert_eq!(Some(42), bst.get(1));
+        assert_eq!(Some(&42), bst.get(&1));
 
         bst.delete_min();
-        assert_eq!(None, bst.get(1));
-        assert_eq!(Some(98), bst.get(2));
-        assert_eq!(Some(75), bst.get(5));
+        assert_eq!(None, bst.get(&1));
+        assert_eq!(Some(&98), bst.get(&2));
+        assert_eq!(Some(&75), bst.get(&5));
     }
 
     #[test]
     fn delete_test() {
         let mut bst = create_bst();
-        assert_eq!(Some(42), bst.get(1));
+        assert_eq!(Some(&42), bst.get(&1));
+
+        assert_eq!(Some(42), bst.delete(&1));
+        assert_eq!(None, bst.get(&1));
+        assert_eq!(Some(&98), bst.get(&2));
+        assert_eq!(Some(&75), bst.get(&5));
+    }
+
+    #[test]
+    fn delete_inner_test() {
+        let mut bst = BST::new();
+        for k in ["s", "e", "a", "r", "c", "h", "x", "m"] {
+            bst.put(k.to_string(), k.len());
+        }
+        // 删除有两个子节点的节点。
+        assert_eq!(Some(1), bst.delete(&"e".to_string()));
+        assert_eq!(7, bst.len());
+        assert_eq!(None, bst.get(&"e".to_string()));
+        let keys: Vec<&str> = bst.keys().into_iter().map(|k| k.as_str()).collect();
+        assert_eq!(["a", "c", "h", "m", "r", "s", "x"], &keys[..]);
+        assert_eq!(2, bst.rank(&"h".to_string()));
+    }
+
+    #[test]
+    fn check_test() {
+        let mut bst = create_bst();
+        assert!(bst.is_valid());
 
-        bst.delete(1);
-        assert_eq!(None, bst.get(1));
-        assert_eq!(Some(98), bst.get(2));
-        assert_eq!(Some(75), bst.get(5));
+        // 破坏子树大小。
+        let root = bst.root.unwrap();
+        bst.nodes[root].len = 4;
+        assert_eq!(Err(InvariantError::Size), bst.check());
+
+        // 破坏对称顺序。
+        bst.nodes[root].len = 3;
+        let left = bst.nodes[root].left.unwrap();
+        bst.nodes[left].key = 3;
+        assert_eq!(Err(InvariantError::Order), bst.check());
     }
 
     #[test]
     fn keys_test() {
         let bst = create_bst();
-        let mut keys = bst.keys();
-        assert_eq!(3, keys.len());
-        assert_eq!(Some(1), keys.pop_back());
-        assert_eq!(Some(2), keys.pop_back());
-        assert_eq!(Some(5), keys.pop_back());
+        assert_eq!(vec![&1, &2, &5], bst.keys());
     }
 
     #[test]
-    fn keys_from_test() {
+    fn range_keys_test() {
         let bst = create_bst();
-        let mut keys = bst.keys_from((2, 6));
-        assert_eq!(2, keys.len());
-        assert_eq!(Some(2), keys.pop_back());
-        assert_eq!(Some(5), keys.pop_back());
+        assert_eq!(vec![&2, &5], bst.range_keys(&2, &6));
+        assert_eq!(Some(&5), bst.ceiling(&3));
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut bst: BST<_, _> = [(5, 'e'), (2, 'b'), (8, 'h')].into_iter().collect();
+        bst.extend([(1, 'a'), (2, 'c')]);
+        assert_eq!('c', bst[&2]);
+
+        let values: String = bst.iter().map(|(_, v)| v).collect();
+        assert_eq!("aceh", values);
+        let keys: Vec<_> = (&bst).into_iter().map(|(k, _)| *k).collect();
+        assert_eq!(vec![1, 2, 5, 8], keys);
+        assert_eq!(
+            vec![(1, 'a'), (2, 'c'), (5, 'e'), (8, 'h')],
+            bst.into_iter().collect::<Vec<_>>()
+        );
     }
 }
@@ -1,146 +1,372 @@
 //! 线性探测符号表。
 
-pub struct LinearProbingHashST {
+use std::{
+    hash::{BuildHasher, Hash, RandomState},
+    iter::Zip,
+    ops::Index,
+    slice,
+};
+
+use super::{check_load_factor, hash, HashStats, SymbolTable};
+
+const INIT_CAPACITY: usize = 16; // 散列表的初始大小。
+
+pub struct LinearProbingHashST<K, V, S = RandomState> {
     n: usize,               // 键值对的总数。
     m: usize,               // 符号表大小。
-    keys: Vec<Option<i32>>, // 键。
-    values: Vec<i32>,       // 值。
+    keys: Vec<Option<K>>,   // 键。
+    values: Vec<Option<V>>, // 值。
+    hasher: S,              // 散列函数。
+    min_load: f64,          // 装填因子低于它时缩小一半。
+    max_load: f64,          // 装填因子超过它时扩大一倍。
 }
 
-impl LinearProbingHashST {
+impl<K, V> LinearProbingHashST<K, V>
+where
+    K: Hash + Eq,
+{
     /// 创建一个空的符号表。
     pub fn new() -> Self {
-        let m = 16;
-        let keys = vec![None; m];
-        let values = vec![0; m];
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<K, V, S> LinearProbingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// 创建一个使用指定散列函数的空符号表。
+    pub fn with_hasher(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(INIT_CAPACITY, hasher)
+    }
+
+    /// 创建一个大小为 m、使用指定散列函数的空符号表。
+    pub fn with_capacity_and_hasher(m: usize, hasher: S) -> Self {
+        let m = m.max(1);
         Self {
             n: 0,
             m,
-            keys,
-            values,
+            keys: empty_slots(m),
+            values: empty_slots(m),
+            hasher,
+            min_load: 0.125,
+            max_load: 0.5,
         }
     }
 
-    /// 插入键值对。
-    pub fn put(&mut self, key: i32, value: i32) {
-        if self.n >= self.m / 2 {
-            self.resize(2 * self.m);
+    /// 设置装填因子的范围：超过 max 时扩大一倍，低于 min 时缩小一半。
+    ///
+    /// 默认为 [0.125, 0.5]。max 必须小于 1，且 min 的两倍必须小于 max。
+    pub fn set_load_factor(&mut self, min: f64, max: f64) {
+        check_load_factor(min, max);
+        assert!(max < 1.0, "线性探测的装填因子必须小于 1");
+        self.min_load = min;
+        self.max_load = max;
+    }
+
+    /// 当前的装填因子。
+    pub fn load_factor(&self) -> f64 {
+        self.n as f64 / self.m as f64
+    }
+
+    /// 获取统计信息，探测长度为从键的散列位置到实际位置比较的次数。
+    pub fn stats(&self) -> HashStats {
+        let probes = self.keys.iter().enumerate().filter_map(|(i, k)| {
+            let home = self.hash(k.as_ref()?);
+            Some((i + self.m - home) % self.m + 1)
+        });
+        HashStats::new(self.m, probes)
+    }
+
+    /// 获取所有键值对的迭代器，顺序不确定。
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            it: self.keys.iter().zip(self.values.iter()),
         }
+    }
+
+    fn hash(&self, key: &K) -> usize {
+        hash(&self.hasher, key, self.m)
+    }
 
+    /// 查找键所在的位置。
+    fn find(&self, key: &K) -> Option<usize> {
         let mut i = self.hash(key);
-        while let Some(k) = self.keys[i] {
+        while let Some(k) = &self.keys[i] {
             if k == key {
-                self.values[i] = value;
-                return;
+                return Some(i);
             }
             i = (i + 1) % self.m;
         }
+        None
+    }
 
+    /// 在不调整大小的情况下插入表中没有的键。
+    fn insert_new(&mut self, key: K, value: V) {
+        let mut i = self.hash(&key);
+        while self.keys[i].is_some() {
+            i = (i + 1) % self.m;
+        }
         self.keys[i] = Some(key);
-        self.values[i] = value;
-        self.n += 1;
+        self.values[i] = Some(value);
     }
 
-    /// 查找指定的键。
-    pub fn get(&self, key: i32) -> Option<i32> {
-        let mut i = self.hash(key);
-        while let Some(k) = self.keys[i] {
-            if k == key {
-                return Some(self.values[i]);
+    fn resize(&mut self, m: usize) {
+        let keys = std::mem::replace(&mut self.keys, empty_slots(m));
+        let values = std::mem::replace(&mut self.values, empty_slots(m));
+        self.m = m;
+        for (k, v) in keys.into_iter().zip(values) {
+            if let (Some(k), Some(v)) = (k, v) {
+                self.insert_new(k, v);
             }
-            i = (i + 1) % self.m;
         }
-        None
     }
+}
 
-    /// 删除指定的键值对。
-    pub fn delete(&mut self, key: i32) {
-        if !self.contains(key) {
+impl<K, V, S> SymbolTable<K, V> for LinearProbingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// 插入键值对。
+    fn put(&mut self, key: K, value: V) {
+        if let Some(i) = self.find(&key) {
+            self.values[i] = Some(value);
             return;
         }
 
-        let mut i = self.hash(key);
-        while self.keys[i].filter(|k| *k != key).is_some() {
-            i = (i + 1) % self.m;
+        if (self.n + 1) as f64 > self.max_load * self.m as f64 {
+            self.resize(2 * self.m);
         }
+        self.insert_new(key, value);
+        self.n += 1;
+    }
+
+    /// 查找指定的键。
+    fn get(&self, key: &K) -> Option<&V> {
+        self.values[self.find(key)?].as_ref()
+    }
 
+    /// 删除指定的键值对。
+    fn delete(&mut self, key: &K) -> Option<V> {
+        let mut i = self.find(key)?;
         self.keys[i] = None;
-        self.values[i] = 0;
+        let value = self.values[i].take();
+        self.n -= 1;
 
+        // 重新插入同一簇中后面的键，不留下空位标记。
         i = (i + 1) % self.m;
-        while let Some(k) = self.keys[i] {
-            let value = self.values[i];
-
-            self.keys[i] = None;
-            self.values[i] = 0;
-            self.n -= 1;
-            self.put(k, value);
-
+        while let Some(k) = self.keys[i].take() {
+            let v = self.values[i].take().expect("键和值应同时存在");
+            self.insert_new(k, v);
             i = (i + 1) % self.m;
         }
 
-        self.n -= 1;
-        if self.n > 0 && self.n == self.m / 8 {
+        if self.m / 2 >= INIT_CAPACITY && (self.n as f64) < self.min_load * self.m as f64 {
             self.resize(self.m / 2);
         }
+        value
     }
 
-    fn hash(&self, key: i32) -> usize {
-        (key & 0x7FFFFFFF) as usize % self.m
+    fn len(&self) -> usize {
+        self.n
     }
+}
 
-    fn resize(&mut self, m: usize) {
-        let mut t = LinearProbingHashST::with_capacity(m);
-        for i in 0..m {
-            if let Some(k) = self.keys[i] {
-                t.put(k, self.values[i]);
-            }
+impl<K, V, S> Default for LinearProbingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, S> Index<&K> for LinearProbingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    /// 获取键对应的值，键不存在时 panic。
+    fn index(&self, key: &K) -> &V {
+        self.get(key).expect("键不存在")
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for LinearProbingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.put(k, v);
         }
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for LinearProbingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut st = Self::default();
+        st.extend(iter);
+        st
+    }
+}
 
-        *self = t;
+impl<'a, K, V, S> IntoIterator for &'a LinearProbingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
+}
+
+impl<K, V, S> IntoIterator for LinearProbingHashST<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
 
-    fn contains(&self, key: i32) -> bool {
-        let i = self.hash(key);
-        self.keys[i].is_some()
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys
+            .into_iter()
+            .zip(self.values)
+            .filter_map(|(k, v)| Some((k?, v?)))
+            .collect::<Vec<_>>()
+            .into_iter()
     }
+}
 
-    fn with_capacity(m: usize) -> Self {
-        Self {
-            n: 0,
-            m,
-            keys: vec![None; m],
-            values: vec![0; m],
-        }
+/// 键值对的迭代器，由 [`LinearProbingHashST::iter`] 创建。
+pub struct Iter<'a, K, V> {
+    it: Zip<slice::Iter<'a, Option<K>>, slice::Iter<'a, Option<V>>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.it
+            .by_ref()
+            .find_map(|(k, v)| Some((k.as_ref()?, v.as_ref()?)))
     }
 }
 
+/// 创建 m 个空位置。
+fn empty_slots<T>(m: usize) -> Vec<Option<T>> {
+    let mut slots = Vec::with_capacity(m);
+    slots.resize_with(m, || None);
+    slots
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::search::tests::ZeroState;
 
     #[test]
     fn get_test() {
         let t = create();
 
         assert_eq!(3, t.n);
-        assert_eq!(Some(99), t.get(1));
-        assert_eq!(Some(199), t.get(3));
-        assert_eq!(Some(299), t.get(5));
+        assert_eq!(Some(&99), t.get(&1));
+        assert_eq!(Some(&199), t.get(&3));
+        assert_eq!(Some(&299), t.get(&5));
     }
 
     #[test]
     fn delete_test() {
         let mut t = create();
-        t.delete(1);
+        assert_eq!(Some(99), t.delete(&1));
+        assert_eq!(None, t.delete(&1));
 
         assert_eq!(2, t.n);
-        assert_eq!(None, t.get(1));
-        assert_eq!(Some(199), t.get(3));
-        assert_eq!(Some(299), t.get(5));
+        assert_eq!(None, t.get(&1));
+        assert_eq!(Some(&199), t.get(&3));
+        assert_eq!(Some(&299), t.get(&5));
+    }
+
+    #[test]
+    fn string_key_test() {
+        let mut t = LinearProbingHashST::new();
+        for i in 0..100 {
+            t.put(format!("key{i}"), i);
+        }
+        assert_eq!(100, t.n);
+        assert_eq!(Some(&42), t.get(&"key42".to_string()));
+        assert_eq!(None, t.get(&"key100".to_string()));
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut t: LinearProbingHashST<_, _> = (0..20).map(|i| (i, i * i)).collect();
+        t.extend([(3, 0)]);
+        assert_eq!(0, t[&3]);
+        assert_eq!(81, t[&9]);
+
+        let mut pairs: Vec<_> = t.iter().map(|(&k, &v)| (k, v)).collect();
+        pairs.sort();
+        assert_eq!(20, pairs.len());
+        assert_eq!((4, 16), pairs[4]);
+
+        let mut owned: Vec<_> = t.into_iter().collect();
+        owned.sort();
+        assert_eq!(pairs, owned);
+    }
+
+    #[test]
+    fn resize_test() {
+        let mut t = LinearProbingHashST::new();
+        t.set_load_factor(0.2, 0.7);
+        for i in 0..1000 {
+            t.put(i, i);
+            assert!(t.load_factor() <= 0.7);
+        }
+        let m = t.stats().buckets;
+        for i in 0..990 {
+            assert_eq!(Some(i), t.delete(&i));
+            assert!(t.load_factor() <= 0.7);
+        }
+        assert_eq!(10, t.len());
+        assert!(t.stats().buckets < m);
+        assert!((990..1000).all(|i| t.get(&i) == Some(&i)));
+    }
+
+    #[test]
+    fn stats_test() {
+        // 所有键散列到同一位置时，第 i 个插入的键需要比较 i 次。
+        let mut t = LinearProbingHashST::with_hasher(ZeroState);
+        t.extend((0..5).map(|i| (i, i)));
+        let stats = t.stats();
+        assert_eq!(5, stats.len);
+        assert_eq!(16, stats.buckets);
+        assert_eq!(5, stats.max_probe);
+        assert_eq!(3.0, stats.avg_probe);
+
+        // 删除簇中间的键后，其余的键仍可找到。
+        assert_eq!(Some(2), t.delete(&2));
+        assert!([0, 1, 3, 4].iter().all(|i| t.get(i) == Some(i)));
+        assert_eq!(4, t.stats().max_probe);
+    }
+
+    #[test]
+    #[should_panic]
+    fn load_factor_test() {
+        LinearProbingHashST::<i32, i32>::new().set_load_factor(0.3, 0.5);
     }
 
-    fn create() -> LinearProbingHashST {
+    fn create() -> LinearProbingHashST<i32, i32> {
         let mut t = LinearProbingHashST::new();
         t.put(1, 99);
         t.put(3, 199);
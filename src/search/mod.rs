@@ -1,8 +1,357 @@
 //! 查找。
 
-mod sequential_search;
-mod binary_search;
-mod bst;
-mod red_black_bst;
-mod separate_chaining_hash_st;
-mod linear_probing_hash_st;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    hash::{BuildHasher, Hash},
+};
+
+mod arena;
+pub mod binary_search;
+pub mod bst;
+pub mod linear_probing_hash_st;
+pub mod red_black_bst;
+pub mod separate_chaining_hash_st;
+pub mod sequential_search;
+pub mod trie_st;
+pub mod tst;
+
+/// 符号表的统一接口。
+pub trait SymbolTable<K, V> {
+    /// 获取指定键对应的值。
+    fn get(&self, key: &K) -> Option<&V>;
+
+    /// 插入键值对，键已存在时更新其值。
+    fn put(&mut self, key: K, value: V);
+
+    /// 删除指定的键，并返回其值。
+    fn delete(&mut self, key: &K) -> Option<V>;
+
+    /// 是否包含指定的键。
+    fn contains(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 获取键值对的数量。
+    fn len(&self) -> usize;
+
+    /// 是否为空。
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// 有序符号表的统一接口，键按 [`Ord`] 排列。
+pub trait OrderedSymbolTable<K, V>: SymbolTable<K, V>
+where
+    K: Ord,
+{
+    /// 获取最小的键。
+    fn min(&self) -> Option<&K>;
+
+    /// 获取最大的键。
+    fn max(&self) -> Option<&K>;
+
+    /// 获取小于等于 key 的最大键。
+    fn floor(&self, key: &K) -> Option<&K>;
+
+    /// 获取大于等于 key 的最小键。
+    fn ceiling(&self, key: &K) -> Option<&K>;
+
+    /// 获取小于 key 的键的数量。
+    fn rank(&self, key: &K) -> usize;
+
+    /// 获取排名为 k 的键。
+    fn select(&self, k: usize) -> Option<&K>;
+
+    /// 获取 `[lo, hi]` 之间的所有键，按顺序排列。
+    fn range_keys(&self, lo: &K, hi: &K) -> Vec<&K>;
+
+    /// 获取 `[lo, hi]` 之间的键的数量。
+    fn range_count(&self, lo: &K, hi: &K) -> usize {
+        if lo > hi {
+            0
+        } else {
+            self.rank(hi) - self.rank(lo) + usize::from(self.contains(hi))
+        }
+    }
+
+    /// 获取所有的键，按顺序排列。
+    fn keys(&self) -> Vec<&K> {
+        match (self.min(), self.max()) {
+            (Some(lo), Some(hi)) => self.range_keys(lo, hi),
+            _ => vec![],
+        }
+    }
+}
+
+/// 查找树不满足的性质。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvariantError {
+    Order,           // 键不满足对称顺序。
+    Size,            // 节点记录的子树大小不正确。
+    RightLeaningRed, // 存在右倾的红链接。
+    ConsecutiveRed,  // 存在连续的两条红链接。
+    BlackBalance,    // 根到各个空链接的路径上黑链接的数量不同。
+    RankSelect,      // rank 与 select 不一致。
+}
+
+impl Display for InvariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            InvariantError::Order => "键不满足对称顺序",
+            InvariantError::Size => "子树大小不正确",
+            InvariantError::RightLeaningRed => "存在右倾的红链接",
+            InvariantError::ConsecutiveRed => "存在连续的两条红链接",
+            InvariantError::BlackBalance => "黑链接不平衡",
+            InvariantError::RankSelect => "rank 与 select 不一致",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Error for InvariantError {}
+
+/// 检查 rank 与 select 是否互逆。
+fn check_rank_select<K, V, S>(st: &S) -> Result<(), InvariantError>
+where
+    K: Ord,
+    S: OrderedSymbolTable<K, V>,
+{
+    for i in 0..st.len() {
+        match st.select(i) {
+            Some(key) if st.rank(key) == i => {}
+            _ => return Err(InvariantError::RankSelect),
+        }
+    }
+    if st.select(st.len()).is_some() {
+        return Err(InvariantError::RankSelect);
+    }
+    Ok(())
+}
+
+/// 散列表的统计信息，用于调整装填因子和散列函数。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HashStats {
+    pub len: usize,       // 键值对的数量。
+    pub buckets: usize,   // 散列表的大小。
+    pub load_factor: f64, // 装填因子，即 len / buckets。
+    pub avg_probe: f64,   // 查找命中时平均比较的键数。
+    pub max_probe: usize, // 查找命中时最多比较的键数。
+}
+
+impl HashStats {
+    /// 由每个键查找命中时比较的次数计算统计信息。
+    fn new(buckets: usize, probes: impl Iterator<Item = usize>) -> Self {
+        let (mut len, mut total, mut max_probe) = (0, 0, 0);
+        for p in probes {
+            len += 1;
+            total += p;
+            max_probe = max_probe.max(p);
+        }
+        Self {
+            len,
+            buckets,
+            load_factor: len as f64 / buckets as f64,
+            avg_probe: if len == 0 {
+                0.0
+            } else {
+                total as f64 / len as f64
+            },
+            max_probe,
+        }
+    }
+}
+
+/// 检查装填因子的范围：缩小一半后不能立即超过上限。
+fn check_load_factor(min: f64, max: f64) {
+    assert!(
+        min >= 0.0 && min * 2.0 < max,
+        "装填因子的范围不合法：[{min}, {max}]"
+    );
+}
+
+/// 计算键在大小为 m 的散列表中的位置。
+fn hash<K, S>(hasher: &S, key: &K, m: usize) -> usize
+where
+    K: Hash + ?Sized,
+    S: BuildHasher,
+{
+    (hasher.hash_one(key) % m as u64) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use binary_search::BinarySearchST;
+    use bst::BST;
+    use linear_probing_hash_st::LinearProbingHashST;
+    use red_black_bst::RedBlackBST;
+    use separate_chaining_hash_st::SeparateChainingHashST;
+    use sequential_search::SequentialSearchST;
+    use std::hash::Hasher;
+    use trie_st::TrieST;
+    use tst::TST;
+
+    /// 把所有键都散列到 0，用于测试冲突。
+    #[derive(Default)]
+    pub(super) struct ZeroState;
+
+    pub(super) struct ZeroHasher;
+
+    impl Hasher for ZeroHasher {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, _: &[u8]) {}
+    }
+
+    impl BuildHasher for ZeroState {
+        type Hasher = ZeroHasher;
+
+        fn build_hasher(&self) -> ZeroHasher {
+            ZeroHasher
+        }
+    }
+
+    const KEYS: [&str; 13] = [
+        "s", "e", "a", "r", "c", "h", "e", "x", "a", "m", "p", "l", "e",
+    ];
+
+    fn create<S>() -> S
+    where
+        S: SymbolTable<String, usize> + Default,
+    {
+        let mut st = S::default();
+        for (i, k) in KEYS.into_iter().enumerate() {
+            st.put(k.to_string(), i);
+        }
+        st
+    }
+
+    fn check<S>()
+    where
+        S: SymbolTable<String, usize> + Default,
+    {
+        let mut st = create::<S>();
+        assert_eq!(10, st.len());
+        assert_eq!(Some(&12), st.get(&"e".to_string()));
+        assert_eq!(Some(&8), st.get(&"a".to_string()));
+        assert!(st.contains(&"x".to_string()));
+        assert!(!st.contains(&"z".to_string()));
+
+        assert_eq!(Some(12), st.delete(&"e".to_string()));
+        assert_eq!(None, st.delete(&"e".to_string()));
+        assert_eq!(9, st.len());
+        for k in ["s", "a", "r", "c", "h", "x", "m", "p", "l"] {
+            assert_eq!(
+                Some(k.to_string()),
+                st.delete(&k.to_string()).map(|i| KEYS[i].to_string())
+            );
+        }
+        assert!(st.is_empty());
+    }
+
+    fn check_ordered<S>()
+    where
+        S: OrderedSymbolTable<String, usize> + Default,
+    {
+        let st = create::<S>();
+        let k = |s: &str| s.to_string();
+        assert_eq!(Some(&k("a")), st.min());
+        assert_eq!(Some(&k("x")), st.max());
+        assert_eq!(Some(&k("h")), st.floor(&k("i")));
+        assert_eq!(Some(&k("h")), st.floor(&k("h")));
+        assert_eq!(None, st.floor(&k("0")));
+        assert_eq!(Some(&k("l")), st.ceiling(&k("i")));
+        assert_eq!(None, st.ceiling(&k("y")));
+        assert_eq!(3, st.rank(&k("h")));
+        assert_eq!(3, st.rank(&k("g")));
+        assert_eq!(10, st.rank(&k("y")));
+        assert_eq!(Some(&k("h")), st.select(3));
+        assert_eq!(None, st.select(10));
+
+        let keys: Vec<&str> = st
+            .range_keys(&k("f"), &k("p"))
+            .into_iter()
+            .map(|s| s.as_str())
+            .collect();
+        assert_eq!(["h", "l", "m", "p"], &keys[..]);
+        assert_eq!(4, st.range_count(&k("f"), &k("p")));
+        assert_eq!(0, st.range_count(&k("p"), &k("f")));
+        assert_eq!(10, st.keys().len());
+        assert!(S::default().keys().is_empty());
+    }
+
+    #[test]
+    fn symbol_table_test() {
+        check::<SequentialSearchST<String, usize>>();
+        check::<BinarySearchST<String, usize>>();
+        check::<BST<String, usize>>();
+        check::<RedBlackBST<String, usize>>();
+        check::<SeparateChainingHashST<String, usize>>();
+        check::<LinearProbingHashST<String, usize>>();
+        check::<TrieST<usize>>();
+        check::<TST<usize>>();
+    }
+
+    #[test]
+    fn ordered_symbol_table_test() {
+        check_ordered::<BinarySearchST<String, usize>>();
+        check_ordered::<BST<String, usize>>();
+        check_ordered::<RedBlackBST<String, usize>>();
+    }
+
+    /// 线性同余伪随机数，保证测试可以重现。
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self, n: u64) -> u64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (self.0 >> 33) % n
+        }
+    }
+
+    /// 随机插入和删除，每次修改后都检查树的性质，并与 BTreeMap 的结果比较。
+    fn fuzz<S>(check: fn(&S) -> Result<(), InvariantError>, delete_min: fn(&mut S))
+    where
+        S: OrderedSymbolTable<u64, u64> + Default,
+    {
+        use std::collections::BTreeMap;
+
+        for seed in 0..4 {
+            let mut rng = Lcg(seed);
+            let mut st = S::default();
+            let mut model = BTreeMap::new();
+            for step in 0..2000 {
+                let key = rng.next(128);
+                match rng.next(10) {
+                    0..=5 => {
+                        st.put(key, step);
+                        model.insert(key, step);
+                    }
+                    6..=8 => assert_eq!(model.remove(&key), st.delete(&key)),
+                    _ => {
+                        delete_min(&mut st);
+                        model.pop_first();
+                    }
+                }
+                if let Err(e) = check(&st) {
+                    panic!("种子 {seed} 第 {step} 步后：{e}");
+                }
+                assert_eq!(model.len(), st.len());
+            }
+            assert_eq!(model.keys().collect::<Vec<_>>(), st.keys());
+        }
+    }
+
+    #[test]
+    fn invariant_test() {
+        fuzz::<BST<u64, u64>>(BST::check, BST::delete_min);
+        fuzz::<RedBlackBST<u64, u64>>(RedBlackBST::check, RedBlackBST::delete_min);
+    }
+}
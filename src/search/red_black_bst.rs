@@ -1,462 +1,777 @@
 //! 红黑树符号表。
 
 use std::{
-    collections::VecDeque,
+    cmp::Ordering,
     fmt::{Debug, Display},
-    marker::PhantomData,
-    ptr::NonNull,
+    mem,
+    ops::{Bound, Index, RangeBounds},
 };
 
-type Link<K, V> = Option<NonNull<Node<K, V>>>;
+use super::{arena::Arena, check_rank_select, InvariantError, OrderedSymbolTable, SymbolTable};
+
+/// 子节点在节点池中的位置。
+type Link = Option<usize>;
 
 pub struct RedBlackBST<K, V> {
-    root: Link<K, V>,
-    marker: PhantomData<Box<Node<K, V>>>,
+    nodes: Arena<Node<K, V>>, // 保存树的所有节点。
+    root: Link,
 }
 
 impl<K, V> RedBlackBST<K, V>
 where
-    K: Copy + PartialOrd,
-    V: Clone,
+    K: Ord,
 {
     /// 创建一个空的红黑树。
     pub fn new() -> Self {
         Self {
+            nodes: Arena::new(),
             root: None,
-            marker: PhantomData,
         }
     }
 
-    /// 插入键值对。
-    pub fn put(&mut self, key: K, value: V) {
-        self.root = put_with(self.root, key, value);
-        set_color(self.root, Color::Black);
-    }
-
     /// 删除最小节点。
     pub fn delete_min(&mut self) {
-        self.set_parent_red_if_child_black();
-        self.root = del_min_node(self.root);
-        set_color(self.root, Color::Black);
+        if let Some(h) = self.root {
+            self.set_root_red_if_children_black(h);
+            self.root = self.del_min_node(h, &mut None);
+            self.set_color(self.root, Color::Black);
+        }
     }
 
     /// 删除最大键。
     pub fn delete_max(&mut self) {
-        self.set_parent_red_if_child_black();
-        self.root = del_max_node(self.root);
-        set_color(self.root, Color::Black);
+        if let Some(h) = self.root {
+            self.set_root_red_if_children_black(h);
+            self.root = self.del_max_node(h);
+            self.set_color(self.root, Color::Black);
+        }
     }
 
-    /// 删除指定节点。
-    pub fn delete(&mut self, key: K) {
-        if !is_red(left_of(self.root)) && !is_red(right_of(self.root)) {
-            set_color(self.root, Color::Red);
+    fn set_root_red_if_children_black(&mut self, h: usize) {
+        let n = &self.nodes[h];
+        if !self.is_red(n.left) && !self.is_red(n.right) {
+            self.nodes[h].color = Color::Red;
         }
-        self.root = del_node(self.root, key);
-        set_color(self.root, Color::Black);
     }
 
-    /// 获取最小键。
-    pub fn min(&self) -> Link<K, V> {
-        min_node(self.root)
+    /// 获取范围内的键值对，按键的顺序排列，可从两端迭代。
+    ///
+    /// 范围的起点大于终点时不返回任何元素。
+    pub fn range<R>(&self, range: R) -> Range<'_, K, V>
+    where
+        R: RangeBounds<K>,
+    {
+        let start = range.start_bound();
+        let end = range.end_bound();
+
+        // 满足终点条件的键数，减去不满足起点条件的键数。
+        let below_end = match end {
+            Bound::Included(k) => self.rank(k) + usize::from(self.contains(k)),
+            Bound::Excluded(k) => self.rank(k),
+            Bound::Unbounded => self.len(),
+        };
+        let below_start = match start {
+            Bound::Included(k) => self.rank(k),
+            Bound::Excluded(k) => self.rank(k) + usize::from(self.contains(k)),
+            Bound::Unbounded => 0,
+        };
+
+        let mut r = Range {
+            nodes: &self.nodes,
+            front: vec![],
+            back: vec![],
+            remaining: below_end.saturating_sub(below_start),
+        };
+
+        // 沿查找路径记录起点之后、终点之前的节点。
+        let mut x = self.node_ref(self.root);
+        while let Some(n) = x {
+            let after_start = match start {
+                Bound::Included(k) => n.key >= *k,
+                Bound::Excluded(k) => n.key > *k,
+                Bound::Unbounded => true,
+            };
+            if after_start {
+                r.front.push(n);
+                x = self.node_ref(n.left);
+            } else {
+                x = self.node_ref(n.right);
+            }
+        }
+        let mut x = self.node_ref(self.root);
+        while let Some(n) = x {
+            let before_end = match end {
+                Bound::Included(k) => n.key <= *k,
+                Bound::Excluded(k) => n.key < *k,
+                Bound::Unbounded => true,
+            };
+            if before_end {
+                r.back.push(n);
+                x = self.node_ref(n.right);
+            } else {
+                x = self.node_ref(n.left);
+            }
+        }
+        r
     }
 
-    fn set_parent_red_if_child_black(&mut self) {
-        let n = self.root;
-        if !is_red(left_of(n)) && !is_red(right_of(n)) {
-            set_color(n, Color::Black);
+    /// 检查红黑树的性质：对称顺序、子树大小、没有右倾或连续的红链接、
+    /// 完美黑色平衡以及 rank 与 select 的一致性。
+    pub fn check(&self) -> Result<(), InvariantError> {
+        if !self.is_ordered(self.root, None, None) {
+            return Err(InvariantError::Order);
+        }
+        if !self.is_size_consistent(self.root) {
+            return Err(InvariantError::Size);
+        }
+        self.check_23(self.root, true)?;
+
+        // 以最左侧路径上的黑链接数为准。
+        let mut black = 0;
+        let mut x = self.node_ref(self.root);
+        while let Some(n) = x {
+            if n.color == Color::Black {
+                black += 1;
+            }
+            x = self.node_ref(n.left);
+        }
+        if !self.is_balanced(self.root, black) {
+            return Err(InvariantError::BlackBalance);
         }
+        check_rank_select(self)
     }
 
-    /// 获取红黑树的迭代器。
-    pub fn iter<'a>(&self) -> Iter<'a, K, V> {
-        Iter::new(self.root)
+    /// 是否满足 [`RedBlackBST::check`] 检查的所有性质。
+    pub fn is_valid(&self) -> bool {
+        self.check().is_ok()
     }
-}
 
-impl<K, V> Display for RedBlackBST<K, V>
-where
-    K: Debug,
-    V: Debug,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        print_tree(self.root, f)
+    /// 获取所有键值对的迭代器，按键的顺序排列，可从两端迭代。
+    pub fn iter(&self) -> Range<'_, K, V> {
+        self.range(..)
     }
-}
-
-fn print_tree<K, V>(root: Link<K, V>, f: &mut std::fmt::Formatter) -> std::fmt::Result
-where
-    K: Debug,
-    V: Debug,
-{
-    root.map_or(Ok(()), |n| {
-        print_tree(left(n), f)?;
-        let h = unsafe { n.as_ref() };
-        write!(f, "\n({:?}, {:?}, {:?})", &h.key, &h.value, &h.color)?;
-        print_tree(right(n), f)
-    })
-}
 
-#[derive(Debug)]
-pub struct Node<K, V> {
-    key: K,
-    value: V,
-    left: Link<K, V>,
-    right: Link<K, V>,
-    len: usize,
-    color: Color,
-}
-
-impl<K, V> Node<K, V> {
-    fn new(key: K, value: V, len: usize, color: Color) -> Self {
-        Self {
-            key,
-            value,
-            left: None,
-            right: None,
-            len,
-            color,
+    /// 查询指定的键。
+    fn get_from_node(&self, h: Link, key: &K) -> Link {
+        let n = self.node_ref(h)?;
+        match key.cmp(&n.key) {
+            Ordering::Equal => h,
+            Ordering::Less => self.get_from_node(n.left, key),
+            Ordering::Greater => self.get_from_node(n.right, key),
         }
     }
 
-    fn update_size(&mut self) {
-        self.len = 1 + size_of(self.left) + size_of(self.right);
+    fn floor_node(&self, h: Link, key: &K) -> Link {
+        let n = self.node_ref(h)?;
+        match key.cmp(&n.key) {
+            Ordering::Equal => h,
+            Ordering::Less => self.floor_node(n.left, key),
+            Ordering::Greater => self.floor_node(n.right, key).or(h),
+        }
     }
-}
-
-/// 迭代器。
-pub struct Iter<'a, K, V> {
-    root: Link<K, V>,
-    list: VecDeque<&'a Node<K, V>>,
-    marker: PhantomData<&'a Node<K, V>>,
-}
 
-impl<'a, K, V> Iter<'a, K, V> {
-    pub fn new(root: Link<K, V>) -> Self {
-        let list = VecDeque::new();
-        Self {
-            root,
-            list,
-            marker: PhantomData,
+    fn ceiling_node(&self, h: Link, key: &K) -> Link {
+        let n = self.node_ref(h)?;
+        match key.cmp(&n.key) {
+            Ordering::Equal => h,
+            Ordering::Greater => self.ceiling_node(n.right, key),
+            Ordering::Less => self.ceiling_node(n.left, key).or(h),
         }
     }
-}
-
-impl<'a, K, V> Iterator for Iter<'a, K, V> {
-    type Item = &'a Node<K, V>;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        while let Some(n) = self.root {
-            let n = unsafe { n.as_ref() };
-            self.list.push_front(n);
-            self.root = n.left;
+    fn collect_range<'a>(&'a self, h: Link, q: &mut Vec<&'a K>, lo: &K, hi: &K) {
+        if let Some(n) = self.node_ref(h) {
+            if n.key > *lo {
+                self.collect_range(n.left, q, lo, hi);
+            }
+            if n.key >= *lo && n.key <= *hi {
+                q.push(&n.key);
+            }
+            if n.key < *hi {
+                self.collect_range(n.right, q, lo, hi);
+            }
         }
-        let node = self.list.pop_front();
-        self.root = node.map_or(None, |n| n.right);
-        node
     }
-}
-
-fn left<K, V>(h: NonNull<Node<K, V>>) -> Link<K, V> {
-    unsafe { h.as_ref().left }
-}
 
-fn right<K, V>(h: NonNull<Node<K, V>>) -> Link<K, V> {
-    unsafe { h.as_ref().right }
-}
-
-fn left_of<K, V>(h: Link<K, V>) -> Link<K, V> {
-    h.map_or(None, |h| left(h))
-}
+    /// 所有键是否都在 min 和 max 之间，且满足对称顺序。
+    fn is_ordered(&self, h: Link, min: Option<&K>, max: Option<&K>) -> bool {
+        let Some(n) = self.node_ref(h) else {
+            return true;
+        };
+        if min.is_some_and(|k| n.key <= *k) || max.is_some_and(|k| n.key >= *k) {
+            return false;
+        }
+        self.is_ordered(n.left, min, Some(&n.key)) && self.is_ordered(n.right, Some(&n.key), max)
+    }
 
-fn right_of<K, V>(h: Link<K, V>) -> Link<K, V> {
-    h.map_or(None, |h| right(h))
-}
+    /// 插入新节点。
+    fn put_with(&mut self, h: Link, key: K, value: V) -> usize {
+        let Some(mut h) = h else {
+            return self.nodes.insert(Node::new(key, value, 1, Color::Red));
+        };
 
-/// 查询指定的键。
-fn get_from_node<K, V>(root: Link<K, V>, key: K) -> Link<K, V>
-where
-    K: PartialOrd,
-{
-    root.map_or(None, |n| {
-        let n = unsafe { n.as_ref() };
-        if key == n.key {
-            root
-        } else if key < n.key {
-            get_from_node(n.left, key)
-        } else {
-            get_from_node(n.right, key)
+        match key.cmp(&self.nodes[h].key) {
+            Ordering::Equal => self.nodes[h].value = value,
+            Ordering::Greater => {
+                let r = self.put_with(self.nodes[h].right, key, value);
+                self.nodes[h].right = Some(r);
+            }
+            Ordering::Less => {
+                let l = self.put_with(self.nodes[h].left, key, value);
+                self.nodes[h].left = Some(l);
+            }
         }
-    })
-}
-
-fn get_from<'a, K, V>(root: Link<K, V>, key: K) -> Option<&'a Node<K, V>>
-where
-    K: PartialOrd,
-{
-    get_from_node(root, key).map(|n| unsafe { n.as_ref() })
-}
 
-fn min_node<K, V>(root: Link<K, V>) -> Link<K, V> {
-    root.map_or(None, |n| unsafe {
-        let n = n.as_ref();
-        if n.left.is_none() {
-            root
-        } else {
-            min_node(n.left)
+        if self.is_red(self.nodes[h].right) && !self.is_red(self.nodes[h].left) {
+            h = self.rotate_left(h);
         }
-    })
-}
+        let left = self.nodes[h].left;
+        if self.is_red(left) && self.is_red(self.left_of(left)) {
+            h = self.rotate_right(h);
+        }
+        if self.is_red(self.nodes[h].left) && self.is_red(self.nodes[h].right) {
+            self.flip_colors(h);
+        }
+        self.update_size(h);
+        h
+    }
 
-fn del_node<K, V>(mut h: Link<K, V>, key: K) -> Link<K, V>
-where
-    K: Copy + PartialOrd,
-    V: Clone,
-{
-    if let Some(n) = h {
-        let n = unsafe { n.as_ref() };
-        if key < n.key {
-            if !is_red(n.left) && !is_red(left_of(n.left)) {
-                h = move_red_left(h);
+    /// 删除键，被删除的值保存在 removed 中。
+    fn del_node(&mut self, mut h: usize, key: &K, removed: &mut Option<V>) -> Link {
+        if *key < self.nodes[h].key {
+            let left = self.nodes[h].left;
+            if !self.is_red(left) && !self.is_red(self.left_of(left)) {
+                h = self.move_red_left(h);
+            }
+            if let Some(l) = self.nodes[h].left {
+                self.nodes[h].left = self.del_node(l, key, removed);
             }
-
-            h.map(|mut n| unsafe {
-                n.as_mut().left = del_node(left(n), key);
-            });
         } else {
-            if is_red(left_of(h)) {
-                h = rotate_right(h);
+            if self.is_red(self.nodes[h].left) {
+                h = self.rotate_right(h);
             }
 
-            if let Some(n) = h {
-                let n = unsafe { n.as_ref() };
-                if key == n.key && n.right.is_none() {
-                    return None;
-                }
+            if *key == self.nodes[h].key && self.nodes[h].right.is_none() {
+                // 回收节点。
+                *removed = Some(self.nodes.remove(h).value);
+                return None;
             }
 
-            if !is_red(right_of(h)) && !is_red(left_of(right_of(h))) {
-                h = move_red_right(h);
+            let right = self.nodes[h].right;
+            if !self.is_red(right) && !self.is_red(self.left_of(right)) {
+                h = self.move_red_right(h);
             }
 
-            if let Some(mut n) = h {
-                let n = unsafe { n.as_mut() };
-                if n.key != key {
-                    n.right = del_node(n.right, key);
+            if let Some(r) = self.nodes[h].right {
+                if self.nodes[h].key != *key {
+                    self.nodes[h].right = self.del_node(r, key, removed);
                 } else {
-                    min_node(n.right).map(|m| {
-                        let m = unsafe { m.as_ref() };
-                        get_from(n.right, m.key).map(|k| n.value = k.value.clone());
-                        n.key = m.key;
-                        n.right = del_min_node(n.right);
-                    });
+                    // 用后继节点的键值代替被删除的键值，再删除后继节点。
+                    let mut min = None;
+                    self.nodes[h].right = self.del_min_node(r, &mut min);
+                    if let Some((k, v)) = min {
+                        let n = &mut self.nodes[h];
+                        n.key = k;
+                        *removed = Some(mem::replace(&mut n.value, v));
+                    }
                 }
             }
         }
-    }
 
-    balance(h)
+        Some(self.balance(h))
+    }
 }
 
-/// 删除最大节点。
-fn del_max_node<K, V>(mut h: Link<K, V>) -> Link<K, V> {
-    if is_red(left_of(h)) {
-        h = rotate_right(h);
+impl<K, V> RedBlackBST<K, V> {
+    fn node_ref(&self, h: Link) -> Option<&Node<K, V>> {
+        h.map(|i| &self.nodes[i])
     }
 
-    if let Some(n) = h {
-        if right(n).is_none() {
-            // 释放节点内存。
-            unsafe { Box::from_raw(n.as_ptr()) };
-            return None;
-        }
+    fn left_of(&self, h: Link) -> Link {
+        self.node_ref(h).and_then(|n| n.left)
+    }
 
-        if !is_red(right(n)) && !is_red(left_of(right(n))) {
-            h = move_red_right(h);
+    /// 是否为红连接。
+    fn is_red(&self, h: Link) -> bool {
+        self.node_ref(h).is_some_and(|n| n.color == Color::Red)
+    }
+
+    /// 设置连接颜色。
+    fn set_color(&mut self, h: Link, color: Color) {
+        if let Some(i) = h {
+            self.nodes[i].color = color;
         }
     }
 
-    // 节点 h 可能已经改变。
-    h.map(|mut n| unsafe {
-        n.as_mut().right = del_max_node(right(n));
-    });
+    /// 获取节点长度。
+    fn size_of(&self, h: Link) -> usize {
+        self.node_ref(h).map_or(0, |n| n.len)
+    }
 
-    balance(h)
-}
+    fn update_size(&mut self, h: usize) {
+        let n = &self.nodes[h];
+        self.nodes[h].len = 1 + self.size_of(n.left) + self.size_of(n.right);
+    }
 
-/// 假定 node 为红，其右子、右左子皆黑，将右子、右左子之一变红。
-fn move_red_right<K, V>(mut h: Link<K, V>) -> Link<K, V> {
-    flip_colors_for_del(h);
+    fn min_node(&self, mut h: usize) -> usize {
+        while let Some(l) = self.nodes[h].left {
+            h = l;
+        }
+        h
+    }
 
-    if !is_red(left_of(left_of(h))) {
-        h = rotate_right(h);
+    /// 删除最小节点，被删除的键值保存在 removed 中。
+    fn del_min_node(&mut self, mut h: usize, removed: &mut Option<(K, V)>) -> Link {
+        let Some(left) = self.nodes[h].left else {
+            // 回收节点。
+            let n = self.nodes.remove(h);
+            *removed = Some((n.key, n.value));
+            return None;
+        };
+
+        if !self.is_red(Some(left)) && !self.is_red(self.nodes[left].left) {
+            h = self.move_red_left(h);
+        }
+        if let Some(l) = self.nodes[h].left {
+            self.nodes[h].left = self.del_min_node(l, removed);
+        }
+
+        Some(self.balance(h))
     }
 
-    h
-}
+    /// 删除最大节点。
+    fn del_max_node(&mut self, mut h: usize) -> Link {
+        if self.is_red(self.nodes[h].left) {
+            h = self.rotate_right(h);
+        }
 
-/// 删除最小节点。
-fn del_min_node<K, V>(mut h: Link<K, V>) -> Link<K, V> {
-    if let Some(n) = h {
-        if left(n).is_none() {
-            // 释放节点内存。
-            unsafe { Box::from_raw(n.as_ptr()) };
+        let Some(right) = self.nodes[h].right else {
+            // 回收节点。
+            self.nodes.remove(h);
             return None;
+        };
+
+        if !self.is_red(Some(right)) && !self.is_red(self.nodes[right].left) {
+            h = self.move_red_right(h);
         }
 
-        if !is_red(left(n)) && !is_red(left_of(left(n))) {
-            h = move_red_left(h);
+        // 节点 h 可能已经改变。
+        if let Some(r) = self.nodes[h].right {
+            self.nodes[h].right = self.del_max_node(r);
         }
+
+        Some(self.balance(h))
     }
 
-    h.map(|mut n| unsafe {
-        n.as_mut().left = del_min_node(left(n));
-    });
+    /// 假定 node 为红，其右子、右左子皆黑，将右子、右左子之一变红。
+    fn move_red_right(&mut self, mut h: usize) -> usize {
+        self.flip_colors_for_del(h);
 
-    balance(h)
-}
+        let left = self.nodes[h].left;
+        if self.is_red(self.left_of(left)) {
+            h = self.rotate_right(h);
+            self.flip_colors(h);
+        }
 
-fn balance<K, V>(mut h: Link<K, V>) -> Link<K, V> {
-    if is_red(right_of(h)) {
-        h = rotate_left(h);
+        h
     }
 
-    if is_red(right_of(h)) && !is_red(left_of(h)) {
-        h = rotate_left(h);
-    }
+    /// 假定 node 为红，其左子、左左子皆黑，将左子、左左子之一变红。
+    fn move_red_left(&mut self, mut h: usize) -> usize {
+        self.flip_colors_for_del(h);
 
-    if is_red(left_of(h)) && is_red(left_of(left_of(h))) {
-        h = rotate_right(h);
-    }
+        if let Some(r) = self.nodes[h].right {
+            if self.is_red(self.nodes[r].left) {
+                self.nodes[h].right = Some(self.rotate_right(r));
+                h = self.rotate_left(h);
+                self.flip_colors(h);
+            }
+        }
 
-    if is_red(left_of(h)) && is_red(right_of(h)) {
-        flip_colors(h);
+        h
     }
 
-    h.map(|mut n| unsafe {
-        n.as_mut().update_size();
-    });
+    fn balance(&mut self, mut h: usize) -> usize {
+        if self.is_red(self.nodes[h].right) {
+            h = self.rotate_left(h);
+        }
 
-    h
-}
+        let left = self.nodes[h].left;
+        if self.is_red(left) && self.is_red(self.left_of(left)) {
+            h = self.rotate_right(h);
+        }
 
-/// 假定 node 为红，其左子、左左子皆黑，将左子、左左子之一变红。
-fn move_red_left<K, V>(mut h: Link<K, V>) -> Link<K, V> {
-    flip_colors_for_del(h);
+        if self.is_red(self.nodes[h].left) && self.is_red(self.nodes[h].right) {
+            self.flip_colors(h);
+        }
 
-    if is_red(left_of(right_of(h))) {
-        h.map(|mut n| unsafe {
-            n.as_mut().right = rotate_right(right(n));
-        });
-        h = rotate_left(h);
+        self.update_size(h);
+        h
+    }
+
+    /// 左旋，将 h 变为左子树。
+    fn rotate_left(&mut self, h: usize) -> usize {
+        // 右倾的红链接，右子节点一定存在。
+        let x = self.nodes[h].right.expect("左旋时右子节点为空");
+        self.nodes[h].right = self.nodes[x].left;
+        self.nodes[x].left = Some(h);
+        self.nodes[x].color = self.nodes[h].color;
+        self.nodes[h].color = Color::Red;
+        self.nodes[x].len = self.nodes[h].len;
+        self.update_size(h);
+        x
+    }
+
+    /// 右旋，将 h 变为右子树。
+    fn rotate_right(&mut self, h: usize) -> usize {
+        // 左倾的红链接，左子节点一定存在。
+        let x = self.nodes[h].left.expect("右旋时左子节点为空");
+        self.nodes[h].left = self.nodes[x].right;
+        self.nodes[x].right = Some(h);
+        self.nodes[x].color = self.nodes[h].color;
+        self.nodes[h].color = Color::Red;
+        self.nodes[x].len = self.nodes[h].len;
+        self.update_size(h);
+        x
+    }
+
+    /// 反转 h 连接的颜色。
+    fn flip_colors(&mut self, h: usize) {
+        self.set_children_color(h, Color::Red, Color::Black);
+    }
+
+    /// 设置为父黑子红。
+    fn flip_colors_for_del(&mut self, h: usize) {
+        self.set_children_color(h, Color::Black, Color::Red);
+    }
+
+    fn set_children_color(&mut self, h: usize, parent: Color, children: Color) {
+        let n = &mut self.nodes[h];
+        n.color = parent;
+        let (left, right) = (n.left, n.right);
+        self.set_color(left, children);
+        self.set_color(right, children);
+    }
+
+    /// 每个节点记录的子树大小是否正确。
+    fn is_size_consistent(&self, h: Link) -> bool {
+        let Some(n) = self.node_ref(h) else {
+            return true;
+        };
+        n.len == self.size_of(n.left) + self.size_of(n.right) + 1
+            && self.is_size_consistent(n.left)
+            && self.is_size_consistent(n.right)
+    }
+
+    /// 检查是否对应一棵 2-3 树：红链接都左倾，且没有连续的两条红链接。
+    fn check_23(&self, h: Link, is_root: bool) -> Result<(), InvariantError> {
+        let Some(n) = self.node_ref(h) else {
+            return Ok(());
+        };
+        if self.is_red(n.right) {
+            return Err(InvariantError::RightLeaningRed);
+        }
+        if (is_root && n.color == Color::Red) || (self.is_red(h) && self.is_red(n.left)) {
+            return Err(InvariantError::ConsecutiveRed);
+        }
+        self.check_23(n.left, false)?;
+        self.check_23(n.right, false)
     }
 
-    h
+    /// 从 h 到每个空链接的路径上是否都恰好有 black 条黑链接。
+    fn is_balanced(&self, h: Link, black: usize) -> bool {
+        let Some(n) = self.node_ref(h) else {
+            return black == 0;
+        };
+        let black = match n.color {
+            Color::Black if black == 0 => return false,
+            Color::Black => black - 1,
+            Color::Red => black,
+        };
+        self.is_balanced(n.left, black) && self.is_balanced(n.right, black)
+    }
 }
 
-/// 设置为父黑子红。
-fn flip_colors_for_del<K, V>(h: Link<K, V>) {
-    set_color(h, Color::Black);
-    set_color(left_of(h), Color::Red);
-    set_color(right_of(h), Color::Red);
+impl<K, V> SymbolTable<K, V> for RedBlackBST<K, V>
+where
+    K: Ord,
+{
+    fn get(&self, key: &K) -> Option<&V> {
+        self.node_ref(self.get_from_node(self.root, key))
+            .map(|n| &n.value)
+    }
+
+    /// 插入键值对。
+    fn put(&mut self, key: K, value: V) {
+        self.root = Some(self.put_with(self.root, key, value));
+        self.set_color(self.root, Color::Black);
+    }
+
+    /// 删除指定节点。
+    fn delete(&mut self, key: &K) -> Option<V> {
+        if !self.contains(key) {
+            return None;
+        }
+        let h = self.root?;
+        self.set_root_red_if_children_black(h);
+        let mut removed = None;
+        self.root = self.del_node(h, key, &mut removed);
+        self.set_color(self.root, Color::Black);
+        removed
+    }
+
+    fn len(&self) -> usize {
+        self.size_of(self.root)
+    }
 }
 
-/// 插入新节点。
-fn put_with<K, V>(mut h: Link<K, V>, key: K, value: V) -> Link<K, V>
+impl<K, V> OrderedSymbolTable<K, V> for RedBlackBST<K, V>
 where
-    K: PartialOrd,
+    K: Ord,
 {
-    if h.is_none() {
-        let n = Node::new(key, value, 1, Color::Red);
-        return Some(Box::leak(Box::new(n)).into());
+    fn min(&self) -> Option<&K> {
+        self.root.map(|h| &self.nodes[self.min_node(h)].key)
     }
 
-    if let Some(mut n) = h {
-        let n = unsafe { n.as_mut() };
-        if key == n.key {
-            n.value = value;
-        } else if key > n.key {
-            n.right = put_with(n.right, key, value);
-        } else {
-            n.left = put_with(n.left, key, value);
+    fn max(&self) -> Option<&K> {
+        let mut x = self.node_ref(self.root)?;
+        while let Some(r) = self.node_ref(x.right) {
+            x = r;
         }
+        Some(&x.key)
+    }
+
+    fn floor(&self, key: &K) -> Option<&K> {
+        self.node_ref(self.floor_node(self.root, key))
+            .map(|n| &n.key)
+    }
+
+    fn ceiling(&self, key: &K) -> Option<&K> {
+        self.node_ref(self.ceiling_node(self.root, key))
+            .map(|n| &n.key)
+    }
 
-        if is_red(n.right) && !is_red(n.left) {
-            h = rotate_left(h);
+    fn rank(&self, key: &K) -> usize {
+        let mut rank = 0;
+        let mut x = self.node_ref(self.root);
+        while let Some(n) = x {
+            match key.cmp(&n.key) {
+                Ordering::Less => x = self.node_ref(n.left),
+                Ordering::Greater => {
+                    rank += 1 + self.size_of(n.left);
+                    x = self.node_ref(n.right);
+                }
+                Ordering::Equal => return rank + self.size_of(n.left),
+            }
         }
+        rank
+    }
+
+    fn select(&self, mut k: usize) -> Option<&K> {
+        let mut x = self.node_ref(self.root);
+        while let Some(n) = x {
+            let t = self.size_of(n.left);
+            match k.cmp(&t) {
+                Ordering::Less => x = self.node_ref(n.left),
+                Ordering::Greater => {
+                    k -= t + 1;
+                    x = self.node_ref(n.right);
+                }
+                Ordering::Equal => return Some(&n.key),
+            }
+        }
+        None
     }
 
-    if is_red(left_of(h)) && is_red(left_of(left_of(h))) {
-        h = rotate_right(h);
+    fn range_keys(&self, lo: &K, hi: &K) -> Vec<&K> {
+        let mut q = vec![];
+        self.collect_range(self.root, &mut q, lo, hi);
+        q
     }
+}
 
-    if is_red(left_of(h)) && is_red(right_of(h)) {
-        flip_colors(h);
+impl<K, V> Default for RedBlackBST<K, V>
+where
+    K: Ord,
+{
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    h.map(|mut n| unsafe {
-        n.as_mut().update_size();
-    });
+impl<K, V> Display for RedBlackBST<K, V>
+where
+    K: Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        print_tree(self, self.root, f)
+    }
+}
+
+fn print_tree<K, V>(t: &RedBlackBST<K, V>, h: Link, f: &mut std::fmt::Formatter) -> std::fmt::Result
+where
+    K: Debug,
+    V: Debug,
+{
+    t.node_ref(h).map_or(Ok(()), |n| {
+        print_tree(t, n.left, f)?;
+        write!(f, "\n({:?}, {:?}, {:?})", &n.key, &n.value, &n.color)?;
+        print_tree(t, n.right, f)
+    })
+}
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    left: Link,
+    right: Link,
+    len: usize,
+    color: Color,
+}
+
+impl<K, V> Node<K, V> {
+    fn new(key: K, value: V, len: usize, color: Color) -> Self {
+        Self {
+            key,
+            value,
+            left: None,
+            right: None,
+            len,
+            color,
+        }
+    }
+}
 
-    h
+/// 范围迭代器，由 [`RedBlackBST::range`] 创建。
+pub struct Range<'a, K, V> {
+    nodes: &'a Arena<Node<K, V>>, // 树的所有节点。
+    front: Vec<&'a Node<K, V>>,   // 栈顶为下一个从前端返回的节点。
+    back: Vec<&'a Node<K, V>>,    // 栈顶为下一个从后端返回的节点。
+    remaining: usize,             // 剩余的元素数。
 }
 
-/// 左旋，将 h 变为左子树。
-fn rotate_left<K, V>(h: Link<K, V>) -> Link<K, V> {
-    let x = right_of(h);
-    x.map(|mut n| unsafe {
-        // 有子节点，则它本身非空。
-        let ph = h.unwrap().as_mut();
-        let px = n.as_mut();
-
-        ph.right = px.left;
-        px.left = h;
-        px.color = ph.color;
-        ph.color = Color::Red;
-        px.len = ph.len;
-        ph.update_size();
-    });
-    x
+impl<'a, K, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let n = self.front.pop()?;
+        // 右子树的左侧路径上的节点都比 n 大。
+        let mut x = n.right;
+        while let Some(i) = x {
+            let c = &self.nodes[i];
+            self.front.push(c);
+            x = c.left;
+        }
+        Some((&n.key, &n.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
 }
 
-/// 右旋，将 h 变为右子树。
-fn rotate_right<K, V>(h: Link<K, V>) -> Link<K, V> {
-    let x = left_of(h);
-    x.map(|mut n| unsafe {
-        // 有子节点，则其本身非空。
-        let ph = h.unwrap().as_mut();
-        let px = n.as_mut();
-
-        ph.left = px.right;
-        px.right = h;
-        px.color = ph.color;
-        ph.color = Color::Red;
-        px.len = ph.len;
-        ph.update_size();
-    });
-    x
+impl<K, V> DoubleEndedIterator for Range<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let n = self.back.pop()?;
+        // 左子树的右侧路径上的节点都比 n 小。
+        let mut x = n.left;
+        while let Some(i) = x {
+            let c = &self.nodes[i];
+            self.back.push(c);
+            x = c.right;
+        }
+        Some((&n.key, &n.value))
+    }
 }
 
-/// 反转 h 连接的颜色。
-fn flip_colors<K, V>(h: Link<K, V>) {
-    set_color(h, Color::Red);
-    set_color(left_of(h), Color::Black);
-    set_color(right_of(h), Color::Black);
+impl<K, V> ExactSizeIterator for Range<'_, K, V> {}
+
+impl<K, V> Index<&K> for RedBlackBST<K, V>
+where
+    K: Ord,
+{
+    type Output = V;
+
+    /// 获取键对应的值，键不存在时 panic。
+    fn index(&self, key: &K) -> &V {
+        self.get(key).expect("键不存在")
+    }
 }
 
-/// 是否为红连接。
-fn is_red<K, V>(h: Link<K, V>) -> bool {
-    h.map_or(false, |n| unsafe { n.as_ref().color == Color::Red })
+impl<K, V> Extend<(K, V)> for RedBlackBST<K, V>
+where
+    K: Ord,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.put(k, v);
+        }
+    }
 }
 
-/// 设置连接颜色。
-fn set_color<K, V>(h: Link<K, V>, color: Color) {
-    h.map(|mut n| unsafe { n.as_mut().color = color });
+impl<K, V> FromIterator<(K, V)> for RedBlackBST<K, V>
+where
+    K: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut st = Self::new();
+        st.extend(iter);
+        st
+    }
+}
+
+impl<K, V> IntoIterator for RedBlackBST<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    /// 按键的顺序取出所有键值对。
+    fn into_iter(mut self) -> Self::IntoIter {
+        // 先按中序记录节点的位置，再逐个取出。
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut stack = vec![];
+        let mut x = self.root;
+        while x.is_some() || !stack.is_empty() {
+            while let Some(i) = x {
+                stack.push(i);
+                x = self.nodes[i].left;
+            }
+            if let Some(i) = stack.pop() {
+                order.push(i);
+                x = self.nodes[i].right;
+            }
+        }
+        order
+            .into_iter()
+            .map(|i| {
+                let n = self.nodes.remove(i);
+                (n.key, n.value)
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
 }
 
-/// 获取节点长度。
-fn size_of<K, V>(h: Link<K, V>) -> usize {
-    h.map_or(0, |n| unsafe { n.as_ref().len })
+impl<'a, K, V> IntoIterator for &'a RedBlackBST<K, V>
+where
+    K: Ord,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Range<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -483,26 +798,22 @@ mod tests {
     #[test]
     fn put_test() {
         let t = create_tree();
-        assert_eq!(10, size_of(t.root));
+        assert_eq!(10, t.len());
 
-        println!("遍历：");
-        for i in t.iter() {
-            println!("{}, {}, {:?}", i.key, i.value, i.color);
-        }
+        let values: String = t.iter().map(|(_, v)| v).collect();
+        assert_eq!("acehlmprsx", values);
     }
 
     #[test]
     fn delete_min_test() {
         let mut t = create_tree();
 
-        let n = get_from(t.root, b'a');
-        assert!(n.is_some());
+        assert!(t.contains(&b'a'));
 
         t.delete_min();
-        assert_eq!(9, size_of(t.root));
+        assert_eq!(9, t.len());
 
-        let n = get_from(t.root, b'a');
-        assert!(n.is_none());
+        assert!(!t.contains(&b'a'));
 
         println!("删除最小节点后：{t}");
     }
@@ -511,27 +822,110 @@ mod tests {
     fn delete_max_test() {
         let mut t = create_tree();
 
-        let n = get_from(t.root, b'x');
-        assert!(n.is_some());
+        assert!(t.contains(&b'x'));
 
         t.delete_max();
-        assert_eq!(9, size_of(t.root));
+        assert_eq!(9, t.len());
 
-        let n = get_from(t.root, b'x');
-        assert!(n.is_none());
+        assert!(!t.contains(&b'x'));
     }
 
     #[test]
     fn delete_test() {
         let mut t = create_tree();
 
-        let n = get_from(t.root, b'x');
-        assert!(n.is_some());
+        assert!(t.contains(&b'x'));
+
+        assert_eq!(Some('x'), t.delete(&b'x'));
+        assert_eq!(None, t.delete(&b'x'));
+        assert_eq!(9, t.len());
+
+        assert!(!t.contains(&b'x'));
+    }
 
-        t.delete(b'x');
-        assert_eq!(9, size_of(t.root));
+    #[test]
+    fn range_test() {
+        let t = create_tree();
+        let values: String = t.range(b'c'..b'p').map(|(_, v)| v).collect();
+        assert_eq!("cehlm", values);
+        let values: String = t.range(b'c'..=b'p').rev().map(|(_, v)| v).collect();
+        assert_eq!("pmlhec", values);
+        let values: String = t.range(b'i'..).map(|(_, v)| v).collect();
+        assert_eq!("lmprsx", values);
+        let values: String = t.range(..b'e').map(|(_, v)| v).collect();
+        assert_eq!("ac", values);
+        assert_eq!(10, t.range(..).len());
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = b'p'..b'c';
+        assert_eq!(0, t.range(empty).count());
+        assert_eq!(0, t.range(b'y'..).count());
+
+        // 两端交替迭代，在中间相遇。
+        let mut r = t.range((Bound::Excluded(b'a'), Bound::Excluded(b'x')));
+        assert_eq!(8, r.len());
+        assert_eq!(Some((&b'c', &'c')), r.next());
+        assert_eq!(Some((&b's', &'s')), r.next_back());
+        assert_eq!(Some((&b'e', &'e')), r.next());
+        assert_eq!(Some((&b'r', &'r')), r.next_back());
+        let rest: String = r.map(|(_, v)| v).collect();
+        assert_eq!("hlmp", rest);
+    }
 
-        let n = get_from(t.root, b'x');
-        assert!(n.is_none());
+    #[test]
+    fn ordered_test() {
+        let t = create_tree();
+        assert_eq!(10, t.len());
+        assert_eq!(Some(&'e'), t.get(&b'e'));
+        assert_eq!(None, t.get(&b'b'));
+        assert_eq!(Some(&b'a'), t.min());
+        assert_eq!(Some(&b'x'), t.max());
+        assert_eq!(Some(&b'h'), t.floor(&b'k'));
+        assert_eq!(Some(&b'l'), t.ceiling(&b'k'));
+        assert_eq!(4, t.rank(&b'l'));
+        assert_eq!(Some(&b'l'), t.select(4));
+        assert_eq!(vec![&b'h', &b'l', &b'm'], t.range_keys(&b'f', &b'n'));
+    }
+
+    #[test]
+    fn check_test() {
+        let mut t = create_tree();
+        assert!(t.is_valid());
+        for k in [b'e', b'x', b's'] {
+            t.delete(&k);
+            assert_eq!(Ok(()), t.check());
+        }
+        t.delete_max();
+        t.delete_min();
+        assert_eq!(Ok(()), t.check());
+
+        // 把一个黑色的叶子染红，破坏黑色平衡。
+        let mut x = t.root.unwrap();
+        while let Some(l) = t.nodes[x].left {
+            x = l;
+        }
+        t.nodes[x].color = Color::Red;
+        assert!(!t.is_valid());
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut t: RedBlackBST<_, _> = "search".bytes().zip(0..).collect();
+        t.extend([(b'x', 6)]);
+        assert_eq!(5, t[&b'h']);
+        let keys: Vec<u8> = (&t).into_iter().rev().map(|(k, _)| *k).collect();
+        assert_eq!(b"xsrheca".to_vec(), keys);
+        let pairs: Vec<_> = t.into_iter().collect();
+        assert_eq!(
+            vec![
+                (b'a', 2),
+                (b'c', 4),
+                (b'e', 1),
+                (b'h', 5),
+                (b'r', 3),
+                (b's', 0),
+                (b'x', 6)
+            ],
+            pairs
+        );
     }
 }
@@ -1,59 +1,322 @@
 //! 基于拉链法的哈希表。
 
-use super::sequential_search::SequentialSearchST;
+use std::{
+    hash::{BuildHasher, Hash, RandomState},
+    ops::Index,
+    slice,
+};
 
-struct SeparateChainingHashST {
-    n: usize, // 键值对总数。
-    m: usize, // 散列表的大小。
-    st: Vec<SequentialSearchST>,
+use super::{
+    check_load_factor, hash,
+    sequential_search::{self, SequentialSearchST},
+    HashStats, SymbolTable,
+};
+
+const INIT_CAPACITY: usize = 4; // 散列表的初始大小。
+
+pub struct SeparateChainingHashST<K, V, S = RandomState> {
+    n: usize,                          // 键值对总数。
+    m: usize,                          // 散列表的大小。
+    st: Vec<SequentialSearchST<K, V>>, // 存放键值对的链表。
+    hasher: S,                         // 散列函数。
+    min_load: f64,                     // 链表的平均长度低于它时缩小一半。
+    max_load: f64,                     // 链表的平均长度超过它时扩大一倍。
 }
 
-impl SeparateChainingHashST {
+impl<K, V> SeparateChainingHashST<K, V>
+where
+    K: Hash + Eq,
+{
     /// 创建一个空的散列表。
     pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<K, V, S> SeparateChainingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// 创建一个使用指定散列函数的空散列表。
+    pub fn with_hasher(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(INIT_CAPACITY, hasher)
+    }
+
+    /// 创建一个含有 m 条链表、使用指定散列函数的空散列表。
+    pub fn with_capacity_and_hasher(m: usize, hasher: S) -> Self {
+        let m = m.max(1);
         Self {
             n: 0,
-            m: 997,
-            st: vec![],
+            m,
+            st: empty_chains(m),
+            hasher,
+            min_load: 2.0,
+            max_load: 10.0,
+        }
+    }
+
+    /// 设置装填因子（链表的平均长度）的范围：超过 max 时扩大一倍，低于 min 时缩小一半。
+    ///
+    /// 默认为 [2, 10]。min 的两倍必须小于 max。
+    pub fn set_load_factor(&mut self, min: f64, max: f64) {
+        check_load_factor(min, max);
+        self.min_load = min;
+        self.max_load = max;
+    }
+
+    /// 当前的装填因子。
+    pub fn load_factor(&self) -> f64 {
+        self.n as f64 / self.m as f64
+    }
+
+    /// 获取统计信息，探测长度为在链表中比较的次数。
+    pub fn stats(&self) -> HashStats {
+        let probes = self.st.iter().flat_map(|chain| 1..=chain.len());
+        HashStats::new(self.m, probes)
+    }
+
+    /// 获取所有键值对的迭代器，顺序不确定。
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            chains: self.st.iter(),
+            curr: None,
         }
     }
 
+    /// 调整为 m 条链表，重新散列所有的键。
+    fn resize(&mut self, m: usize) {
+        let chains = std::mem::replace(&mut self.st, empty_chains(m));
+        self.m = m;
+        for (k, v) in chains.into_iter().flatten() {
+            let i = self.hash(&k);
+            self.st[i].put(k, v);
+        }
+    }
+
+    /// 获取哈希值。
+    fn hash(&self, key: &K) -> usize {
+        hash(&self.hasher, key, self.m)
+    }
+}
+
+impl<K, V, S> SymbolTable<K, V> for SeparateChainingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
     /// 根据键，查找值。
-    pub fn get(&self, key: i32) -> Option<i32> {
-        let i = self.hash(key);
-        self.st[i].get(key)
+    fn get(&self, key: &K) -> Option<&V> {
+        self.st[self.hash(key)].get(key)
     }
 
     /// 插入键值对。
-    pub fn put(&mut self, key: i32, value: i32) {
+    fn put(&mut self, key: K, value: V) {
+        let i = self.hash(&key);
+        let st = &mut self.st[i];
+        let len = st.len();
+        st.put(key, value);
+        // 只有插入新键时才计数。
+        self.n += st.len() - len;
+
+        if self.n as f64 > self.max_load * self.m as f64 {
+            self.resize(2 * self.m);
+        }
+    }
+
+    /// 删除指定的键值对。
+    fn delete(&mut self, key: &K) -> Option<V> {
         let i = self.hash(key);
-        self.resize(i);
+        let value = self.st[i].delete(key)?;
+        self.n -= 1;
+
+        if self.m / 2 >= INIT_CAPACITY && (self.n as f64) < self.min_load * self.m as f64 {
+            self.resize(self.m / 2);
+        }
+        Some(value)
+    }
+
+    fn len(&self) -> usize {
+        self.n
+    }
+}
+
+impl<K, V, S> Default for SeparateChainingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, S> Index<&K> for SeparateChainingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    type Output = V;
 
-        self.n += 1;
-        self.st[i].put(key, value);
+    /// 获取键对应的值，键不存在时 panic。
+    fn index(&self, key: &K) -> &V {
+        self.get(key).expect("键不存在")
     }
+}
 
-    fn resize(&mut self, i: usize) {
-        // 借助 Vec 自动调整缓存大小。
-        while self.st.len() <= i {
-            self.st.push(SequentialSearchST::new());
+impl<K, V, S> Extend<(K, V)> for SeparateChainingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.put(k, v);
         }
     }
+}
 
-    /// 获取哈希值。
-    fn hash(&self, key: i32) -> usize {
-        (key & 0x7FFFFFFF) as usize % self.m
+impl<K, V, S> FromIterator<(K, V)> for SeparateChainingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut st = Self::default();
+        st.extend(iter);
+        st
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a SeparateChainingHashST<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K, V, S> IntoIterator for SeparateChainingHashST<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.st
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+/// 键值对的迭代器，由 [`SeparateChainingHashST::iter`] 创建。
+pub struct Iter<'a, K, V> {
+    chains: slice::Iter<'a, SequentialSearchST<K, V>>, // 剩余的链表。
+    curr: Option<sequential_search::Iter<'a, K, V>>,   // 当前链表的迭代器。
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V>
+where
+    K: Eq,
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.curr.as_mut().and_then(|it| it.next()) {
+                return Some(item);
+            }
+            self.curr = Some(self.chains.next()?.iter());
+        }
     }
 }
 
+/// 创建 m 条空链表。
+fn empty_chains<K, V>(m: usize) -> Vec<SequentialSearchST<K, V>>
+where
+    K: Eq,
+{
+    (0..m).map(|_| SequentialSearchST::new()).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::search::tests::ZeroState;
 
     #[test]
     fn test() {
         let mut h = SeparateChainingHashST::new();
         h.put(1, 99);
-        assert_eq!(Some(99), h.get(1));
+        assert_eq!(Some(&99), h.get(&1));
+        assert_eq!(None, h.get(&2));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn string_key_test() {
+        let mut h = SeparateChainingHashST::new();
+        h.put("sea".to_string(), 2);
+        h.put("shore".to_string(), 7);
+        h.put("sea".to_string(), 6);
+        assert_eq!(Some(&6), h.get(&"sea".to_string()));
+        assert_eq!(Some(&7), h.get(&"shore".to_string()));
+        assert_eq!(2, h.len());
+
+        assert_eq!(Some(6), h.delete(&"sea".to_string()));
+        assert_eq!(None, h.delete(&"sea".to_string()));
+        assert_eq!(1, h.len());
+    }
+
+    #[test]
+    fn iter_test() {
+        let h: SeparateChainingHashST<_, _> =
+            ["sea", "shore", "sea"].into_iter().zip(0..).collect();
+        assert_eq!(2, h[&"sea"]);
+
+        let mut pairs: Vec<_> = (&h).into_iter().map(|(&k, &v)| (k, v)).collect();
+        pairs.sort();
+        assert_eq!(vec![("sea", 2), ("shore", 1)], pairs);
+
+        let mut owned: Vec<_> = h.into_iter().collect();
+        owned.sort();
+        assert_eq!(pairs, owned);
+    }
+
+    #[test]
+    fn resize_test() {
+        let mut h = SeparateChainingHashST::new();
+        for i in 0..1000 {
+            h.put(i, i);
+            h.put(i, i + 1);
+            assert!(h.load_factor() <= 10.0);
+        }
+        assert_eq!(1000, h.len());
+        let m = h.stats().buckets;
+        assert!(m > INIT_CAPACITY);
+
+        for i in 0..990 {
+            assert_eq!(Some(i + 1), h.delete(&i));
+        }
+        assert_eq!(10, h.len());
+        assert!(h.stats().buckets < m);
+        assert!((990..1000).all(|i| h[&i] == i + 1));
+    }
+
+    #[test]
+    fn stats_test() {
+        let mut h = SeparateChainingHashST::with_hasher(ZeroState);
+        h.set_load_factor(0.0, 100.0);
+        h.extend((0..5).map(|i| (i, i)));
+        let stats = h.stats();
+        assert_eq!(5, stats.len);
+        assert_eq!(INIT_CAPACITY, stats.buckets);
+        assert_eq!(1.25, stats.load_factor);
+        assert_eq!(5, stats.max_probe);
+        assert_eq!(3.0, stats.avg_probe);
+    }
+}
@@ -1,121 +1,217 @@
 //! 顺序查找。
 
-use std::{fmt::Display, marker::PhantomData, ptr::NonNull};
+use std::{
+    fmt::{Debug, Display},
+    ops::Index,
+};
+
+use super::{arena::Arena, SymbolTable};
+
+/// 下一个节点在节点池中的位置。
+type Link = Option<usize>;
 
 /// 基于无序链表。
-pub struct SequentialSearchST {
-    head: Option<NonNull<Node>>,
-    marker: PhantomData<Box<Node>>,
+pub struct SequentialSearchST<K, V> {
+    nodes: Arena<Node<K, V>>, // 保存链表的所有节点。
+    head: Link,               // 链表的头部。
 }
 
-struct Node {
-    key: i32,
-    value: Option<i32>,
-    next: Option<NonNull<Node>>,
+struct Node<K, V> {
+    key: K,
+    value: V,
+    next: Link,
 }
 
-impl Node {
-    fn new(key: i32, value: Option<i32>, next: Option<NonNull<Node>>) -> Self {
+impl<K, V> Node<K, V> {
+    fn new(key: K, value: V, next: Link) -> Self {
         Self { key, value, next }
     }
 }
 
-impl Display for SequentialSearchST {
+impl<K, V> Display for SequentialSearchST<K, V>
+where
+    K: Display,
+    V: Debug,
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        unsafe {
-            let mut x = self.head.as_ref();
-            while let Some(node) = x {
-                let p = node.as_ptr();
-                write!(f, "\n({}, {:?})", (*p).key, (*p).value)?;
-                x = (*p).next.as_ref();
-            }
-            writeln!(f)
-        }
-    }
-}
-
-impl Drop for SequentialSearchST {
-    fn drop(&mut self) {
-        unsafe {
-            while let Some(node) = self.head.take() {
-                let mut n = Box::from_raw(node.as_ptr());
-                self.head = n.next.take();
-            }
+        let mut x = self.head;
+        while let Some(i) = x {
+            let node = &self.nodes[i];
+            write!(f, "\n({}, {:?})", node.key, node.value)?;
+            x = node.next;
         }
+        writeln!(f)
     }
 }
 
-impl SequentialSearchST {
+impl<K, V> SequentialSearchST<K, V>
+where
+    K: Eq,
+{
     // 创建一个新的无序链表。
     pub fn new() -> Self {
         Self {
+            nodes: Arena::new(),
             head: None,
-            marker: PhantomData,
         }
     }
 
-    // 查找指定的值。
-    pub fn get(&self, key: i32) -> Option<i32> {
-        unsafe {
-            let mut x = self.head.as_ref();
-            while let Some(node) = x {
-                let p = node.as_ptr();
-                debug_assert!(!p.is_null());
-                if key == (*p).key {
-                    // 命中，返回。
-                    return (*p).value;
-                }
-                x = (*p).next.as_ref();
-            }
+    /// 获取所有键值对的迭代器，按插入的逆序排列。
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            nodes: &self.nodes,
+            next: self.head,
         }
-        None // 未命中。
     }
 
-    // 添加一个键值对。
-    pub fn put(&mut self, key: i32, value: i32) {
-        unsafe {
-            let mut x = self.head.as_mut();
-            while let Some(node) = x {
-                let p = node.as_ptr();
-                debug_assert!(!p.is_null());
-                if key == (*p).key {
-                    // 命中，更新。
-                    (*p).value = Some(value);
-                    return;
-                }
-                x = (*p).next.as_mut();
+    // 查找键所在节点的位置。
+    fn find(&self, key: &K) -> Link {
+        let mut x = self.head;
+        while let Some(i) = x {
+            if *key == self.nodes[i].key {
+                break;
             }
+            x = self.nodes[i].next;
+        }
+        x
+    }
+}
+
+impl<K, V> SymbolTable<K, V> for SequentialSearchST<K, V>
+where
+    K: Eq,
+{
+    // 查找指定的值。
+    fn get(&self, key: &K) -> Option<&V> {
+        self.find(key).map(|i| &self.nodes[i].value)
+    }
 
-            // 未命中，插入根节点前面。
-            let node = Node::new(key, Some(value), self.head);
-            self.head = Some(Box::leak(Box::new(node)).into())
+    // 添加一个键值对。
+    fn put(&mut self, key: K, value: V) {
+        if let Some(i) = self.find(&key) {
+            // 命中，更新。
+            self.nodes[i].value = value;
+            return;
         }
+
+        // 未命中，插入根节点前面。
+        let node = Node::new(key, value, self.head);
+        self.head = Some(self.nodes.insert(node));
     }
 
     // 删除指定的键值对。
-    pub fn delete(&mut self, key: i32) {
-        unsafe {
-            let mut previous = self.head.as_ref();
-            let mut x = previous.map(|n| (*n.as_ptr()).next.as_ref()).flatten();
-            while let Some(node) = x {
-                let p = node.as_ptr();
-                debug_assert!(!p.is_null());
-                if key == (*p).key {
-                    // 命中，更新。
-                    previous.map(|n| {
-                        (*n.as_ptr()).next = (*p).next;
-                    });
-                    // 释放命中节点。
-                    Box::from_raw(p);
-                    return;
+    fn delete(&mut self, key: &K) -> Option<V> {
+        // prev 为当前节点的前一个节点。
+        let mut prev: Link = None;
+        let mut x = self.head;
+        while let Some(i) = x {
+            if *key == self.nodes[i].key {
+                // 命中，跳过并回收该节点。
+                let node = self.nodes.remove(i);
+                match prev {
+                    None => self.head = node.next,
+                    Some(p) => self.nodes[p].next = node.next,
                 }
-                previous = x;
-                x = (*p).next.as_ref();
+                return Some(node.value);
             }
+            prev = x;
+            x = self.nodes[i].next;
+        }
+        None
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+impl<K, V> Default for SequentialSearchST<K, V>
+where
+    K: Eq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Index<&K> for SequentialSearchST<K, V>
+where
+    K: Eq,
+{
+    type Output = V;
+
+    /// 获取键对应的值，键不存在时 panic。
+    fn index(&self, key: &K) -> &V {
+        self.get(key).expect("键不存在")
+    }
+}
+
+impl<K, V> Extend<(K, V)> for SequentialSearchST<K, V>
+where
+    K: Eq,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.put(k, v);
         }
     }
 }
 
+impl<K, V> FromIterator<(K, V)> for SequentialSearchST<K, V>
+where
+    K: Eq,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut st = Self::new();
+        st.extend(iter);
+        st
+    }
+}
+
+impl<K, V> IntoIterator for SequentialSearchST<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    /// 按插入的逆序取出所有键值对。
+    fn into_iter(mut self) -> Self::IntoIter {
+        let mut v = Vec::with_capacity(self.nodes.len());
+        while let Some(i) = self.head {
+            let node = self.nodes.remove(i);
+            self.head = node.next;
+            v.push((node.key, node.value));
+        }
+        v.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a SequentialSearchST<K, V>
+where
+    K: Eq,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// 键值对的迭代器，由 [`SequentialSearchST::iter`] 创建。
+pub struct Iter<'a, K, V> {
+    nodes: &'a Arena<Node<K, V>>, // 链表的所有节点。
+    next: Link,                   // 下一个节点。
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = &self.nodes[self.next?];
+        self.next = node.next;
+        Some((&node.key, &node.value))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,13 +223,36 @@ mod tests {
         st.put(12, 55);
         println!("st:{st}");
 
-        assert_eq!(Some(55), st.get(12));
-        assert_eq!(Some(5), st.get(1));
+        assert_eq!(Some(&55), st.get(&12));
+        assert_eq!(Some(&5), st.get(&1));
 
-        st.delete(1);
-        assert_eq!(None, st.get(1));
+        assert_eq!(Some(5), st.delete(&1));
+        assert_eq!(None, st.get(&1));
+        assert_eq!(1, st.len());
 
         st.put(12, 33);
-        assert_eq!(Some(33), st.get(12));
+        assert_eq!(Some(&33), st.get(&12));
+    }
+
+    #[test]
+    fn string_key_test() {
+        let mut st = SequentialSearchST::new();
+        for (i, k) in ["she", "sells", "sea", "shells"].into_iter().enumerate() {
+            st.put(k.to_string(), i);
+        }
+        // 删除链表头部的节点。
+        st.delete(&"shells".to_string());
+        assert_eq!(None, st.get(&"shells".to_string()));
+        assert_eq!(Some(&2), st.get(&"sea".to_string()));
+        assert_eq!(Some(&0), st.get(&"she".to_string()));
+    }
+
+    #[test]
+    fn iter_test() {
+        let st: SequentialSearchST<_, _> = [(1, 'a'), (2, 'b'), (1, 'c')].into_iter().collect();
+        assert_eq!(2, st.len());
+        assert_eq!('c', st[&1]);
+        assert_eq!(vec![(&2, &'b'), (&1, &'c')], st.iter().collect::<Vec<_>>());
+        assert_eq!(vec![(2, 'b'), (1, 'c')], st.into_iter().collect::<Vec<_>>());
     }
 }
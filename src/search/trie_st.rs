@@ -0,0 +1,407 @@
+//! 基于单词查找树（R 向）的符号表。
+
+use std::ops::Index;
+
+use super::SymbolTable;
+
+const R: usize = 256; // 字母表的大小，按字节划分。
+
+/// 以字符串为键的符号表，键按字节逐层分支。
+pub struct TrieST<V> {
+    root: Option<Box<Node<V>>>,
+    n: usize, // 键的总数。
+}
+
+struct Node<V> {
+    value: Option<V>,
+    next: [Option<Box<Node<V>>>; R],
+}
+
+impl<V> Node<V> {
+    fn new() -> Self {
+        Self {
+            value: None,
+            next: std::array::from_fn(|_| None),
+        }
+    }
+}
+
+impl<V> TrieST<V> {
+    /// 创建一个空的符号表。
+    pub fn new() -> Self {
+        Self { root: None, n: 0 }
+    }
+
+    /// 获取键的总数。
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// 是否为空。
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// 获取指定键对应的值。
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.node(key.as_bytes()).and_then(|x| x.value.as_ref())
+    }
+
+    /// 是否包含指定的键。
+    pub fn contains(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 插入键值对。
+    pub fn put(&mut self, key: &str, value: V) {
+        let mut x = self.root.get_or_insert_with(|| Box::new(Node::new()));
+        for b in key.bytes() {
+            x = x.next[b as usize].get_or_insert_with(|| Box::new(Node::new()));
+        }
+        if x.value.is_none() {
+            self.n += 1;
+        }
+        x.value = Some(value);
+    }
+
+    /// 删除指定的键，并返回其值。
+    pub fn delete(&mut self, key: &str) -> Option<V> {
+        let value = delete_from(&mut self.root, key.as_bytes());
+        if value.is_some() {
+            self.n -= 1;
+        }
+        value
+    }
+
+    /// 获取所有的键，按字节序排列。
+    pub fn keys(&self) -> Vec<String> {
+        self.keys_with_prefix("")
+    }
+
+    /// 获取所有以 prefix 开头的键。
+    pub fn keys_with_prefix(&self, prefix: &str) -> Vec<String> {
+        self.pairs_with_prefix(prefix)
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect()
+    }
+
+    /// 获取所有键值对的迭代器，按键的字节序排列。
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter {
+            it: self.pairs_with_prefix("").into_iter(),
+        }
+    }
+
+    /// 获取所有以 prefix 开头的键值对。
+    fn pairs_with_prefix(&self, prefix: &str) -> Vec<(String, &V)> {
+        let mut results = vec![];
+        let mut pre = prefix.as_bytes().to_vec();
+        collect(self.node(prefix.as_bytes()), &mut pre, &mut results);
+        results
+    }
+
+    /// 获取所有和 pattern 匹配的键，`.` 匹配任意一个字节。
+    pub fn keys_that_match(&self, pattern: &str) -> Vec<String> {
+        let mut results = vec![];
+        collect_match(
+            self.root.as_deref(),
+            &mut vec![],
+            pattern.as_bytes(),
+            &mut results,
+        );
+        results
+    }
+
+    /// 获取 query 的前缀中最长的键。
+    pub fn longest_prefix_of<'a>(&self, query: &'a str) -> Option<&'a str> {
+        let mut x = self.root.as_deref();
+        let mut len = None;
+        for (d, b) in query.bytes().enumerate() {
+            match x {
+                Some(n) => {
+                    if n.value.is_some() {
+                        len = Some(d);
+                    }
+                    x = n.next[b as usize].as_deref();
+                }
+                None => break,
+            }
+        }
+        if x.is_some_and(|n| n.value.is_some()) {
+            len = Some(query.len());
+        }
+        len.map(|d| &query[..d])
+    }
+
+    /// 获取键对应的节点。
+    fn node(&self, key: &[u8]) -> Option<&Node<V>> {
+        let mut x = self.root.as_deref();
+        for &b in key {
+            x = x?.next[b as usize].as_deref();
+        }
+        x
+    }
+}
+
+impl<V> SymbolTable<String, V> for TrieST<V> {
+    fn get(&self, key: &String) -> Option<&V> {
+        TrieST::get(self, key)
+    }
+
+    fn put(&mut self, key: String, value: V) {
+        TrieST::put(self, &key, value);
+    }
+
+    fn delete(&mut self, key: &String) -> Option<V> {
+        TrieST::delete(self, key)
+    }
+
+    fn len(&self) -> usize {
+        self.n
+    }
+}
+
+impl<V> Default for TrieST<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Index<&str> for TrieST<V> {
+    type Output = V;
+
+    /// 获取键对应的值，键不存在时 panic。
+    fn index(&self, key: &str) -> &V {
+        self.get(key).expect("键不存在")
+    }
+}
+
+impl<K, V> Extend<(K, V)> for TrieST<V>
+where
+    K: AsRef<str>,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.put(k.as_ref(), v);
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for TrieST<V>
+where
+    K: AsRef<str>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut st = Self::new();
+        st.extend(iter);
+        st
+    }
+}
+
+impl<V> IntoIterator for TrieST<V> {
+    type Item = (String, V);
+    type IntoIter = std::vec::IntoIter<(String, V)>;
+
+    /// 按键的字节序取出所有键值对。
+    fn into_iter(self) -> Self::IntoIter {
+        let mut results = Vec::with_capacity(self.n);
+        collect_owned(self.root, &mut vec![], &mut results);
+        results.into_iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a TrieST<V> {
+    type Item = (String, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// 键值对的迭代器，由 [`TrieST::iter`] 创建。
+pub struct Iter<'a, V> {
+    it: std::vec::IntoIter<(String, &'a V)>,
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (String, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.it.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+}
+
+/// 删除键，返回其值；同时回收不再需要的节点。
+fn delete_from<V>(x: &mut Option<Box<Node<V>>>, key: &[u8]) -> Option<V> {
+    let Some(n) = x else {
+        return None;
+    };
+
+    let deleted = match key.split_first() {
+        None => n.value.take(),
+        Some((&b, rest)) => delete_from(&mut n.next[b as usize], rest),
+    };
+
+    if n.value.is_none() && n.next.iter().all(|c| c.is_none()) {
+        *x = None;
+    }
+    deleted
+}
+
+fn collect<'a, V>(x: Option<&'a Node<V>>, pre: &mut Vec<u8>, results: &mut Vec<(String, &'a V)>) {
+    let Some(n) = x else {
+        return;
+    };
+
+    if let Some(v) = &n.value {
+        results.push((to_string(pre), v));
+    }
+    for (c, next) in n.next.iter().enumerate() {
+        if next.is_some() {
+            pre.push(c as u8);
+            collect(next.as_deref(), pre, results);
+            pre.pop();
+        }
+    }
+}
+
+/// 按字节序取出所有键值对。
+fn collect_owned<V>(x: Option<Box<Node<V>>>, pre: &mut Vec<u8>, results: &mut Vec<(String, V)>) {
+    let Some(n) = x else {
+        return;
+    };
+
+    let Node { value, next } = *n;
+    if let Some(v) = value {
+        results.push((to_string(pre), v));
+    }
+    for (c, next) in next.into_iter().enumerate() {
+        if next.is_some() {
+            pre.push(c as u8);
+            collect_owned(next, pre, results);
+            pre.pop();
+        }
+    }
+}
+
+fn collect_match<V>(x: Option<&Node<V>>, pre: &mut Vec<u8>, pat: &[u8], results: &mut Vec<String>) {
+    let Some(n) = x else {
+        return;
+    };
+
+    let d = pre.len();
+    if d == pat.len() {
+        if n.value.is_some() {
+            results.push(to_string(pre));
+        }
+        return;
+    }
+
+    for (c, next) in n.next.iter().enumerate() {
+        if next.is_some() && (pat[d] == b'.' || pat[d] as usize == c) {
+            pre.push(c as u8);
+            collect_match(next.as_deref(), pre, pat, results);
+            pre.pop();
+        }
+    }
+}
+
+/// 完整的键一定是合法的 UTF-8。
+fn to_string(pre: &[u8]) -> String {
+    String::from_utf8(pre.to_vec()).expect("键必须是合法的 UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn put_test() {
+        let mut t = create();
+        assert_eq!(7, t.len());
+        assert_eq!(Some(&7), t.get("shore"));
+        assert_eq!(Some(&6), t.get("sea"));
+        assert_eq!(None, t.get("shor"));
+
+        t.put("sea", 8);
+        assert_eq!(7, t.len());
+        assert_eq!(Some(&8), t.get("sea"));
+    }
+
+    #[test]
+    fn delete_test() {
+        let mut t = create();
+        assert_eq!(Some(3), t.delete("shells"));
+        assert_eq!(None, t.delete("shells"));
+        assert_eq!(6, t.len());
+        assert!(!t.contains("shells"));
+        assert!(t.contains("she"));
+        assert!(t.contains("sells"));
+    }
+
+    #[test]
+    fn keys_test() {
+        let t = create();
+        assert_eq!(
+            vec!["by", "sea", "sells", "she", "shells", "shore", "the"],
+            t.keys()
+        );
+        assert_eq!(vec!["she", "shells", "shore"], t.keys_with_prefix("sh"));
+        assert!(t.keys_with_prefix("x").is_empty());
+    }
+
+    #[test]
+    fn keys_that_match_test() {
+        let t = create();
+        assert_eq!(vec!["she", "the"], t.keys_that_match(".he"));
+        assert_eq!(vec!["shells"], t.keys_that_match("s..l.s"));
+    }
+
+    #[test]
+    fn longest_prefix_of_test() {
+        let mut t = create();
+        assert_eq!(Some("shells"), t.longest_prefix_of("shellsort"));
+        assert_eq!(Some("she"), t.longest_prefix_of("shelter"));
+        assert_eq!(None, t.longest_prefix_of("quicksort"));
+
+        t.put("", 9);
+        assert_eq!(Some(""), t.longest_prefix_of("quicksort"));
+    }
+
+    fn create() -> TrieST<usize> {
+        let mut t = TrieST::new();
+        for (i, k) in ["she", "sells", "sea", "shells", "by", "the", "sea", "shore"]
+            .into_iter()
+            .enumerate()
+        {
+            t.put(k, i);
+        }
+        t
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut t: TrieST<_> = ["she", "sells", "sea"].into_iter().zip(0..).collect();
+        t.extend([("shells".to_string(), 3)]);
+        assert_eq!(1, t["sells"]);
+
+        let pairs: Vec<_> = t.iter().collect();
+        assert_eq!(
+            vec![
+                ("sea".to_string(), &2),
+                ("sells".to_string(), &1),
+                ("she".to_string(), &0),
+                ("shells".to_string(), &3)
+            ],
+            pairs
+        );
+        let owned: Vec<_> = t.into_iter().map(|(k, v)| (k, v * 10)).collect();
+        assert_eq!(("shells".to_string(), 30), owned[3]);
+    }
+}
@@ -0,0 +1,458 @@
+//! 基于三向单词查找树的符号表。
+
+use std::ops::Index;
+
+use super::SymbolTable;
+
+/// 以字符串为键的符号表，键按字符逐层比较。
+#[allow(clippy::upper_case_acronyms)]
+pub struct TST<V> {
+    root: Link<V>,
+    n: usize, // 键的总数。
+}
+
+type Link<V> = Option<Box<Node<V>>>;
+
+struct Node<V> {
+    c: char,
+    value: Option<V>,
+    left: Link<V>,  // 小于 c 的键。
+    mid: Link<V>,   // 以 c 开头的键。
+    right: Link<V>, // 大于 c 的键。
+}
+
+impl<V> Node<V> {
+    fn new(c: char) -> Self {
+        Self {
+            c,
+            value: None,
+            left: None,
+            mid: None,
+            right: None,
+        }
+    }
+}
+
+impl<V> TST<V> {
+    /// 创建一个空的符号表。
+    pub fn new() -> Self {
+        Self { root: None, n: 0 }
+    }
+
+    /// 获取键的总数。
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// 是否为空。
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// 获取指定键对应的值。
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.node(key).and_then(|x| x.value.as_ref())
+    }
+
+    /// 是否包含指定的键。
+    pub fn contains(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 插入键值对。
+    ///
+    /// # Panics
+    ///
+    /// 键为空字符串时 panic。
+    pub fn put(&mut self, key: &str, value: V) {
+        assert!(!key.is_empty(), "键不能为空");
+        let chars: Vec<char> = key.chars().collect();
+        if put_with(&mut self.root, &chars, value).is_none() {
+            self.n += 1;
+        }
+    }
+
+    /// 删除指定的键，并返回其值。
+    pub fn delete(&mut self, key: &str) -> Option<V> {
+        let chars: Vec<char> = key.chars().collect();
+        if chars.is_empty() {
+            return None;
+        }
+        let value = delete_from(&mut self.root, &chars);
+        if value.is_some() {
+            self.n -= 1;
+        }
+        value
+    }
+
+    /// 获取所有的键，按字符序排列。
+    pub fn keys(&self) -> Vec<String> {
+        self.pairs().into_iter().map(|(k, _)| k).collect()
+    }
+
+    /// 获取所有键值对的迭代器，按键的字符序排列。
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter {
+            it: self.pairs().into_iter(),
+        }
+    }
+
+    /// 获取所有键值对，按键的字符序排列。
+    fn pairs(&self) -> Vec<(String, &V)> {
+        let mut results = vec![];
+        collect(self.root.as_deref(), &mut String::new(), &mut results);
+        results
+    }
+
+    /// 获取所有以 prefix 开头的键。
+    pub fn keys_with_prefix(&self, prefix: &str) -> Vec<String> {
+        if prefix.is_empty() {
+            return self.keys();
+        }
+
+        let mut results = vec![];
+        if let Some(x) = self.node(prefix) {
+            if let Some(v) = &x.value {
+                results.push((prefix.to_owned(), v));
+            }
+            collect(x.mid.as_deref(), &mut prefix.to_owned(), &mut results);
+        }
+        results.into_iter().map(|(k, _)| k).collect()
+    }
+
+    /// 获取所有和 pattern 匹配的键，`.` 匹配任意一个字符。
+    pub fn keys_that_match(&self, pattern: &str) -> Vec<String> {
+        let pat: Vec<char> = pattern.chars().collect();
+        let mut results = vec![];
+        collect_match(self.root.as_deref(), &mut String::new(), &pat, &mut results);
+        results
+    }
+
+    /// 获取 query 的前缀中最长的键。
+    pub fn longest_prefix_of<'a>(&self, query: &'a str) -> Option<&'a str> {
+        let mut x = self.root.as_deref();
+        let mut len = None;
+        let mut chars = query.char_indices().peekable();
+        while let (Some(n), Some(&(i, c))) = (x, chars.peek()) {
+            if c < n.c {
+                x = n.left.as_deref();
+            } else if c > n.c {
+                x = n.right.as_deref();
+            } else {
+                chars.next();
+                if n.value.is_some() {
+                    len = Some(i + c.len_utf8());
+                }
+                x = n.mid.as_deref();
+            }
+        }
+        len.map(|d| &query[..d])
+    }
+
+    /// 获取键的最后一个字符对应的节点。
+    fn node(&self, key: &str) -> Option<&Node<V>> {
+        let mut x = self.root.as_deref();
+        let mut chars = key.chars().peekable();
+        while let Some(n) = x {
+            let c = *chars.peek()?;
+            if c < n.c {
+                x = n.left.as_deref();
+            } else if c > n.c {
+                x = n.right.as_deref();
+            } else {
+                chars.next();
+                if chars.peek().is_none() {
+                    return Some(n);
+                }
+                x = n.mid.as_deref();
+            }
+        }
+        None
+    }
+}
+
+/// 插入键值对，返回被替换的旧值。
+fn put_with<V>(x: &mut Link<V>, key: &[char], value: V) -> Option<V> {
+    let c = key[0];
+    let n = x.get_or_insert_with(|| Box::new(Node::new(c)));
+    if c < n.c {
+        put_with(&mut n.left, key, value)
+    } else if c > n.c {
+        put_with(&mut n.right, key, value)
+    } else if key.len() > 1 {
+        put_with(&mut n.mid, &key[1..], value)
+    } else {
+        n.value.replace(value)
+    }
+}
+
+impl<V> SymbolTable<String, V> for TST<V> {
+    fn get(&self, key: &String) -> Option<&V> {
+        TST::get(self, key)
+    }
+
+    fn put(&mut self, key: String, value: V) {
+        TST::put(self, &key, value);
+    }
+
+    fn delete(&mut self, key: &String) -> Option<V> {
+        TST::delete(self, key)
+    }
+
+    fn len(&self) -> usize {
+        self.n
+    }
+}
+
+impl<V> Default for TST<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Index<&str> for TST<V> {
+    type Output = V;
+
+    /// 获取键对应的值，键不存在时 panic。
+    fn index(&self, key: &str) -> &V {
+        self.get(key).expect("键不存在")
+    }
+}
+
+impl<K, V> Extend<(K, V)> for TST<V>
+where
+    K: AsRef<str>,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.put(k.as_ref(), v);
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for TST<V>
+where
+    K: AsRef<str>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut st = Self::new();
+        st.extend(iter);
+        st
+    }
+}
+
+impl<V> IntoIterator for TST<V> {
+    type Item = (String, V);
+    type IntoIter = std::vec::IntoIter<(String, V)>;
+
+    /// 按键的字符序取出所有键值对。
+    fn into_iter(self) -> Self::IntoIter {
+        let mut results = Vec::with_capacity(self.n);
+        collect_owned(self.root, &mut String::new(), &mut results);
+        results.into_iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a TST<V> {
+    type Item = (String, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// 键值对的迭代器，由 [`TST::iter`] 创建。
+pub struct Iter<'a, V> {
+    it: std::vec::IntoIter<(String, &'a V)>,
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (String, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.it.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+}
+
+/// 删除键，返回其值；同时回收不再需要的叶子节点。
+fn delete_from<V>(x: &mut Link<V>, key: &[char]) -> Option<V> {
+    let Some(n) = x else {
+        return None;
+    };
+
+    let c = key[0];
+    let deleted = if c < n.c {
+        delete_from(&mut n.left, key)
+    } else if c > n.c {
+        delete_from(&mut n.right, key)
+    } else if key.len() > 1 {
+        delete_from(&mut n.mid, &key[1..])
+    } else {
+        n.value.take()
+    };
+
+    if n.value.is_none() && n.left.is_none() && n.mid.is_none() && n.right.is_none() {
+        *x = None;
+    }
+    deleted
+}
+
+fn collect<'a, V>(x: Option<&'a Node<V>>, pre: &mut String, results: &mut Vec<(String, &'a V)>) {
+    let Some(n) = x else {
+        return;
+    };
+
+    collect(n.left.as_deref(), pre, results);
+    pre.push(n.c);
+    if let Some(v) = &n.value {
+        results.push((pre.clone(), v));
+    }
+    collect(n.mid.as_deref(), pre, results);
+    pre.pop();
+    collect(n.right.as_deref(), pre, results);
+}
+
+/// 按字符序取出所有键值对。
+fn collect_owned<V>(x: Link<V>, pre: &mut String, results: &mut Vec<(String, V)>) {
+    let Some(n) = x else {
+        return;
+    };
+
+    let Node {
+        c,
+        value,
+        left,
+        mid,
+        right,
+    } = *n;
+    collect_owned(left, pre, results);
+    pre.push(c);
+    if let Some(v) = value {
+        results.push((pre.clone(), v));
+    }
+    collect_owned(mid, pre, results);
+    pre.pop();
+    collect_owned(right, pre, results);
+}
+
+fn collect_match<V>(
+    x: Option<&Node<V>>,
+    pre: &mut String,
+    pat: &[char],
+    results: &mut Vec<String>,
+) {
+    let Some(n) = x else {
+        return;
+    };
+    let Some((&c, rest)) = pat.split_first() else {
+        return;
+    };
+
+    if c == '.' || c < n.c {
+        collect_match(n.left.as_deref(), pre, pat, results);
+    }
+    if c == '.' || c == n.c {
+        pre.push(n.c);
+        if rest.is_empty() {
+            if n.value.is_some() {
+                results.push(pre.clone());
+            }
+        } else {
+            collect_match(n.mid.as_deref(), pre, rest, results);
+        }
+        pre.pop();
+    }
+    if c == '.' || c > n.c {
+        collect_match(n.right.as_deref(), pre, pat, results);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn put_test() {
+        let mut t = create();
+        assert_eq!(7, t.len());
+        assert_eq!(Some(&7), t.get("shore"));
+        assert_eq!(Some(&6), t.get("sea"));
+        assert_eq!(None, t.get("shor"));
+        assert_eq!(None, t.get(""));
+
+        t.put("sea", 8);
+        assert_eq!(7, t.len());
+        assert_eq!(Some(&8), t.get("sea"));
+    }
+
+    #[test]
+    fn delete_test() {
+        let mut t = create();
+        assert_eq!(Some(0), t.delete("she"));
+        assert_eq!(None, t.delete("she"));
+        assert_eq!(6, t.len());
+        assert!(!t.contains("she"));
+        assert!(t.contains("shells"));
+
+        t.delete("shells");
+        assert!(t.contains("sells"));
+        assert!(t.contains("shore"));
+    }
+
+    #[test]
+    fn keys_test() {
+        let t = create();
+        assert_eq!(
+            vec!["by", "sea", "sells", "she", "shells", "shore", "the"],
+            t.keys()
+        );
+        assert_eq!(vec!["she", "shells", "shore"], t.keys_with_prefix("sh"));
+        assert!(t.keys_with_prefix("x").is_empty());
+    }
+
+    #[test]
+    fn keys_that_match_test() {
+        let t = create();
+        assert_eq!(vec!["she", "the"], t.keys_that_match(".he"));
+        assert_eq!(vec!["shells"], t.keys_that_match("s..l.s"));
+    }
+
+    #[test]
+    fn longest_prefix_of_test() {
+        let mut t = create();
+        assert_eq!(Some("shells"), t.longest_prefix_of("shellsort"));
+        assert_eq!(Some("she"), t.longest_prefix_of("shelter"));
+        assert_eq!(None, t.longest_prefix_of("quicksort"));
+
+        t.put("数据", 9);
+        assert_eq!(Some("数据"), t.longest_prefix_of("数据结构"));
+    }
+
+    fn create() -> TST<usize> {
+        let mut t = TST::new();
+        for (i, k) in ["she", "sells", "sea", "shells", "by", "the", "sea", "shore"]
+            .into_iter()
+            .enumerate()
+        {
+            t.put(k, i);
+        }
+        t
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut t: TST<_> = ["she", "sells", "sea"].into_iter().zip(0..).collect();
+        t.extend([("shells".to_string(), 3)]);
+        assert_eq!(2, t["sea"]);
+
+        let keys: Vec<_> = (&t).into_iter().map(|(k, _)| k).collect();
+        assert_eq!(["sea", "sells", "she", "shells"], &keys[..]);
+        let owned: Vec<_> = t.into_iter().collect();
+        assert_eq!(("sells".to_string(), 1), owned[1]);
+    }
+}
@@ -23,12 +23,17 @@ where
     }
 
     /// 获取二叉堆的迭代器。
-    pub fn iter(&self) -> BinaryHeapIter<T> {
+    pub fn iter(&self) -> BinaryHeapIter<'_, T> {
         BinaryHeapIter {
             it: self.buf.iter(),
         }
     }
 
+    /// 取出底层的 [`Vec`]。
+    pub fn into_vec(self) -> Vec<T> {
+        self.buf
+    }
+
     /// 获取二叉堆的元素数。
     pub fn len(&self) -> usize {
         self.buf.len()
@@ -0,0 +1,153 @@
+//! 索引优先队列（最大堆）。
+
+use std::cmp::Reverse;
+
+use super::index_min_pq::IndexMinPQ;
+
+/// 关联整数索引的最大优先队列，索引的取值范围为 `0..max_n`。
+pub struct IndexMaxPQ<K> {
+    pq: IndexMinPQ<Reverse<K>>, // 键取反后的最小堆。
+}
+
+impl<K> IndexMaxPQ<K>
+where
+    K: PartialOrd,
+{
+    /// 创建一个索引范围为 `0..max_n` 的空队列。
+    pub fn new(max_n: usize) -> Self {
+        Self {
+            pq: IndexMinPQ::new(max_n),
+        }
+    }
+
+    /// 获取元素数。
+    pub fn len(&self) -> usize {
+        self.pq.len()
+    }
+
+    /// 是否为空。
+    pub fn is_empty(&self) -> bool {
+        self.pq.is_empty()
+    }
+
+    /// 是否包含索引 i。
+    pub fn contains(&self, i: usize) -> bool {
+        self.pq.contains(i)
+    }
+
+    /// 插入索引 i 及其键。
+    ///
+    /// # Panics
+    ///
+    /// 索引 i 已经存在时 panic。
+    pub fn insert(&mut self, i: usize, key: K) {
+        self.pq.insert(i, Reverse(key));
+    }
+
+    /// 获取最大键对应的索引。
+    pub fn max_index(&self) -> Option<usize> {
+        self.pq.min_index()
+    }
+
+    /// 获取最大键。
+    pub fn max_key(&self) -> Option<&K> {
+        self.pq.min_key().map(|k| &k.0)
+    }
+
+    /// 获取索引 i 对应的键。
+    pub fn key_of(&self, i: usize) -> Option<&K> {
+        self.pq.key_of(i).map(|k| &k.0)
+    }
+
+    /// 删除最大键，并返回其索引。
+    pub fn delete_max(&mut self) -> Option<usize> {
+        self.pq.delete_min()
+    }
+
+    /// 删除索引 i 及其键，并返回该键。
+    pub fn delete(&mut self, i: usize) -> Option<K> {
+        self.pq.delete(i).map(|k| k.0)
+    }
+
+    /// 将索引 i 的键修改为 key。
+    ///
+    /// # Panics
+    ///
+    /// 索引 i 不存在时 panic。
+    pub fn change_key(&mut self, i: usize, key: K) {
+        self.pq.change_key(i, Reverse(key));
+    }
+
+    /// 将索引 i 的键增大为 key。
+    ///
+    /// # Panics
+    ///
+    /// 索引 i 不存在或 key 不大于原来的键时 panic。
+    pub fn increase_key(&mut self, i: usize, key: K) {
+        assert!(
+            self.key_of(i).is_some_and(|old| key > *old),
+            "新键必须大于索引 {i} 原来的键"
+        );
+        self.pq.change_key(i, Reverse(key));
+    }
+
+    /// 将索引 i 的键减小为 key。
+    ///
+    /// # Panics
+    ///
+    /// 索引 i 不存在或 key 不小于原来的键时 panic。
+    pub fn decrease_key(&mut self, i: usize, key: K) {
+        assert!(
+            self.key_of(i).is_some_and(|old| key < *old),
+            "新键必须小于索引 {i} 原来的键"
+        );
+        self.pq.change_key(i, Reverse(key));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delete_max_test() {
+        let mut q = create();
+        assert_eq!(Some(1), q.max_index());
+        assert_eq!(Some(&'t'), q.max_key());
+
+        let mut order = vec![];
+        while let Some(i) = q.delete_max() {
+            order.push(i);
+        }
+        assert_eq!(&[1, 2, 4, 0, 3], &order[..]);
+    }
+
+    #[test]
+    fn change_key_test() {
+        let mut q = create();
+        q.increase_key(3, 'z');
+        assert_eq!(Some(3), q.max_index());
+
+        q.decrease_key(3, 'b');
+        q.change_key(0, 'x');
+        assert_eq!(Some('x'), q.delete(0));
+        assert_eq!(Some(&'b'), q.key_of(3));
+        assert_eq!(Some(1), q.delete_max());
+        assert_eq!(3, q.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "新键必须大于")]
+    fn increase_key_panic_test() {
+        let mut q = create();
+        q.increase_key(1, 'a');
+    }
+
+    fn create() -> IndexMaxPQ<char> {
+        let mut q = IndexMaxPQ::new(8);
+        for (i, c) in ['e', 't', 'p', 'a', 'm'].into_iter().enumerate() {
+            q.insert(i, c);
+        }
+        q
+    }
+}
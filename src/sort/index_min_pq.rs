@@ -0,0 +1,247 @@
+//! 索引优先队列（最小堆）。
+
+use super::binary_heap::{left, parent};
+
+/// 关联整数索引的最小优先队列，索引的取值范围为 `0..max_n`。
+pub struct IndexMinPQ<K> {
+    pq: Vec<usize>,         // 二叉堆，元素为索引。
+    qp: Vec<Option<usize>>, // 索引在堆中的位置，即 qp[pq[i]] = i。
+    keys: Vec<Option<K>>,   // 索引对应的键。
+}
+
+impl<K> IndexMinPQ<K>
+where
+    K: PartialOrd,
+{
+    /// 创建一个索引范围为 `0..max_n` 的空队列。
+    pub fn new(max_n: usize) -> Self {
+        let mut keys = Vec::with_capacity(max_n);
+        keys.resize_with(max_n, || None);
+        Self {
+            pq: Vec::with_capacity(max_n),
+            qp: vec![None; max_n],
+            keys,
+        }
+    }
+
+    /// 获取元素数。
+    pub fn len(&self) -> usize {
+        self.pq.len()
+    }
+
+    /// 是否为空。
+    pub fn is_empty(&self) -> bool {
+        self.pq.is_empty()
+    }
+
+    /// 是否包含索引 i。
+    pub fn contains(&self, i: usize) -> bool {
+        self.qp[i].is_some()
+    }
+
+    /// 插入索引 i 及其键。
+    ///
+    /// # Panics
+    ///
+    /// 索引 i 已经存在时 panic。
+    pub fn insert(&mut self, i: usize, key: K) {
+        assert!(!self.contains(i), "索引 {i} 已在队列中");
+        let n = self.pq.len();
+        self.qp[i] = Some(n);
+        self.pq.push(i);
+        self.keys[i] = Some(key);
+        self.swim(n);
+    }
+
+    /// 获取最小键对应的索引。
+    pub fn min_index(&self) -> Option<usize> {
+        self.pq.first().copied()
+    }
+
+    /// 获取最小键。
+    pub fn min_key(&self) -> Option<&K> {
+        self.min_index().and_then(|i| self.key_of(i))
+    }
+
+    /// 获取索引 i 对应的键。
+    pub fn key_of(&self, i: usize) -> Option<&K> {
+        self.keys[i].as_ref()
+    }
+
+    /// 删除最小键，并返回其索引。
+    pub fn delete_min(&mut self) -> Option<usize> {
+        let i = self.min_index()?;
+        self.delete(i);
+        Some(i)
+    }
+
+    /// 删除索引 i 及其键，并返回该键。
+    pub fn delete(&mut self, i: usize) -> Option<K> {
+        let k = self.qp[i]?;
+        let last = self.pq.len() - 1;
+        self.exch(k, last);
+        self.pq.pop();
+        self.qp[i] = None;
+        if k < last {
+            self.swim(k);
+            self.sink(k);
+        }
+        self.keys[i].take()
+    }
+
+    /// 将索引 i 的键修改为 key。
+    ///
+    /// # Panics
+    ///
+    /// 索引 i 不存在时 panic。
+    pub fn change_key(&mut self, i: usize, key: K) {
+        let k = self.position(i);
+        self.keys[i] = Some(key);
+        self.swim(k);
+        self.sink(k);
+    }
+
+    /// 将索引 i 的键减小为 key。
+    ///
+    /// # Panics
+    ///
+    /// 索引 i 不存在或 key 不小于原来的键时 panic。
+    pub fn decrease_key(&mut self, i: usize, key: K) {
+        let k = self.position(i);
+        assert!(
+            self.keys[i].as_ref().is_some_and(|old| key < *old),
+            "新键必须小于索引 {i} 原来的键"
+        );
+        self.keys[i] = Some(key);
+        self.swim(k);
+    }
+
+    /// 将索引 i 的键增大为 key。
+    ///
+    /// # Panics
+    ///
+    /// 索引 i 不存在或 key 不大于原来的键时 panic。
+    pub fn increase_key(&mut self, i: usize, key: K) {
+        let k = self.position(i);
+        assert!(
+            self.keys[i].as_ref().is_some_and(|old| key > *old),
+            "新键必须大于索引 {i} 原来的键"
+        );
+        self.keys[i] = Some(key);
+        self.sink(k);
+    }
+
+    /// 索引 i 在堆中的位置。
+    fn position(&self, i: usize) -> usize {
+        self.qp[i].unwrap_or_else(|| panic!("索引 {i} 不在队列中"))
+    }
+
+    /// 堆中位置 i 的键是否大于位置 j 的键。
+    fn greater(&self, i: usize, j: usize) -> bool {
+        self.keys[self.pq[i]] > self.keys[self.pq[j]]
+    }
+
+    fn exch(&mut self, i: usize, j: usize) {
+        self.pq.swap(i, j);
+        self.qp[self.pq[i]] = Some(i);
+        self.qp[self.pq[j]] = Some(j);
+    }
+
+    /// 上浮。
+    fn swim(&mut self, mut k: usize) {
+        while k > 0 && self.greater(parent(k), k) {
+            self.exch(parent(k), k);
+            k = parent(k);
+        }
+    }
+
+    /// 下沉。
+    fn sink(&mut self, mut k: usize) {
+        let n = self.pq.len();
+        let mut j = left(k);
+        while j < n {
+            if j + 1 < n && self.greater(j, j + 1) {
+                j += 1;
+            }
+            if !self.greater(k, j) {
+                break;
+            }
+            self.exch(k, j);
+            k = j;
+            j = left(k);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_test() {
+        let q = create();
+        assert_eq!(5, q.len());
+        assert!(q.contains(4));
+        assert!(!q.contains(5));
+        assert_eq!(Some(3), q.min_index());
+        assert_eq!(Some(&'a'), q.min_key());
+        assert_eq!(Some(&'t'), q.key_of(1));
+    }
+
+    #[test]
+    fn delete_min_test() {
+        let mut q = create();
+        let mut order = vec![];
+        while let Some(i) = q.delete_min() {
+            order.push(i);
+        }
+        assert_eq!(&[3, 0, 4, 2, 1], &order[..]);
+        assert!(q.is_empty());
+    }
+
+    #[test]
+    fn change_key_test() {
+        let mut q = create();
+        q.decrease_key(1, 'b');
+        assert_eq!(Some(3), q.delete_min());
+        assert_eq!(Some(1), q.min_index());
+
+        q.increase_key(1, 'z');
+        q.change_key(0, 'y');
+        assert_eq!(Some(4), q.delete_min());
+        assert_eq!(Some(2), q.delete_min());
+        assert_eq!(Some(0), q.delete_min());
+        assert_eq!(Some(1), q.delete_min());
+    }
+
+    #[test]
+    fn delete_test() {
+        let mut q = create();
+        assert_eq!(Some('e'), q.delete(0));
+        assert_eq!(None, q.delete(0));
+        assert!(!q.contains(0));
+        assert_eq!(None, q.key_of(0));
+
+        q.insert(0, 'q');
+        let mut order = vec![];
+        while let Some(i) = q.delete_min() {
+            order.push(i);
+        }
+        assert_eq!(&[3, 4, 2, 0, 1], &order[..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "新键必须小于")]
+    fn decrease_key_panic_test() {
+        let mut q = create();
+        q.decrease_key(3, 'z');
+    }
+
+    fn create() -> IndexMinPQ<char> {
+        let mut q = IndexMinPQ::new(8);
+        for (i, c) in ['e', 't', 'p', 'a', 'm'].into_iter().enumerate() {
+            q.insert(i, c);
+        }
+        q
+    }
+}
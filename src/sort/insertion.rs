@@ -1,23 +1,34 @@
 //! 插入排序。
 
-fn sort(a: &mut [i32]) {
-    for i in 1..a.len() {
-        let mut j = i;
-        while j > 0 && a[j] < a[j - 1] {
-            a.swap(j, j - 1);
-            j -= 1;
+use std::cmp::Ordering;
+
+use super::Sorter;
+
+pub struct Insertion;
+
+impl Sorter for Insertion {
+    fn sort_by<T, F>(a: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        for i in 1..a.len() {
+            let mut j = i;
+            while j > 0 && compare(&a[j], &a[j - 1]) == Ordering::Less {
+                a.swap(j, j - 1);
+                j -= 1;
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::sort::{insertion::sort, is_sorted};
+    use crate::sort::{insertion::Insertion, is_sorted, Sorter};
 
     #[test]
     fn test() {
         let mut a = [8, 5, 7, 6, 4, 9, 3, 1];
-        sort(&mut a);
+        Insertion::sort(&mut a);
         assert!(is_sorted(&a));
     }
 }
@@ -0,0 +1,75 @@
+//! 键索引计数法。
+
+use super::permute;
+
+/// 按 `0..r` 范围内的整数键对 a 稳定排序。
+///
+/// # Panics
+///
+/// 键不小于 r 时 panic。
+pub fn key_indexed_counting<T, F>(a: &mut [T], r: usize, mut key: F)
+where
+    F: FnMut(&T) -> usize,
+{
+    let keys: Vec<usize> = a.iter().map(&mut key).collect();
+    let perm = sorted_positions(&keys, r);
+    permute(a, perm);
+}
+
+/// 计算按键稳定排序后的排列，即排序后第 i 个元素原来的位置。
+pub(crate) fn sorted_positions(keys: &[usize], r: usize) -> Vec<usize> {
+    // 计算出现频率。
+    let mut count = vec![0; r + 1];
+    for &k in keys {
+        assert!(k < r, "键 {k} 超出范围 0..{r}");
+        count[k + 1] += 1;
+    }
+    // 将频率转换为索引。
+    for i in 0..r {
+        count[i + 1] += count[i];
+    }
+    // 将元素分类。
+    let mut perm = vec![0; keys.len()];
+    for (i, &k) in keys.iter().enumerate() {
+        perm[count[k]] = i;
+        count[k] += 1;
+    }
+    perm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        // 学生姓名与所在组号。
+        let mut a = [
+            ("anderson", 2),
+            ("brown", 3),
+            ("davis", 3),
+            ("garcia", 4),
+            ("harris", 1),
+            ("jackson", 3),
+            ("johnson", 4),
+            ("jones", 3),
+            ("martin", 1),
+            ("martinez", 2),
+        ];
+        key_indexed_counting(&mut a, 5, |x| x.1);
+        let names: Vec<&str> = a.iter().map(|x| x.0).collect();
+        assert_eq!(
+            [
+                "harris", "martin", "anderson", "martinez", "brown", "davis", "jackson", "jones",
+                "garcia", "johnson"
+            ],
+            &names[..]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "超出范围")]
+    fn out_of_range_test() {
+        key_indexed_counting(&mut [3, 1], 3, |&x| x);
+    }
+}
@@ -0,0 +1,66 @@
+//! 低位优先的字符串排序。
+
+use super::{key_indexed::sorted_positions, permute};
+
+const R: usize = 256; // 字母表的大小，按字节划分。
+
+/// 低位优先（LSD）的字符串排序，适用于定长的键。
+#[allow(clippy::upper_case_acronyms)]
+pub struct LSD;
+
+impl LSD {
+    /// 按前 w 个字节对 a 稳定排序。
+    ///
+    /// # Panics
+    ///
+    /// 存在长度小于 w 的键时 panic。
+    pub fn sort<T>(a: &mut [T], w: usize)
+    where
+        T: AsRef<[u8]>,
+    {
+        assert!(
+            a.iter().all(|s| s.as_ref().len() >= w),
+            "键的长度必须不小于 {w}"
+        );
+        // 从右往左，按第 d 个字节进行键索引计数排序。
+        for d in (0..w).rev() {
+            let keys: Vec<usize> = a.iter().map(|s| s.as_ref()[d] as usize).collect();
+            permute(a, sorted_positions(&keys, R));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::is_sorted;
+
+    #[test]
+    fn test() {
+        let mut a: Vec<String> = [
+            "4PGC938", "2IYE230", "3CIO720", "1ICK750", "1OHV845", "4JZY524", "1ICK750", "3CIO720",
+            "1OHV845", "1OHV845", "2RLA629", "2RLA629", "3ATW723",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        LSD::sort(&mut a, 7);
+        assert!(is_sorted(&a));
+        assert_eq!("1ICK750", a[0]);
+        assert_eq!("4PGC938", a[12]);
+    }
+
+    #[test]
+    fn prefix_test() {
+        // 只按第一个字节排序，其余保持原有的相对顺序。
+        let mut a = ["b2", "a9", "b1x", "a1"];
+        LSD::sort(&mut a, 1);
+        assert_eq!(["a9", "a1", "b2", "b1x"], a);
+    }
+
+    #[test]
+    #[should_panic(expected = "键的长度必须不小于")]
+    fn short_key_test() {
+        LSD::sort(&mut ["abc", "ab"], 3);
+    }
+}
@@ -1,3 +1,7 @@
+//! 基于二叉堆的最大优先队列。
+
+use std::{slice, vec};
+
 use super::binary_heap::BinaryHeap;
 
 /// 优先队列。
@@ -10,23 +14,89 @@ where
     T: Default + Clone + PartialEq + PartialOrd,
 {
     /// 创建一个指定大小的优先队列。
-    fn new(capacity: usize) -> Self {
+    pub fn new(capacity: usize) -> Self {
         Self {
             h: BinaryHeap::new(capacity),
         }
     }
 
     /// 插入新元素。
-    fn insert(&mut self, item: T) {
+    pub fn insert(&mut self, item: T) {
         self.h.push(item);
         self.h.swim(self.h.len() - 1);
     }
 
-    /// 删除并返回最大元素。
-    fn delete_max(&mut self) -> T {
+    /// 删除并返回最大元素，队列为空时返回 [`None`]。
+    pub fn delete_max(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
         let max = self.h.pop();
-        self.h.swim(0);
-        max
+        self.h.sink(0);
+        Some(max)
+    }
+
+    /// 获取元素数。
+    pub fn len(&self) -> usize {
+        self.h.len()
+    }
+
+    /// 是否为空。
+    pub fn is_empty(&self) -> bool {
+        self.h.len() == 0
+    }
+
+    /// 获取所有元素的迭代器，按堆中的顺序排列。
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.h.as_slice().iter()
+    }
+}
+
+impl<T> Extend<T> for MaxPQ<T>
+where
+    T: Default + Clone + PartialEq + PartialOrd,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for MaxPQ<T>
+where
+    T: Default + Clone + PartialEq + PartialOrd,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut q = Self::new(0);
+        q.extend(iter);
+        q
+    }
+}
+
+impl<T> IntoIterator for MaxPQ<T>
+where
+    T: Default + Clone + PartialEq + PartialOrd,
+{
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    /// 按堆中的顺序取出所有元素。
+    fn into_iter(self) -> Self::IntoIter {
+        self.h.into_vec().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a MaxPQ<T>
+where
+    T: Default + Clone + PartialEq + PartialOrd,
+{
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
@@ -55,12 +125,40 @@ mod tests {
         q.insert(4);
         q.insert(8);
 
-        let v = q.delete_max();
-        assert_eq!(8, v);
+        assert_eq!(Some(8), q.delete_max());
 
         let mut iter = q.h.iter();
         assert_eq!(Some(&4), iter.next());
         assert_eq!(Some(&2), iter.next());
         assert_eq!(None, iter.next());
     }
+
+    #[test]
+    fn empty_test() {
+        let mut q: MaxPQ<i32> = MaxPQ::new(0);
+        assert_eq!(None, q.delete_max());
+        q.insert(1);
+        assert_eq!(Some(1), q.delete_max());
+        assert_eq!(None, q.delete_max());
+        assert!(q.is_empty());
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut q: MaxPQ<_> = [5, 3, 8].into_iter().collect();
+        q.extend([1, 9]);
+        assert_eq!(5, q.len());
+        assert_eq!(Some(&9), q.iter().next());
+        assert_eq!(26, (&q).into_iter().sum::<i32>());
+
+        let mut sorted = vec![];
+        while let Some(max) = q.delete_max() {
+            sorted.push(max);
+        }
+        assert_eq!(vec![9, 8, 5, 3, 1], sorted);
+
+        let mut items: Vec<_> = MaxPQ::from_iter([2, 7]).into_iter().collect();
+        items.sort();
+        assert_eq!(vec![2, 7], items);
+    }
 }
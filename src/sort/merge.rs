@@ -1,37 +1,61 @@
 //! 自顶向下的归并排序。
 
-use std::usize;
+use std::cmp::Ordering;
 
-fn sort(a: &mut [i32]) {
-    let mut aux = vec![0; a.len()];
-    sort_internal(&mut aux[..], a, 0, a.len());
+use super::{permute, Sorter};
+
+pub struct Merge;
+
+impl Sorter for Merge {
+    fn sort_by<T, F>(a: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // 归并索引而非元素本身，从而不要求 T: Clone。
+        let mut idx: Vec<usize> = (0..a.len()).collect();
+        let mut aux = vec![0; a.len()];
+        let mut less = |i: usize, j: usize| compare(&a[i], &a[j]) == Ordering::Less;
+        sort_internal(&mut aux, &mut idx, 0, a.len(), &mut less);
+        permute(a, idx);
+    }
 }
 
-fn sort_internal(aux: &mut [i32], a: &mut [i32], lo: usize, hi: usize) {
+fn sort_internal<F>(aux: &mut [usize], a: &mut [usize], lo: usize, hi: usize, less: &mut F)
+where
+    F: FnMut(usize, usize) -> bool,
+{
     if lo + 1 >= hi {
         return;
     }
     let mid = lo + (hi - lo) / 2;
-    sort_internal(aux, a, lo, mid);
-    sort_internal(aux, a, mid, hi);
-    merge(aux, a, lo, mid, hi);
+    sort_internal(aux, a, lo, mid, less);
+    sort_internal(aux, a, mid, hi, less);
+    merge(aux, a, lo, mid, hi, less);
 }
 
-fn merge(aux: &mut [i32], a: &mut [i32], lo: usize, mid: usize, hi: usize) {
+/// 原地归并 a[lo..mid] 和 a[mid..hi]。
+pub(crate) fn merge<F>(
+    aux: &mut [usize],
+    a: &mut [usize],
+    lo: usize,
+    mid: usize,
+    hi: usize,
+    less: &mut F,
+) where
+    F: FnMut(usize, usize) -> bool,
+{
     let mut i = lo;
     let mut j = mid;
-    for k in lo..hi {
-        aux[k] = a[k];
-    }
+    aux[lo..hi].copy_from_slice(&a[lo..hi]);
 
-    for k in lo..hi {
-        a[k] = if i >= mid {
+    for x in a[lo..hi].iter_mut() {
+        *x = if i >= mid {
             j += 1;
             aux[j - 1]
         } else if j >= hi {
             i += 1;
             aux[i - 1]
-        } else if aux[j] < aux[i] {
+        } else if less(aux[j], aux[i]) {
             j += 1;
             aux[j - 1]
         } else {
@@ -43,12 +67,12 @@ fn merge(aux: &mut [i32], a: &mut [i32], lo: usize, mid: usize, hi: usize) {
 
 #[cfg(test)]
 mod tests {
-    use crate::sort::{is_sorted, merge::sort};
+    use crate::sort::{is_sorted, merge::Merge, Sorter};
 
     #[test]
     fn test() {
         let mut a = [8, 5, 7, 6, 4, 9, 3, 1];
-        sort(&mut a);
+        Merge::sort(&mut a);
         assert!(is_sorted(&a));
     }
 }
@@ -1,3 +1,7 @@
+//! 基于二叉堆的最小优先队列。
+
+use std::{slice, vec};
+
 use super::binary_heap::*;
 
 /// 最小堆。
@@ -25,6 +29,10 @@ where
 
     /// 删除并返回最小元素。
     pub fn delete_min(&mut self) -> Option<T> {
+        if self.h.is_empty() {
+            return None;
+        }
+
         let i = self.h.len() - 1;
         self.h.swap(0, i);
 
@@ -33,17 +41,63 @@ where
         min
     }
 
-    /// 是否符合指定的条件。
-    pub fn get_mut<F>(&mut self, f: F) -> Option<&mut T>
-    where
-        F: Fn(&T) -> bool,
-    {
-        for v in self.h.iter_mut() {
-            if f(v) {
-                return Some(v);
-            }
+    /// 获取元素数。
+    pub fn len(&self) -> usize {
+        self.h.len()
+    }
+
+    /// 是否为空。
+    pub fn is_empty(&self) -> bool {
+        self.h.is_empty()
+    }
+
+    /// 获取所有元素的迭代器，按堆中的顺序排列。
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.h.iter()
+    }
+}
+
+impl<T> Extend<T> for MinPQ<T>
+where
+    T: PartialEq + PartialOrd,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
         }
-        None
+    }
+}
+
+impl<T> FromIterator<T> for MinPQ<T>
+where
+    T: PartialEq + PartialOrd,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut q = Self::new(0);
+        q.extend(iter);
+        q
+    }
+}
+
+impl<T> IntoIterator for MinPQ<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    /// 按堆中的顺序取出所有元素。
+    fn into_iter(self) -> Self::IntoIter {
+        self.h.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a MinPQ<T>
+where
+    T: PartialEq + PartialOrd,
+{
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
@@ -113,5 +167,23 @@ mod tests {
         let v = q.delete_min();
         assert_eq!(Some(2), v);
         assert_eq!(&[4, 8], &q.h[..]);
+
+        q.delete_min();
+        q.delete_min();
+        assert!(q.is_empty());
+        assert_eq!(None, q.delete_min());
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut q: MinPQ<_> = [5, 3, 8].into_iter().collect();
+        q.extend([1, 9]);
+        assert_eq!(5, q.len());
+        assert_eq!(Some(&1), q.iter().next());
+        assert_eq!(26, (&q).into_iter().sum::<i32>());
+
+        let mut items: Vec<_> = q.into_iter().collect();
+        items.sort();
+        assert_eq!(vec![1, 3, 5, 8, 9], items);
     }
 }
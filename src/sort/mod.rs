@@ -1,29 +1,96 @@
 //! 排序算法。
 
-mod selection;
-mod insertion;
-mod shell;
-mod merge;
-mod merge_bu;
-mod quick;
-mod quick_3_way;
+use std::cmp::Ordering;
+
+pub mod selection;
+pub mod insertion;
+pub mod shell;
+pub mod merge;
+pub mod merge_bu;
+pub mod quick;
+pub mod quick_3_way;
 mod binary_heap;
 mod heap_sort;
-mod max_pq;
-pub(crate) mod min_pq;
+pub mod max_pq;
+pub mod min_pq;
+pub mod index_min_pq;
+pub mod index_max_pq;
+pub mod key_indexed;
+pub mod lsd;
+pub mod msd;
+pub mod quick_3_string;
+
+/// 排序算法的统一入口。
+///
+/// 实现者只需提供 [`Sorter::sort_by`]，其余方法均基于它实现。
+pub trait Sorter {
+    /// 按指定的比较函数排序。
+    fn sort_by<T, F>(a: &mut [T], compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// 按元素的自然顺序排序。
+    fn sort<T>(a: &mut [T])
+    where
+        T: Ord,
+    {
+        Self::sort_by(a, T::cmp);
+    }
+
+    /// 按元素提取出的键排序。
+    fn sort_by_key<T, K, F>(a: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        Self::sort_by(a, |x, y| f(x).cmp(&f(y)));
+    }
+}
+
+/// 是否按自然顺序有序。
+pub fn is_sorted<T>(a: &[T]) -> bool
+where
+    T: Ord,
+{
+    is_sorted_by(a, T::cmp)
+}
+
+/// 是否按指定的比较函数有序。
+pub fn is_sorted_by<T, F>(a: &[T], mut compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    a.windows(2)
+        .all(|w| compare(&w[0], &w[1]) != Ordering::Greater)
+}
+
+/// 是否按元素提取出的键有序。
+pub fn is_sorted_by_key<T, K, F>(a: &[T], mut f: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    is_sorted_by(a, |x, y| f(x).cmp(&f(y)))
+}
 
-fn is_sorted(a: &[i32]) -> bool {
-    for i in 1..a.len() {
-        if a[i - 1] > a[i] {
-            return false;
+/// 按索引排列 a，使 a[i] 变为原来的 a[perm[i]]。
+pub(crate) fn permute<T>(a: &mut [T], mut perm: Vec<usize>) {
+    for i in 0..a.len() {
+        // 沿置换环逐个归位。
+        let mut j = i;
+        while perm[j] != i {
+            let k = perm[j];
+            a.swap(j, k);
+            perm[j] = j;
+            j = k;
         }
+        perm[j] = j;
     }
-    true
 }
 
 #[cfg(test)]
 mod tests {
-    use super::is_sorted;
+    use super::*;
 
     #[test]
     fn test() {
@@ -33,4 +100,59 @@ mod tests {
         let b = [2, 1, 3];
         assert!(!is_sorted(&b));
     }
+
+    #[test]
+    fn is_sorted_by_test() {
+        let a = [3, 2, 1];
+        assert!(is_sorted_by(&a, |x, y| y.cmp(x)));
+        assert!(is_sorted_by_key(&a, |x| -x));
+    }
+
+    #[test]
+    fn permute_test() {
+        let mut a = ['a', 'b', 'c', 'd'];
+        permute(&mut a, vec![2, 0, 3, 1]);
+        assert_eq!(['c', 'a', 'd', 'b'], a);
+    }
+
+    /// 姓名与年龄。
+    fn records() -> Vec<(&'static str, u32)> {
+        vec![("carol", 31), ("alice", 25), ("dave", 25), ("bob", 40)]
+    }
+
+    fn check<S: Sorter>() {
+        let mut a = [8, 5, 7, 6, 4, 9, 3, 1];
+        S::sort_by(&mut a, |x, y| y.cmp(x));
+        assert!(is_sorted_by(&a, |x, y| y.cmp(x)));
+
+        let mut r = records();
+        S::sort_by_key(&mut r, |r| r.1);
+        assert!(is_sorted_by_key(&r, |r| r.1));
+
+        S::sort_by(&mut r, |x, y| x.0.cmp(y.0));
+        let names: Vec<&str> = r.iter().map(|r| r.0).collect();
+        assert_eq!(["alice", "bob", "carol", "dave"], &names[..]);
+
+        let mut dup = [3, 1, 3, 2, 1, 3, 2, 2, 1, 3];
+        S::sort(&mut dup);
+        assert_eq!([1, 1, 1, 2, 2, 2, 3, 3, 3, 3], dup);
+
+        let mut equal = [5; 16];
+        S::sort(&mut equal);
+        assert_eq!([5; 16], equal);
+
+        let mut empty: [i32; 0] = [];
+        S::sort(&mut empty);
+    }
+
+    #[test]
+    fn sorter_test() {
+        check::<selection::Selection>();
+        check::<insertion::Insertion>();
+        check::<shell::Shell>();
+        check::<merge::Merge>();
+        check::<merge_bu::MergeBU>();
+        check::<quick::Quick>();
+        check::<quick_3_way::Quick3Way>();
+    }
 }
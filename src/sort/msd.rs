@@ -0,0 +1,97 @@
+//! 高位优先的字符串排序。
+
+use super::{key_indexed::sorted_positions, permute};
+
+const R: usize = 256; // 字母表的大小，按字节划分。
+const CUTOFF: usize = 15; // 小数组切换到插入排序的阈值。
+
+/// 高位优先（MSD）的字符串排序，适用于变长的键。
+#[allow(clippy::upper_case_acronyms)]
+pub struct MSD;
+
+impl MSD {
+    /// 按字节序对 a 排序。
+    pub fn sort<T>(a: &mut [T])
+    where
+        T: AsRef<[u8]>,
+    {
+        sort_internal(a, 0);
+    }
+}
+
+/// 第 d 个字节加一，字符串结尾为 0，使较短的字符串排在前面。
+fn char_at(s: &[u8], d: usize) -> usize {
+    s.get(d).map_or(0, |&b| b as usize + 1)
+}
+
+/// 对前 d 个字节都相同的 a 按第 d 个字节及以后排序。
+fn sort_internal<T>(a: &mut [T], d: usize)
+where
+    T: AsRef<[u8]>,
+{
+    if a.len() <= CUTOFF {
+        insertion(a, d);
+        return;
+    }
+
+    let keys: Vec<usize> = a.iter().map(|s| char_at(s.as_ref(), d)).collect();
+    permute(a, sorted_positions(&keys, R + 1));
+
+    // 递归地以每个字节为键排序，已到结尾的字符串无需再排。
+    let mut count = [0; R + 1];
+    for k in keys {
+        count[k] += 1;
+    }
+    let mut lo = count[0];
+    for &n in &count[1..] {
+        sort_internal(&mut a[lo..lo + n], d + 1);
+        lo += n;
+    }
+}
+
+/// 从第 d 个字节开始比较的插入排序。
+fn insertion<T>(a: &mut [T], d: usize)
+where
+    T: AsRef<[u8]>,
+{
+    for i in 1..a.len() {
+        let mut j = i;
+        while j > 0 && a[j].as_ref()[d..] < a[j - 1].as_ref()[d..] {
+            a.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::is_sorted;
+
+    #[test]
+    fn test() {
+        let mut a = words();
+        MSD::sort(&mut a);
+        assert!(is_sorted(&a));
+        assert_eq!(["are", "by", "sea"], a[..3]);
+    }
+
+    #[test]
+    fn large_test() {
+        // 超过阈值，触发按字节分组。
+        let mut a: Vec<String> = (0..1000).map(|i| ((i * 7919) % 1000).to_string()).collect();
+        MSD::sort(&mut a);
+        assert!(is_sorted(&a));
+        assert_eq!("0", a[0]);
+        assert_eq!("1", a[1]);
+        assert_eq!("10", a[2]);
+        assert_eq!("100", a[3]);
+    }
+
+    fn words() -> Vec<String> {
+        "she sells seashells by the sea shore the shells she sells are surely seashells"
+            .split(' ')
+            .map(String::from)
+            .collect()
+    }
+}
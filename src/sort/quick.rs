@@ -1,37 +1,57 @@
 //! 快速排序。
 
-fn sort(a: &mut [i32]) {
-    sort_internal(a);
+use std::cmp::Ordering;
+
+use super::Sorter;
+
+pub struct Quick;
+
+impl Sorter for Quick {
+    fn sort_by<T, F>(a: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort_internal(a, &mut compare);
+    }
 }
 
-fn sort_internal(a: &mut [i32]) {
+fn sort_internal<T, F>(a: &mut [T], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
     if a.len() < 2 {
         return;
     }
-    let j = partition(a);
-    sort_internal(&mut a[..j]);
-    sort_internal(&mut a[j + 1..]); // 排序右半边，一定不要包含切分元素。
+    let j = partition(a, compare);
+    sort_internal(&mut a[..j], compare);
+    sort_internal(&mut a[j + 1..], compare); // 排序右半边，一定不要包含切分元素。
 }
 
-fn partition(a: &mut [i32]) -> usize {
+fn partition<T, F>(a: &mut [T], compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
     debug_assert!(a.len() > 1);
 
     let mut i = 1;
     let mut j = a.len() - 1;
-    let v = a[0]; // 切分元素。
+    // 切分元素 a[0] 在切分结束前保持不动。
     loop {
         // i 加到 a.len() - 1 即可。
-        while i < j && a[i] < v {
+        while i < j && compare(&a[i], &a[0]) == Ordering::Less {
             i += 1;
         }
         // 切分元素本身即哨兵，因此 j 不可能越界。
-        while a[j] > v {
+        while compare(&a[j], &a[0]) == Ordering::Greater {
             j -= 1;
         }
         if i >= j {
             break;
         }
         a.swap(i, j);
+        // 交换后两边的元素都已就位，必须前进，否则 a[i] 与 a[j] 都等于切分元素时会死循环。
+        i += 1;
+        j -= 1;
     }
     a.swap(0, j);
     j
@@ -39,12 +59,23 @@ fn partition(a: &mut [i32]) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::sort::{is_sorted, quick::sort};
+    use crate::sort::{is_sorted, quick::Quick, Sorter};
 
     #[test]
     fn test() {
         let mut a = [8, 5, 7, 6, 4, 9, 3, 1];
-        sort(&mut a);
+        Quick::sort(&mut a);
         assert!(is_sorted(&a));
     }
+
+    #[test]
+    fn duplicate_test() {
+        let mut a = [5, 5, 5];
+        Quick::sort(&mut a);
+        assert_eq!([5, 5, 5], a);
+
+        let mut b = [2, 1, 2, 1, 2, 1, 2];
+        Quick::sort(&mut b);
+        assert_eq!([1, 1, 1, 2, 2, 2, 2], b);
+    }
 }
@@ -0,0 +1,79 @@
+//! 三向字符串快速排序。
+
+use std::cmp::Ordering;
+
+/// 三向字符串快速排序，适用于含有较长公共前缀的键。
+pub struct Quick3String;
+
+impl Quick3String {
+    /// 按字节序对 a 排序。
+    pub fn sort<T>(a: &mut [T])
+    where
+        T: AsRef<[u8]>,
+    {
+        sort_internal(a, 0);
+    }
+}
+
+/// 第 d 个字节，字符串结尾为 None，使较短的字符串排在前面。
+fn char_at(s: &[u8], d: usize) -> Option<u8> {
+    s.get(d).copied()
+}
+
+/// 对前 d 个字节都相同的 a 按第 d 个字节切分。
+fn sort_internal<T>(a: &mut [T], d: usize)
+where
+    T: AsRef<[u8]>,
+{
+    if a.len() < 2 {
+        return;
+    }
+    let mut lt = 0;
+    let mut i = 1;
+    let mut gt = a.len() - 1;
+    let v = char_at(a[0].as_ref(), d);
+    while i <= gt {
+        match char_at(a[i].as_ref(), d).cmp(&v) {
+            Ordering::Less => {
+                a.swap(lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                a.swap(i, gt);
+                gt -= 1;
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+    sort_internal(&mut a[..lt], d);
+    // 切分字节相同的部分继续比较下一个字节，已到结尾时全部相等。
+    if v.is_some() {
+        sort_internal(&mut a[lt..=gt], d + 1);
+    }
+    sort_internal(&mut a[gt + 1..], d);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::is_sorted;
+
+    #[test]
+    fn test() {
+        let mut a = words();
+        Quick3String::sort(&mut a);
+        assert!(is_sorted(&a));
+
+        let mut b = [&b"ab\xff"[..], b"", b"ab", b"a\x00"];
+        Quick3String::sort(&mut b);
+        assert_eq!([&b""[..], b"a\x00", b"ab", b"ab\xff"], b);
+    }
+
+    fn words() -> Vec<String> {
+        "she sells seashells by the sea shore the shells she sells are surely seashells"
+            .split(' ')
+            .map(String::from)
+            .collect()
+    }
+}
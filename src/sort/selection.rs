@@ -1,25 +1,36 @@
 //! 选择排序。
 
-fn sort(a: &mut [i32]) {
-    for i in 0..a.len() {
-        let mut min = i;
-        for j in i + 1..a.len() {
-            if a[j] < a[min] {
-                min = j;
+use std::cmp::Ordering;
+
+use super::Sorter;
+
+pub struct Selection;
+
+impl Sorter for Selection {
+    fn sort_by<T, F>(a: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        for i in 0..a.len() {
+            let mut min = i;
+            for j in i + 1..a.len() {
+                if compare(&a[j], &a[min]) == Ordering::Less {
+                    min = j;
+                }
             }
+            a.swap(i, min);
         }
-        a.swap(i, min);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::sort::{is_sorted, selection::sort};
-    
+    use crate::sort::{is_sorted, selection::Selection, Sorter};
+
     #[test]
     fn test() {
         let mut a = [8, 5, 7, 6, 4, 9, 3, 1];
-        sort(&mut a);
+        Selection::sort(&mut a);
         assert!(is_sorted(&a));
     }
 }
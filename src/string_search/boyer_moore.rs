@@ -0,0 +1,100 @@
+//! Boyer-Moore 子字符串查找算法（启发式地处理不匹配的字符）。
+
+use super::{check_pattern, Searcher};
+
+const R: usize = 256; // 字母表的大小。
+
+pub struct BoyerMoore {
+    pat: Vec<u8>,              // 模式。
+    right: [Option<usize>; R], // 字节在模式中出现的最右位置。
+}
+
+impl BoyerMoore {
+    /// 由模式计算跳跃表。
+    ///
+    /// # Panics
+    ///
+    /// 模式为空时 panic。
+    pub fn new(pat: impl AsRef<[u8]>) -> Self {
+        let pat = pat.as_ref().to_vec();
+        check_pattern(&pat);
+
+        let mut right = [None; R];
+        for (j, &c) in pat.iter().enumerate() {
+            right[c as usize] = Some(j);
+        }
+        Self { pat, right }
+    }
+
+    /// 在 text 中查找，每找到一个匹配就以它的起始位置调用 f，f 返回 false 时停止。
+    fn run<F>(&self, text: &[u8], mut f: F)
+    where
+        F: FnMut(usize) -> bool,
+    {
+        let m = self.pat.len();
+        let n = text.len();
+        let mut i = 0;
+        while i + m <= n {
+            // 从右向左比较，模式和文本在位置 i + j 上是否匹配。
+            let mismatch = (0..m).rev().find(|&j| self.pat[j] != text[i + j]);
+            match mismatch {
+                None => {
+                    // 找到匹配，右移一位继续查找重叠的匹配。
+                    if !f(i) {
+                        return;
+                    }
+                    i += 1;
+                }
+                Some(j) => {
+                    // 将文本中不匹配的字节与它在模式中出现的最右位置对齐。
+                    let skip = match self.right[text[i + j] as usize] {
+                        Some(r) if r < j => j - r,
+                        Some(_) => 1,
+                        None => j + 1,
+                    };
+                    i += skip;
+                }
+            }
+        }
+    }
+}
+
+impl Searcher for BoyerMoore {
+    fn pattern_len(&self) -> usize {
+        self.pat.len()
+    }
+
+    fn find(&self, text: &[u8]) -> Option<usize> {
+        let mut found = None;
+        self.run(text, |i| {
+            found = Some(i);
+            false
+        });
+        found
+    }
+
+    fn find_all(&self, text: &[u8]) -> Vec<usize> {
+        let mut results = vec![];
+        self.run(text, |i| {
+            results.push(i);
+            true
+        });
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_test() {
+        let b = BoyerMoore::new("NEEDLE");
+        assert_eq!(Some(15), b.find(b"FINDINAHAYSTACKNEEDLEINA"));
+        assert_eq!(vec![0, 1, 2], BoyerMoore::new("aa").find_all(b"aaaa"));
+        assert_eq!(
+            (0..98).collect::<Vec<_>>(),
+            BoyerMoore::new("aaa").find_all(&[b'a'; 100])
+        );
+    }
+}
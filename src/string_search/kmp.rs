@@ -0,0 +1,144 @@
+//! Knuth-Morris-Pratt 子字符串查找算法（基于确定有限状态自动机）。
+
+use std::io::{self, Read};
+
+use super::{check_pattern, Searcher, CHUNK};
+
+const R: usize = 256; // 字母表的大小。
+
+#[allow(clippy::upper_case_acronyms)]
+pub struct KMP {
+    m: usize,             // 模式的长度。
+    dfa: Vec<[usize; R]>, // dfa[j][c] 为状态 j 读入字节 c 后的状态。
+}
+
+impl KMP {
+    /// 由模式构造 DFA。
+    ///
+    /// 状态 m 表示完成一次匹配，其转换与重启状态相同，以便继续查找重叠的匹配。
+    ///
+    /// # Panics
+    ///
+    /// 模式为空时 panic。
+    pub fn new(pat: impl AsRef<[u8]>) -> Self {
+        let pat = pat.as_ref();
+        check_pattern(pat);
+
+        let m = pat.len();
+        let mut dfa = vec![[0; R]; m + 1];
+        dfa[0][pat[0] as usize] = 1;
+        let mut x = 0; // 重启状态。
+        for j in 1..m {
+            dfa[j] = dfa[x]; // 复制匹配失败情况下的值。
+            dfa[j][pat[j] as usize] = j + 1; // 设置匹配成功情况下的值。
+            x = dfa[x][pat[j] as usize]; // 更新重启状态。
+        }
+        dfa[m] = dfa[x];
+
+        Self { m, dfa }
+    }
+
+    /// 从状态 j 开始读入 text，每完成一次匹配就以匹配的结束位置调用 f，
+    /// f 返回 false 时停止，返回最终的状态。
+    fn run<F>(&self, mut j: usize, text: &[u8], mut f: F) -> usize
+    where
+        F: FnMut(usize) -> bool,
+    {
+        for (i, &c) in text.iter().enumerate() {
+            j = self.dfa[j][c as usize];
+            if j == self.m && !f(i + 1) {
+                break;
+            }
+        }
+        j
+    }
+
+    /// 自动机逐字节读入，无需回退，也无需缓存输入。
+    fn stream<R>(&self, mut reader: R, first_only: bool) -> io::Result<Vec<usize>>
+    where
+        R: Read,
+    {
+        let mut results = vec![];
+        let mut chunk = [0; CHUNK];
+        let mut offset = 0; // chunk[0] 在整个输入中的位置。
+        let mut j = 0;
+        loop {
+            let n = match reader.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            // 匹配可能始于上一块，因此以结束位置推算起始位置。
+            j = self.run(j, &chunk[..n], |end| {
+                results.push(offset + end - self.m);
+                !first_only
+            });
+            if first_only && !results.is_empty() {
+                break;
+            }
+            offset += n;
+        }
+        Ok(results)
+    }
+}
+
+impl Searcher for KMP {
+    fn pattern_len(&self) -> usize {
+        self.m
+    }
+
+    fn find(&self, text: &[u8]) -> Option<usize> {
+        let mut found = None;
+        self.run(0, text, |end| {
+            found = Some(end - self.m);
+            false
+        });
+        found
+    }
+
+    fn find_all(&self, text: &[u8]) -> Vec<usize> {
+        let mut results = vec![];
+        self.run(0, text, |end| {
+            results.push(end - self.m);
+            true
+        });
+        results
+    }
+
+    fn find_in<R>(&self, reader: R) -> io::Result<Option<usize>>
+    where
+        R: Read,
+    {
+        self.stream(reader, true).map(|r| r.first().copied())
+    }
+
+    fn find_all_in<R>(&self, reader: R) -> io::Result<Vec<usize>>
+    where
+        R: Read,
+    {
+        self.stream(reader, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dfa_test() {
+        let k = KMP::new("ABABAC");
+        let a: Vec<usize> = k.dfa.iter().map(|s| s[b'A' as usize]).collect();
+        let b: Vec<usize> = k.dfa.iter().map(|s| s[b'B' as usize]).collect();
+        let c: Vec<usize> = k.dfa.iter().map(|s| s[b'C' as usize]).collect();
+        assert_eq!(&a[..6], &[1, 1, 3, 1, 5, 1]);
+        assert_eq!(&b[..6], &[0, 2, 0, 4, 0, 4]);
+        assert_eq!(&c[..6], &[0, 0, 0, 0, 0, 6]);
+    }
+
+    #[test]
+    fn find_all_test() {
+        let k = KMP::new("aa");
+        assert_eq!(vec![0, 1, 2], k.find_all(b"aaaa"));
+    }
+}
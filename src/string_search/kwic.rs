@@ -0,0 +1,72 @@
+//! 上下文中的关键词（KWIC）索引。
+
+use super::suffix_array::SuffixArray;
+
+/// 基于后缀数组的关键词索引，查找关键词出现的每一处及其上下文。
+#[allow(clippy::upper_case_acronyms)]
+pub struct KWIC<'a> {
+    text: &'a str,
+    sa: SuffixArray<'a>,
+}
+
+impl<'a> KWIC<'a> {
+    /// 为 text 建立索引。
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            sa: SuffixArray::new(text.as_bytes()),
+        }
+    }
+
+    /// 查找 query 出现的所有位置，按后缀的顺序排列。
+    pub fn find(&self, query: &str) -> Vec<usize> {
+        let mut results = vec![];
+        for i in self.sa.rank(query)..self.sa.len() {
+            if !self.sa.select(i).starts_with(query.as_bytes()) {
+                break;
+            }
+            results.push(self.sa.index(i));
+        }
+        results
+    }
+
+    /// 查找 query 出现的每一处，连同前后最多 context 个字节的完整字符一起返回。
+    pub fn search(&self, query: &str, context: usize) -> Vec<&'a str> {
+        self.find(query)
+            .into_iter()
+            .map(|p| {
+                let mut from = p.saturating_sub(context);
+                while !self.text.is_char_boundary(from) {
+                    from += 1;
+                }
+                let mut to = (p + query.len() + context).min(self.text.len());
+                while !self.text.is_char_boundary(to) {
+                    to -= 1;
+                }
+                &self.text[from..to]
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_test() {
+        let text = "it was the best of times it was the worst of times";
+        let k = KWIC::new(text);
+        assert_eq!(vec![45, 19], k.find("times"));
+        assert_eq!(vec!["of times", "of times it"], k.search("times", 3));
+        assert_eq!(vec!["the worst of "], k.search("worst", 4));
+        assert!(k.search("age", 5).is_empty());
+    }
+
+    #[test]
+    fn unicode_test() {
+        let k = KWIC::new("你好，世界");
+        // 上下文的边界落在字符中间时向内收缩。
+        assert_eq!(vec!["，世界"], k.search("世", 4));
+    }
+}
@@ -0,0 +1,123 @@
+//! 子字符串查找。
+
+use std::io::{self, Read};
+
+pub mod boyer_moore;
+pub mod kmp;
+pub mod kwic;
+pub mod rabin_karp;
+pub mod suffix_array;
+
+/// 流式查找时每次读取的字节数。
+const CHUNK: usize = 8192;
+
+/// 子字符串查找算法的统一入口，文本和模式均按字节处理。
+pub trait Searcher {
+    /// 获取模式的长度。
+    fn pattern_len(&self) -> usize;
+
+    /// 查找模式在 text 中第一次出现的位置。
+    fn find(&self, text: &[u8]) -> Option<usize>;
+
+    /// 查找模式在 text 中出现的所有位置（可重叠）。
+    fn find_all(&self, text: &[u8]) -> Vec<usize> {
+        let mut results = vec![];
+        let mut start = 0;
+        while let Some(i) = self.find(&text[start..]) {
+            results.push(start + i);
+            start += i + 1;
+        }
+        results
+    }
+
+    /// 从 reader 中查找模式第一次出现的位置，找到后即停止读取。
+    fn find_in<R>(&self, reader: R) -> io::Result<Option<usize>>
+    where
+        R: Read,
+        Self: Sized,
+    {
+        scan(self, reader, true).map(|r| r.first().copied())
+    }
+
+    /// 从 reader 中查找模式出现的所有位置（可重叠）。
+    fn find_all_in<R>(&self, reader: R) -> io::Result<Vec<usize>>
+    where
+        R: Read,
+        Self: Sized,
+    {
+        scan(self, reader, false)
+    }
+}
+
+/// 分块读取 reader 并查找，相邻两块之间保留 m - 1 个字节，
+/// 保证跨越边界的匹配不会遗漏，也不会重复。
+fn scan<S, R>(s: &S, mut reader: R, first_only: bool) -> io::Result<Vec<usize>>
+where
+    S: Searcher,
+    R: Read,
+{
+    let m = s.pattern_len();
+    let mut results = vec![];
+    let mut buf = Vec::with_capacity(CHUNK + m);
+    let mut offset = 0; // buf[0] 在整个输入中的位置。
+    let mut chunk = [0; CHUNK];
+    loop {
+        let n = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        buf.extend_from_slice(&chunk[..n]);
+
+        for i in s.find_all(&buf) {
+            results.push(offset + i);
+            if first_only {
+                return Ok(results);
+            }
+        }
+
+        let keep = buf.len().min(m - 1);
+        offset += buf.len() - keep;
+        buf.drain(..buf.len() - keep);
+    }
+    Ok(results)
+}
+
+/// 检查模式非空。
+fn check_pattern(pat: &[u8]) {
+    assert!(!pat.is_empty(), "模式不能为空");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boyer_moore::BoyerMoore;
+    use kmp::KMP;
+    use rabin_karp::RabinKarp;
+
+    fn check<S: Searcher>(s: S) {
+        let text = b"abacadabrabracabracadabrabrabracad";
+        assert_eq!(Some(14), s.find(text));
+        assert_eq!(vec![14], s.find_all(text));
+        assert_eq!(None, s.find(b"abracadabr"));
+        assert_eq!(None, s.find(b""));
+
+        // 跨越分块边界的重叠匹配。
+        let mut long = vec![b'x'; CHUNK - 5];
+        long.extend_from_slice(b"abracadabracadabra");
+        let expect = vec![CHUNK - 5, CHUNK + 2];
+        assert_eq!(expect, s.find_all(&long));
+        assert_eq!(expect, s.find_all_in(&long[..]).unwrap());
+        assert_eq!(Some(CHUNK - 5), s.find_in(&long[..]).unwrap());
+        assert_eq!(None, s.find_in(&text[..10]).unwrap());
+    }
+
+    #[test]
+    fn searcher_test() {
+        check(KMP::new("abracadabra"));
+        check(BoyerMoore::new("abracadabra"));
+        check(RabinKarp::new("abracadabra"));
+        check(RabinKarp::monte_carlo("abracadabra"));
+    }
+}
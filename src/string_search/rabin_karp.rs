@@ -0,0 +1,126 @@
+//! Rabin-Karp 指纹字符串查找算法。
+
+use super::{check_pattern, Searcher};
+
+const R: u64 = 256; // 字母表的大小。
+const Q: u64 = 1_000_000_007; // 散列使用的大素数。
+
+pub struct RabinKarp {
+    pat: Vec<u8>,    // 模式，拉斯维加斯算法用于验证。
+    pat_hash: u64,   // 模式的散列值。
+    rm: u64,         // R^(m-1) % Q。
+    las_vegas: bool, // 散列值相同时是否逐字节验证。
+}
+
+impl RabinKarp {
+    /// 创建拉斯维加斯版本：散列值相同时再逐字节比较，结果一定正确。
+    ///
+    /// # Panics
+    ///
+    /// 模式为空时 panic。
+    pub fn new(pat: impl AsRef<[u8]>) -> Self {
+        Self::with_verify(pat.as_ref(), true)
+    }
+
+    /// 创建蒙特卡洛版本：散列值相同即认为匹配，速度更快，但有极小的概率出错。
+    ///
+    /// # Panics
+    ///
+    /// 模式为空时 panic。
+    pub fn monte_carlo(pat: impl AsRef<[u8]>) -> Self {
+        Self::with_verify(pat.as_ref(), false)
+    }
+
+    fn with_verify(pat: &[u8], las_vegas: bool) -> Self {
+        check_pattern(pat);
+
+        let mut rm = 1;
+        for _ in 1..pat.len() {
+            rm = (R * rm) % Q;
+        }
+        Self {
+            pat: pat.to_vec(),
+            pat_hash: hash(pat),
+            rm,
+            las_vegas,
+        }
+    }
+
+    /// 滚动计算 text 中每个长度为 m 的子串的散列值，每找到一个匹配就以它的起始位置调用 f，
+    /// f 返回 false 时停止。
+    fn run<F>(&self, text: &[u8], mut f: F)
+    where
+        F: FnMut(usize) -> bool,
+    {
+        let m = self.pat.len();
+        if text.len() < m {
+            return;
+        }
+
+        let mut text_hash = hash(&text[..m]);
+        if text_hash == self.pat_hash && self.check(text, 0) && !f(0) {
+            return;
+        }
+        for i in m..text.len() {
+            // 减去第一个字节，加上最后一个字节，再次检查匹配。
+            text_hash = (text_hash + Q - self.rm * text[i - m] as u64 % Q) % Q;
+            text_hash = (text_hash * R + text[i] as u64) % Q;
+            let offset = i + 1 - m;
+            if text_hash == self.pat_hash && self.check(text, offset) && !f(offset) {
+                return;
+            }
+        }
+    }
+
+    /// 散列值相同时，确认 text[i..] 是否以模式开头。
+    fn check(&self, text: &[u8], i: usize) -> bool {
+        !self.las_vegas || text[i..i + self.pat.len()] == self.pat[..]
+    }
+}
+
+/// 用 Horner 方法计算 key 的散列值。
+fn hash(key: &[u8]) -> u64 {
+    key.iter().fold(0, |h, &c| (R * h + c as u64) % Q)
+}
+
+impl Searcher for RabinKarp {
+    fn pattern_len(&self) -> usize {
+        self.pat.len()
+    }
+
+    fn find(&self, text: &[u8]) -> Option<usize> {
+        let mut found = None;
+        self.run(text, |i| {
+            found = Some(i);
+            false
+        });
+        found
+    }
+
+    fn find_all(&self, text: &[u8]) -> Vec<usize> {
+        let mut results = vec![];
+        self.run(text, |i| {
+            results.push(i);
+            true
+        });
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_test() {
+        let text = b"3141592653589793";
+        assert_eq!(Some(6), RabinKarp::new("26535").find(text));
+        assert_eq!(Some(6), RabinKarp::monte_carlo("26535").find(text));
+        assert_eq!(None, RabinKarp::new("26536").find(text));
+        assert_eq!(hash(b"26535"), RabinKarp::new("26535").pat_hash);
+        assert_eq!(
+            (0..98).collect::<Vec<_>>(),
+            RabinKarp::new("aaa").find_all(&[b'a'; 100])
+        );
+    }
+}
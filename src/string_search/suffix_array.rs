@@ -0,0 +1,251 @@
+//! 后缀数组，以及基于它的最长重复子字符串和最长公共子字符串。
+
+use crate::sort::key_indexed::sorted_positions;
+
+const R: usize = 256; // 字母表的大小，按字节划分。
+
+/// 文本所有后缀按字节序排列而成的数组。
+pub struct SuffixArray<'a> {
+    text: &'a [u8],
+    sa: Vec<usize>,  // 排序后第 i 个后缀在文本中的位置。
+    lcp: Vec<usize>, // 排序后第 i 个后缀与第 i - 1 个后缀的最长公共前缀。
+}
+
+impl<'a> SuffixArray<'a> {
+    /// 用倍增法构造后缀数组，并用 Kasai 算法计算最长公共前缀。
+    pub fn new(text: &'a [u8]) -> Self {
+        let sa = build(text);
+        let lcp = kasai(text, &sa);
+        Self { text, sa, lcp }
+    }
+
+    /// 获取文本的长度，即后缀的数量。
+    pub fn len(&self) -> usize {
+        self.sa.len()
+    }
+
+    /// 文本是否为空。
+    pub fn is_empty(&self) -> bool {
+        self.sa.is_empty()
+    }
+
+    /// 排序后第 i 个后缀在文本中的位置。
+    pub fn index(&self, i: usize) -> usize {
+        self.sa[i]
+    }
+
+    /// 排序后第 i 个后缀。
+    pub fn select(&self, i: usize) -> &'a [u8] {
+        &self.text[self.sa[i]..]
+    }
+
+    /// 排序后第 i 个后缀与第 i - 1 个后缀的最长公共前缀的长度。
+    ///
+    /// # Panics
+    ///
+    /// i 不在 `1..len()` 范围内时 panic。
+    pub fn lcp(&self, i: usize) -> usize {
+        assert!(0 < i && i < self.len(), "i 必须在 1..{} 范围内", self.len());
+        self.lcp[i]
+    }
+
+    /// 小于 key 的后缀的数量。
+    pub fn rank(&self, key: impl AsRef<[u8]>) -> usize {
+        let key = key.as_ref();
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.select(mid) < key {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+}
+
+/// 倍增法：每一轮按前 2k 个字节的排名排序，排名互不相同时结束。
+fn build(text: &[u8]) -> Vec<usize> {
+    let n = text.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    if n == 0 {
+        return sa;
+    }
+
+    // 排名从 1 开始，0 表示越过了文本的结尾。
+    let mut rank: Vec<usize> = text.iter().map(|&b| b as usize + 1).collect();
+    let mut r = R + 1;
+    let mut k = 1;
+    loop {
+        let second = |i: usize| if i + k < n { rank[i + k] } else { 0 };
+
+        // 先按后 k 个字节、再按前 k 个字节的排名进行键索引计数排序。
+        let keys: Vec<usize> = sa.iter().map(|&i| second(i)).collect();
+        sa = sorted_positions(&keys, r)
+            .into_iter()
+            .map(|j| sa[j])
+            .collect();
+        let keys: Vec<usize> = sa.iter().map(|&i| rank[i]).collect();
+        sa = sorted_positions(&keys, r)
+            .into_iter()
+            .map(|j| sa[j])
+            .collect();
+
+        let mut next = vec![0; n];
+        next[sa[0]] = 1;
+        for j in 1..n {
+            let (p, q) = (sa[j - 1], sa[j]);
+            let same = rank[p] == rank[q] && second(p) == second(q);
+            next[q] = next[p] + usize::from(!same);
+        }
+        rank = next;
+        r = rank[sa[n - 1]] + 1;
+        if r == n + 1 {
+            return sa;
+        }
+        k *= 2;
+    }
+}
+
+/// Kasai 算法：按后缀在文本中的顺序计算，相邻后缀的公共前缀最多减少 1。
+fn kasai(text: &[u8], sa: &[usize]) -> Vec<usize> {
+    let n = sa.len();
+    let mut inv = vec![0; n];
+    for (i, &p) in sa.iter().enumerate() {
+        inv[p] = i;
+    }
+
+    let mut lcp = vec![0; n];
+    let mut h = 0;
+    for p in 0..n {
+        if inv[p] == 0 {
+            h = 0;
+            continue;
+        }
+        let q = sa[inv[p] - 1];
+        while p + h < n && q + h < n && text[p + h] == text[q + h] {
+            h += 1;
+        }
+        lcp[inv[p]] = h;
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
+
+/// 截取 text 中从 start 开始、不超过 len 个字节的完整字符。
+fn char_prefix(text: &str, start: usize, mut len: usize) -> &str {
+    while !text.is_char_boundary(start + len) {
+        len -= 1;
+    }
+    &text[start..start + len]
+}
+
+/// 查找 text 中最长的重复子字符串。
+pub fn longest_repeated_substring(text: &str) -> &str {
+    let sa = SuffixArray::new(text.as_bytes());
+    let mut lrs = "";
+    for i in 1..sa.len() {
+        // 公共前缀非空时，两个后缀都从字符的边界开始。
+        let p = sa.index(i);
+        if sa.lcp(i) > lrs.len() && text.is_char_boundary(p) {
+            let s = char_prefix(text, p, sa.lcp(i));
+            if s.len() > lrs.len() {
+                lrs = s;
+            }
+        }
+    }
+    lrs
+}
+
+/// 查找 a 和 b 的最长公共子字符串，返回其在 a 中的切片。
+pub fn longest_common_substring<'a>(a: &'a str, b: &str) -> &'a str {
+    // 0xff 不会出现在 UTF-8 中，公共前缀不会越过分隔符。
+    let mut text = Vec::with_capacity(a.len() + b.len() + 1);
+    text.extend_from_slice(a.as_bytes());
+    text.push(0xff);
+    text.extend_from_slice(b.as_bytes());
+
+    let sa = SuffixArray::new(&text);
+    let mut lcs = "";
+    for i in 1..sa.len() {
+        // 相邻的两个后缀必须分别来自 a 和 b。
+        let (p, q) = (sa.index(i), sa.index(i - 1));
+        let p = match (p < a.len(), q < a.len()) {
+            (true, false) if q > a.len() => p,
+            (false, true) if p > a.len() => q,
+            _ => continue,
+        };
+        if sa.lcp(i) > lcs.len() && a.is_char_boundary(p) {
+            let s = char_prefix(a, p, sa.lcp(i));
+            if s.len() > lcs.len() {
+                lcs = s;
+            }
+        }
+    }
+    lcs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suffix_array_test() {
+        let sa = SuffixArray::new(b"ABRACADABRA!");
+        assert_eq!(12, sa.len());
+        let index: Vec<usize> = (0..sa.len()).map(|i| sa.index(i)).collect();
+        assert_eq!(&[11, 10, 7, 0, 3, 5, 8, 1, 4, 6, 9, 2], &index[..]);
+        let lcp: Vec<usize> = (1..sa.len()).map(|i| sa.lcp(i)).collect();
+        assert_eq!(&[0, 1, 4, 1, 1, 0, 3, 0, 0, 0, 2], &lcp[..]);
+        assert_eq!(b"ABRA!", sa.select(2));
+    }
+
+    #[test]
+    fn rank_test() {
+        let sa = SuffixArray::new(b"ABRACADABRA!");
+        assert_eq!(0, sa.rank(""));
+        assert_eq!(2, sa.rank("ABRA"));
+        assert_eq!(6, sa.rank("B"));
+        assert_eq!(12, sa.rank("Z"));
+    }
+
+    #[test]
+    fn build_test() {
+        // 与直接排序的结果比较。
+        let text: Vec<u8> = (0..2000_u32)
+            .map(|i| b"ab"[(i * i % 7 % 2) as usize])
+            .collect();
+        let sa = SuffixArray::new(&text);
+        let mut expect: Vec<usize> = (0..text.len()).collect();
+        expect.sort_by_key(|&i| &text[i..]);
+        let index: Vec<usize> = (0..sa.len()).map(|i| sa.index(i)).collect();
+        assert_eq!(expect, index);
+        assert!(SuffixArray::new(b"").is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "i 必须在 1..3 范围内")]
+    fn lcp_panic_test() {
+        SuffixArray::new(b"abc").lcp(0);
+    }
+
+    #[test]
+    fn longest_repeated_substring_test() {
+        assert_eq!("acaag", longest_repeated_substring("aacaagtttacaagc"));
+        assert_eq!(
+            "it was the ",
+            longest_repeated_substring("it was the best of times it was the worst")
+        );
+        assert_eq!("你好", longest_repeated_substring("你好世界你好"));
+        assert_eq!("", longest_repeated_substring("abc"));
+    }
+
+    #[test]
+    fn longest_common_substring_test() {
+        assert_eq!("abcd", longest_common_substring("abcdxyz", "xyzabcd"));
+        assert_eq!("世界", longest_common_substring("你好世界", "世界和平"));
+        assert_eq!("", longest_common_substring("abc", "xyz"));
+        assert_eq!("", longest_common_substring("", "xyz"));
+    }
+}